@@ -40,8 +40,8 @@ fn Example1() -> Element {{
     rsx! {{
         Navbar {{
             menus: vec![
-                Menu {{ id: 1, link: "/", name: "Home", icon_start: None, icon_end: None }},
-                Menu {{ id: 2, link: "/about", name: "About", icon_start: None, icon_end: None }},
+                Menu::new(1, "Home", "/"),
+                Menu::new(2, "About", "/about"),
             ],
         }}
     }}
@@ -64,8 +64,8 @@ fn Example2() -> Element {{
         Navbar {{
             show_search: true,
             menus: vec![
-                Menu {{ id: 1, link: "/", name: "Dashboard", icon_start: None, icon_end: None }},
-                Menu {{ id: 2, link: "/reports", name: "Reports", icon_start: None, icon_end: None }},
+                Menu::new(1, "Dashboard", "/"),
+                Menu::new(2, "Reports", "/reports"),
             ],
         }}
     }}
@@ -88,12 +88,12 @@ fn Example3() -> Element {{
         Navbar {{
             show_profile_menu: true,
             dropdown_items: vec![
-                DropdownItem {{ id: 1, link: "/profile", label: "Profile", icon: None }},
-                DropdownItem {{ id: 2, link: "/settings", label: "Settings", icon: None }},
-                DropdownItem {{ id: 3, link: "/logout", label: "Logout", icon: None }},
+                DropdownItem::new(1, "Profile", "/profile"),
+                DropdownItem::new(2, "Settings", "/settings"),
+                DropdownItem::new(3, "Logout", "/logout"),
             ],
             menus: vec![
-                Menu {{ id: 1, link: "/", name: "Services", icon_start: None, icon_end: None }},
+                Menu::new(1, "Services", "/"),
             ],
         }}
     }}
@@ -116,11 +116,11 @@ fn Example4() -> Element {{
         Navbar {{
             show_mega_menu: true,
             mega_menu_items: vec![
-                MegaMenuItem {{ title: "Hosting", description: "Fast cloud hosting", link: "/hosting" }},
-                MegaMenuItem {{ title: "Storage", description: "Secure storage plans", link: "/storage" }},
+                MegaMenuItem::new("Hosting", "Fast cloud hosting", "/hosting"),
+                MegaMenuItem::new("Storage", "Secure storage plans", "/storage"),
             ],
             menus: vec![
-                Menu {{ id: 1, link: "/products", name: "Products", icon_start: None, icon_end: None }},
+                Menu::new(1, "Products", "/products"),
             ],
         }}
     }}
@@ -145,12 +145,12 @@ fn Example5() -> Element {{
             button_href: "/upgrade",
             show_profile_menu: true,
             dropdown_items: vec![
-                DropdownItem {{ id: 1, link: "/account", label: "Account", icon: None }},
-                DropdownItem {{ id: 2, link: "/logout", label: "Logout", icon: None }},
+                DropdownItem::new(1, "Account", "/account"),
+                DropdownItem::new(2, "Logout", "/logout"),
             ],
             menus: vec![
-                Menu {{ id: 1, link: "/", name: "Overview", icon_start: None, icon_end: None }},
-                Menu {{ id: 2, link: "/billing", name: "Billing", icon_start: None, icon_end: None }},
+                Menu::new(1, "Overview", "/"),
+                Menu::new(2, "Billing", "/billing"),
             ],
         }}
     }}
@@ -174,8 +174,8 @@ fn Example6() -> Element {{
             show_search: true,
             search_placeholder: "Search products...",
             menus: vec![
-                Menu {{ id: 1, link: "/shop", name: "Shop", icon_start: None, icon_end: None }},
-                Menu {{ id: 2, link: "/categories", name: "Categories", icon_start: None, icon_end: None }},
+                Menu::new(1, "Shop", "/shop"),
+                Menu::new(2, "Categories", "/categories"),
             ],
         }}
     }}
@@ -200,8 +200,8 @@ fn Example7() -> Element {{
             button_href: "/signup",
             button_target: "_self",
             menus: vec![
-                Menu {{ id: 1, link: "/solutions", name: "Solutions", icon_start: None, icon_end: None }},
-                Menu {{ id: 2, link: "/pricing", name: "Pricing", icon_start: None, icon_end: None }},
+                Menu::new(1, "Solutions", "/solutions"),
+                Menu::new(2, "Pricing", "/pricing"),
             ],
         }}
     }}
@@ -225,13 +225,13 @@ fn Example8() -> Element {{
             show_profile_menu: true,
             profile_button_text: "🌐 languages",
             dropdown_items: vec![
-                DropdownItem {{ id: 1, link: "/lang/en", label: "English", icon: None }},
-                DropdownItem {{ id: 2, link: "/lang/es", label: "Español", icon: None }},
-                DropdownItem {{ id: 3, link: "/lang/fr", label: "Français", icon: None }},
+                DropdownItem::new(1, "English", "/lang/en"),
+                DropdownItem::new(2, "Español", "/lang/es"),
+                DropdownItem::new(3, "Français", "/lang/fr"),
             ],
             menus: vec![
-                Menu {{ id: 1, link: "/", name: "Home", icon_start: None, icon_end: None }},
-                Menu {{ id: 2, link: "/about", name: "About", icon_start: None, icon_end: None }},
+                Menu::new(1, "Home", "/"),
+                Menu::new(2, "About", "/about"),
             ],
         }}
     }}
@@ -254,12 +254,12 @@ fn Example9() -> Element {{
         Navbar {{
             show_mega_menu: true,
             mega_menu_items: vec![
-                MegaMenuItem {{ title: "CRM", description: "Customer management", link: "/crm" }},
-                MegaMenuItem {{ title: "Marketing", description: "Automation tools", link: "/marketing" }},
-                MegaMenuItem {{ title: "Analytics", description: "Business insights", link: "/analytics" }},
+                MegaMenuItem::new("CRM", "Customer management", "/crm"),
+                MegaMenuItem::new("Marketing", "Automation tools", "/marketing"),
+                MegaMenuItem::new("Analytics", "Business insights", "/analytics"),
             ],
             menus: vec![
-                Menu {{ id: 1, link: "/platform", name: "Platform", icon_start: None, icon_end: None }},
+                Menu::new(1, "Platform", "/platform"),
             ],
         }}
     }}
@@ -281,9 +281,9 @@ fn Example10() -> Element {{
     rsx! {{
         Navbar {{
             menus: vec![
-                Menu {{ id: 1, link: "/docs", name: "Docs", icon_start: None, icon_end: None }},
-                Menu {{ id: 2, link: "/api", name: "API", icon_start: None, icon_end: None }},
-                Menu {{ id: 3, link: "/guides", name: "Guides", icon_start: None, icon_end: None }},
+                Menu::new(1, "Docs", "/docs"),
+                Menu::new(2, "API", "/api"),
+                Menu::new(3, "Guides", "/guides"),
             ],
         }}
     }}
@@ -305,14 +305,14 @@ fn Example11() -> Element {{
     rsx! {{
         Navbar {{
             menus: vec![
-                Menu {{ id: 1, link: "/courses", name: "Courses", icon_start: None, icon_end: None }},
-                Menu {{ id: 2, link: "/tutorials", name: "Tutorials", icon_start: None, icon_end: None }},
-                Menu {{ id: 3, link: "/certifications", name: "Certifications", icon_start: None, icon_end: None }},
+                Menu::new(1, "Courses", "/courses"),
+                Menu::new(2, "Tutorials", "/tutorials"),
+                Menu::new(3, "Certifications", "/certifications"),
             ],
             show_profile_menu: true,
             dropdown_items: vec![
-                DropdownItem {{ id: 1, link: "/dashboard", label: "Dashboard", icon: None }},
-                DropdownItem {{ id: 2, link: "/logout", label: "Logout", icon: None }},
+                DropdownItem::new(1, "Dashboard", "/dashboard"),
+                DropdownItem::new(2, "Logout", "/logout"),
             ],
         }}
     }}
@@ -338,19 +338,19 @@ fn Example12() -> Element {{
             show_profile_menu: true,
             search_placeholder: "Search courses, docs...",
             mega_menu_items: vec![
-                MegaMenuItem {{ title: "Docs", description: "Official docs", link: "/docs" }},
-                MegaMenuItem {{ title: "Tutorials", description: "Step-by-step guides", link: "/tutorials" }},
-                MegaMenuItem {{ title: "API", description: "Full API Reference", link: "/api" }},
+                MegaMenuItem::new("Docs", "Official docs", "/docs"),
+                MegaMenuItem::new("Tutorials", "Step-by-step guides", "/tutorials"),
+                MegaMenuItem::new("API", "Full API Reference", "/api"),
             ],
             dropdown_items: vec![
-                DropdownItem {{ id: 1, link: "/account", label: "Account", icon: None }},
-                DropdownItem {{ id: 2, link: "/notifications", label: "Notifications", icon: None }},
-                DropdownItem {{ id: 3, link: "/logout", label: "Logout", icon: None }},
+                DropdownItem::new(1, "Account", "/account"),
+                DropdownItem::new(2, "Notifications", "/notifications"),
+                DropdownItem::new(3, "Logout", "/logout"),
             ],
             menus: vec![
-                Menu {{ id: 1, link: "/", name: "Home", icon_start: None, icon_end: None }},
-                Menu {{ id: 2, link: "/explore", name: "Explore", icon_start: None, icon_end: None }},
-                Menu {{ id: 3, link: "/pricing", name: "Pricing", icon_start: None, icon_end: None }},
+                Menu::new(1, "Home", "/"),
+                Menu::new(2, "Explore", "/explore"),
+                Menu::new(3, "Pricing", "/pricing"),
             ],
         }}
     }}
@@ -368,8 +368,8 @@ fn Example1() -> Element {
     rsx! {
         Navbar {
             menus: vec![
-                Menu { id: 1, link: "/", name: "Home", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/about", name: "About", icon_start: None, icon_end: None },
+                Menu::new(1, "Home", "/"),
+                Menu::new(2, "About", "/about"),
             ],
         }
     }
@@ -380,8 +380,8 @@ fn Example2() -> Element {
         Navbar {
             show_search: true,
             menus: vec![
-                Menu { id: 1, link: "/", name: "Dashboard", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/reports", name: "Reports", icon_start: None, icon_end: None },
+                Menu::new(1, "Dashboard", "/"),
+                Menu::new(2, "Reports", "/reports"),
             ],
         }
     }
@@ -392,12 +392,12 @@ fn Example3() -> Element {
         Navbar {
             show_profile_menu: true,
             dropdown_items: vec![
-                DropdownItem { id: 1, link: "/profile", label: "Profile", icon: None },
-                DropdownItem { id: 2, link: "/settings", label: "Settings", icon: None },
-                DropdownItem { id: 3, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Profile", "/profile"),
+                DropdownItem::new(2, "Settings", "/settings"),
+                DropdownItem::new(3, "Logout", "/logout"),
             ],
             menus: vec![
-                Menu { id: 1, link: "/", name: "Services", icon_start: None, icon_end: None },
+                Menu::new(1, "Services", "/"),
             ],
         }
     }
@@ -408,11 +408,11 @@ fn Example4() -> Element {
         Navbar {
             show_mega_menu: true,
             mega_menu_items: vec![
-                MegaMenuItem { title: "Hosting", description: "Fast cloud hosting", link: "/hosting" },
-                MegaMenuItem { title: "Storage", description: "Secure storage plans", link: "/storage" },
+                MegaMenuItem::new("Hosting", "Fast cloud hosting", "/hosting"),
+                MegaMenuItem::new("Storage", "Secure storage plans", "/storage"),
             ],
             menus: vec![
-                Menu { id: 1, link: "/products", name: "Products", icon_start: None, icon_end: None },
+                Menu::new(1, "Products", "/products"),
             ],
         }
     }
@@ -425,12 +425,12 @@ fn Example5() -> Element {
             button_href: "/upgrade",
             show_profile_menu: true,
             dropdown_items: vec![
-                DropdownItem { id: 1, link: "/account", label: "Account", icon: None },
-                DropdownItem { id: 2, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Account", "/account"),
+                DropdownItem::new(2, "Logout", "/logout"),
             ],
             menus: vec![
-                Menu { id: 1, link: "/", name: "Overview", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/billing", name: "Billing", icon_start: None, icon_end: None },
+                Menu::new(1, "Overview", "/"),
+                Menu::new(2, "Billing", "/billing"),
             ],
         }
     }
@@ -442,8 +442,8 @@ fn Example6() -> Element {
             show_search: true,
             search_placeholder: "Search products...",
             menus: vec![
-                Menu { id: 1, link: "/shop", name: "Shop", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/categories", name: "Categories", icon_start: None, icon_end: None },
+                Menu::new(1, "Shop", "/shop"),
+                Menu::new(2, "Categories", "/categories"),
             ],
         }
     }
@@ -456,8 +456,8 @@ fn Example7() -> Element {
             button_href: "/signup",
             button_target: "_self",
             menus: vec![
-                Menu { id: 1, link: "/solutions", name: "Solutions", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/pricing", name: "Pricing", icon_start: None, icon_end: None },
+                Menu::new(1, "Solutions", "/solutions"),
+                Menu::new(2, "Pricing", "/pricing"),
             ],
         }
     }
@@ -469,13 +469,13 @@ fn Example8() -> Element {
             show_profile_menu: true,
             profile_button_text: "🌐 languages",
             dropdown_items: vec![
-                DropdownItem { id: 1, link: "/lang/en", label: "English", icon: None },
-                DropdownItem { id: 2, link: "/lang/es", label: "Español", icon: None },
-                DropdownItem { id: 3, link: "/lang/fr", label: "Français", icon: None },
+                DropdownItem::new(1, "English", "/lang/en"),
+                DropdownItem::new(2, "Español", "/lang/es"),
+                DropdownItem::new(3, "Français", "/lang/fr"),
             ],
             menus: vec![
-                Menu { id: 1, link: "/", name: "Home", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/about", name: "About", icon_start: None, icon_end: None },
+                Menu::new(1, "Home", "/"),
+                Menu::new(2, "About", "/about"),
             ],
         }
     }
@@ -486,12 +486,12 @@ fn Example9() -> Element {
         Navbar {
             show_mega_menu: true,
             mega_menu_items: vec![
-                MegaMenuItem { title: "CRM", description: "Customer management", link: "/crm" },
-                MegaMenuItem { title: "Marketing", description: "Automation tools", link: "/marketing" },
-                MegaMenuItem { title: "Analytics", description: "Business insights", link: "/analytics" },
+                MegaMenuItem::new("CRM", "Customer management", "/crm"),
+                MegaMenuItem::new("Marketing", "Automation tools", "/marketing"),
+                MegaMenuItem::new("Analytics", "Business insights", "/analytics"),
             ],
             menus: vec![
-                Menu { id: 1, link: "/platform", name: "Platform", icon_start: None, icon_end: None },
+                Menu::new(1, "Platform", "/platform"),
             ],
         }
     }
@@ -501,9 +501,9 @@ fn Example10() -> Element {
     rsx! {
         Navbar {
             menus: vec![
-                Menu { id: 1, link: "/docs", name: "Docs", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/api", name: "API", icon_start: None, icon_end: None },
-                Menu { id: 3, link: "/guides", name: "Guides", icon_start: None, icon_end: None },
+                Menu::new(1, "Docs", "/docs"),
+                Menu::new(2, "API", "/api"),
+                Menu::new(3, "Guides", "/guides"),
             ],
         }
     }
@@ -513,14 +513,14 @@ fn Example11() -> Element {
     rsx! {
         Navbar {
             menus: vec![
-                Menu { id: 1, link: "/courses", name: "Courses", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/tutorials", name: "Tutorials", icon_start: None, icon_end: None },
-                Menu { id: 3, link: "/certifications", name: "Certifications", icon_start: None, icon_end: None },
+                Menu::new(1, "Courses", "/courses"),
+                Menu::new(2, "Tutorials", "/tutorials"),
+                Menu::new(3, "Certifications", "/certifications"),
             ],
             show_profile_menu: true,
             dropdown_items: vec![
-                DropdownItem { id: 1, link: "/dashboard", label: "Dashboard", icon: None },
-                DropdownItem { id: 2, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Dashboard", "/dashboard"),
+                DropdownItem::new(2, "Logout", "/logout"),
             ],
         }
     }
@@ -534,19 +534,19 @@ fn Example12() -> Element {
             show_profile_menu: true,
             search_placeholder: "Search courses, docs...",
             mega_menu_items: vec![
-                MegaMenuItem { title: "Docs", description: "Official docs", link: "/docs" },
-                MegaMenuItem { title: "Tutorials", description: "Step-by-step guides", link: "/tutorials" },
-                MegaMenuItem { title: "API", description: "Full API Reference", link: "/api" },
+                MegaMenuItem::new("Docs", "Official docs", "/docs"),
+                MegaMenuItem::new("Tutorials", "Step-by-step guides", "/tutorials"),
+                MegaMenuItem::new("API", "Full API Reference", "/api"),
             ],
             dropdown_items: vec![
-                DropdownItem { id: 1, link: "/account", label: "Account", icon: None },
-                DropdownItem { id: 2, link: "/notifications", label: "Notifications", icon: None },
-                DropdownItem { id: 3, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Account", "/account"),
+                DropdownItem::new(2, "Notifications", "/notifications"),
+                DropdownItem::new(3, "Logout", "/logout"),
             ],
             menus: vec![
-                Menu { id: 1, link: "/", name: "Home", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/explore", name: "Explore", icon_start: None, icon_end: None },
-                Menu { id: 3, link: "/pricing", name: "Pricing", icon_start: None, icon_end: None },
+                Menu::new(1, "Home", "/"),
+                Menu::new(2, "Explore", "/explore"),
+                Menu::new(3, "Pricing", "/pricing"),
             ],
         }
     }
@@ -6,8 +6,8 @@ pub fn example1() -> Html {
     html! {
         <Navbar
             menus={vec![
-            Menu { id: 1, link: "/", name: "Home", icon_start: None, icon_end: None },
-            Menu { id: 2, link: "/about", name: "About", icon_start: None, icon_end: None },
+            Menu::new(1, "Home", "/"),
+            Menu::new(2, "About", "/about"),
         ]}
         />
     }
@@ -19,8 +19,8 @@ pub fn example2() -> Html {
         <Navbar
             show_search=true
             menus={vec![
-                Menu { id: 1, link: "/", name: "Dashboard", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/reports", name: "Reports", icon_start: None, icon_end: None },
+                Menu::new(1, "Dashboard", "/"),
+                Menu::new(2, "Reports", "/reports"),
             ]}
         />
     }
@@ -32,12 +32,12 @@ pub fn example3() -> Html {
         <Navbar
             show_profile_menu=true
             dropdown_items={vec![
-                DropdownItem { id: 1, link: "/profile", label: "Profile", icon: None },
-                DropdownItem { id: 2, link: "/settings", label: "Settings", icon: None },
-                DropdownItem { id: 3, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Profile", "/profile"),
+                DropdownItem::new(2, "Settings", "/settings"),
+                DropdownItem::new(3, "Logout", "/logout"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/", name: "Services", icon_start: None, icon_end: None },
+                Menu::new(1, "Services", "/"),
             ]}
         />
     }
@@ -49,11 +49,11 @@ pub fn example4() -> Html {
         <Navbar
             show_mega_menu=true
             mega_menu_items={vec![
-                MegaMenuItem { title: "Hosting", description: "Fast cloud hosting", link: "/hosting" },
-                MegaMenuItem { title: "Storage", description: "Secure storage plans", link: "/storage" },
+                MegaMenuItem::new("Hosting", "Fast cloud hosting", "/hosting"),
+                MegaMenuItem::new("Storage", "Secure storage plans", "/storage"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/products", name: "Products", icon_start: None, icon_end: None },
+                Menu::new(1, "Products", "/products"),
             ]}
         />
     }
@@ -67,12 +67,12 @@ pub fn example5() -> Html {
             button_href="/upgrade"
             show_profile_menu=true
             dropdown_items={vec![
-                DropdownItem { id: 1, link: "/account", label: "Account", icon: None },
-                DropdownItem { id: 2, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Account", "/account"),
+                DropdownItem::new(2, "Logout", "/logout"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/", name: "Overview", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/billing", name: "Billing", icon_start: None, icon_end: None },
+                Menu::new(1, "Overview", "/"),
+                Menu::new(2, "Billing", "/billing"),
             ]}
         />
     }
@@ -85,8 +85,8 @@ pub fn example6() -> Html {
             show_search=true
             search_placeholder="Search products..."
             menus={vec![
-                Menu { id: 1, link: "/shop", name: "Shop", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/categories", name: "Categories", icon_start: None, icon_end: None },
+                Menu::new(1, "Shop", "/shop"),
+                Menu::new(2, "Categories", "/categories"),
             ]}
         />
     }
@@ -100,8 +100,8 @@ pub fn example7() -> Html {
             button_href="/signup"
             button_target="_self"
             menus={vec![
-                Menu { id: 1, link: "/solutions", name: "Solutions", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/pricing", name: "Pricing", icon_start: None, icon_end: None },
+                Menu::new(1, "Solutions", "/solutions"),
+                Menu::new(2, "Pricing", "/pricing"),
             ]}
         />
     }
@@ -114,13 +114,13 @@ pub fn example8() -> Html {
             show_profile_menu=true
             profile_button_text="🌐 languages"
             dropdown_items={vec![
-                DropdownItem { id: 1, link: "/lang/en", label: "English", icon: None },
-                DropdownItem { id: 2, link: "/lang/es", label: "Español", icon: None },
-                DropdownItem { id: 3, link: "/lang/fr", label: "Français", icon: None },
+                DropdownItem::new(1, "English", "/lang/en"),
+                DropdownItem::new(2, "Español", "/lang/es"),
+                DropdownItem::new(3, "Français", "/lang/fr"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/", name: "Home", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/about", name: "About", icon_start: None, icon_end: None },
+                Menu::new(1, "Home", "/"),
+                Menu::new(2, "About", "/about"),
             ]}
         />
     }
@@ -132,12 +132,12 @@ pub fn example9() -> Html {
         <Navbar
             show_mega_menu=true
             mega_menu_items={vec![
-                MegaMenuItem { title: "CRM", description: "Customer management", link: "/crm" },
-                MegaMenuItem { title: "Marketing", description: "Automation tools", link: "/marketing" },
-                MegaMenuItem { title: "Analytics", description: "Business insights", link: "/analytics" },
+                MegaMenuItem::new("CRM", "Customer management", "/crm"),
+                MegaMenuItem::new("Marketing", "Automation tools", "/marketing"),
+                MegaMenuItem::new("Analytics", "Business insights", "/analytics"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/platform", name: "Platform", icon_start: None, icon_end: None },
+                Menu::new(1, "Platform", "/platform"),
             ]}
         />
     }
@@ -148,9 +148,9 @@ pub fn example10() -> Html {
     html! {
         <Navbar
             menus={vec![
-                Menu { id: 1, link: "/docs", name: "Docs", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/api", name: "API", icon_start: None, icon_end: None },
-                Menu { id: 3, link: "/guides", name: "Guides", icon_start: None, icon_end: None },
+                Menu::new(1, "Docs", "/docs"),
+                Menu::new(2, "API", "/api"),
+                Menu::new(3, "Guides", "/guides"),
             ]}
         />
     }
@@ -161,14 +161,14 @@ pub fn example11() -> Html {
     html! {
         <Navbar
             menus={vec![
-                Menu { id: 1, link: "/courses", name: "Courses", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/tutorials", name: "Tutorials", icon_start: None, icon_end: None },
-                Menu { id: 3, link: "/certifications", name: "Certifications", icon_start: None, icon_end: None },
+                Menu::new(1, "Courses", "/courses"),
+                Menu::new(2, "Tutorials", "/tutorials"),
+                Menu::new(3, "Certifications", "/certifications"),
             ]}
             show_profile_menu=true
             dropdown_items={vec![
-                DropdownItem { id: 1, link: "/dashboard", label: "Dashboard", icon: None },
-                DropdownItem { id: 2, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Dashboard", "/dashboard"),
+                DropdownItem::new(2, "Logout", "/logout"),
             ]}
         />
     }
@@ -183,19 +183,19 @@ pub fn example12() -> Html {
             show_profile_menu=true
             search_placeholder="Search courses, docs..."
             mega_menu_items={vec![
-                MegaMenuItem { title: "Docs", description: "Official docs", link: "/docs" },
-                MegaMenuItem { title: "Tutorials", description: "Step-by-step guides", link: "/tutorials" },
-                MegaMenuItem { title: "API", description: "Full API Reference", link: "/api" },
+                MegaMenuItem::new("Docs", "Official docs", "/docs"),
+                MegaMenuItem::new("Tutorials", "Step-by-step guides", "/tutorials"),
+                MegaMenuItem::new("API", "Full API Reference", "/api"),
             ]}
             dropdown_items={vec![
-                DropdownItem { id: 1, link: "/account", label: "Account", icon: None },
-                DropdownItem { id: 2, link: "/notifications", label: "Notifications", icon: None },
-                DropdownItem { id: 3, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Account", "/account"),
+                DropdownItem::new(2, "Notifications", "/notifications"),
+                DropdownItem::new(3, "Logout", "/logout"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/", name: "Home", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/explore", name: "Explore", icon_start: None, icon_end: None },
-                Menu { id: 3, link: "/pricing", name: "Pricing", icon_start: None, icon_end: None },
+                Menu::new(1, "Home", "/"),
+                Menu::new(2, "Explore", "/explore"),
+                Menu::new(3, "Pricing", "/pricing"),
             ]}
         />
     }
@@ -219,8 +219,8 @@ use navbar::yew::{Menu, Navbar};
 pub fn example1() -> Html {
     html! {
         <Navbar menus={vec![
-            Menu { id: 1, link: "/", name: "Home", icon_start: None, icon_end: None },
-            Menu { id: 2, link: "/about", name: "About", icon_start: None, icon_end: None },
+            Menu::new(1, "Home", "/"),
+            Menu::new(2, "About", "/about"),
         ]}/>
     }
 }"# }
@@ -242,8 +242,8 @@ pub fn example2() -> Html {
         <Navbar
             show_search={true}
             menus={vec![
-                Menu { id: 1, link: "/", name: "Dashboard", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/reports", name: "Reports", icon_start: None, icon_end: None },
+                Menu::new(1, "Dashboard", "/"),
+                Menu::new(2, "Reports", "/reports"),
             ]}
         />
     }
@@ -266,12 +266,12 @@ pub fn example3() -> Html {
         <Navbar
             show_profile_menu={true}
             dropdown_items={vec![
-                DropdownItem { id: 1, link: "/profile", label: "Profile", icon: None },
-                DropdownItem { id: 2, link: "/settings", label: "Settings", icon: None },
-                DropdownItem { id: 3, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Profile", "/profile"),
+                DropdownItem::new(2, "Settings", "/settings"),
+                DropdownItem::new(3, "Logout", "/logout"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/", name: "Services", icon_start: None, icon_end: None },
+                Menu::new(1, "Services", "/"),
             ]}
         />
     }
@@ -294,11 +294,11 @@ pub fn example4() -> Html {
         <Navbar
             show_mega_menu=true
             mega_menu_items={vec![
-                MegaMenuItem { title: "Hosting", description: "Fast cloud hosting", link: "/hosting" },
-                MegaMenuItem { title: "Storage", description: "Secure storage plans", link: "/storage" },
+                MegaMenuItem::new("Hosting", "Fast cloud hosting", "/hosting"),
+                MegaMenuItem::new("Storage", "Secure storage plans", "/storage"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/products", name: "Products", icon_start: None, icon_end: None },
+                Menu::new(1, "Products", "/products"),
             ]}
         />
     }
@@ -323,12 +323,12 @@ pub fn example5() -> Html {
             button_href="/upgrade"
             show_profile_menu=true
             dropdown_items={vec![
-                DropdownItem { id: 1, link: "/account", label: "Account", icon: None },
-                DropdownItem { id: 2, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Account", "/account"),
+                DropdownItem::new(2, "Logout", "/logout"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/", name: "Overview", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/billing", name: "Billing", icon_start: None, icon_end: None },
+                Menu::new(1, "Overview", "/"),
+                Menu::new(2, "Billing", "/billing"),
             ]}
         />
     }
@@ -352,8 +352,8 @@ pub fn example6() -> Html {
             show_search=true
             search_placeholder="Search products..."
             menus={vec![
-                Menu { id: 1, link: "/shop", name: "Shop", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/categories", name: "Categories", icon_start: None, icon_end: None },
+                Menu::new(1, "Shop", "/shop"),
+                Menu::new(2, "Categories", "/categories"),
             ]}
         />
     }
@@ -378,8 +378,8 @@ pub fn example7() -> Html {
             button_href="/signup"
             button_target="_self"
             menus={vec![
-                Menu { id: 1, link: "/solutions", name: "Solutions", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/pricing", name: "Pricing", icon_start: None, icon_end: None },
+                Menu::new(1, "Solutions", "/solutions"),
+                Menu::new(2, "Pricing", "/pricing"),
             ]}
         />
     }
@@ -402,13 +402,13 @@ pub fn example8() -> Html {
         <Navbar
             show_profile_menu=true
             dropdown_items={vec![
-                DropdownItem { id: 1, link: "/lang/en", label: "English", icon: None },
-                DropdownItem { id: 2, link: "/lang/es", label: "Español", icon: None },
-                DropdownItem { id: 3, link: "/lang/fr", label: "Français", icon: None },
+                DropdownItem::new(1, "English", "/lang/en"),
+                DropdownItem::new(2, "Español", "/lang/es"),
+                DropdownItem::new(3, "Français", "/lang/fr"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/", name: "Home", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/about", name: "About", icon_start: None, icon_end: None },
+                Menu::new(1, "Home", "/"),
+                Menu::new(2, "About", "/about"),
             ]}
         />
     }
@@ -432,12 +432,12 @@ pub fn example9() -> Html {
             show_mega_menu=true
             profile_button_text="🌐 languages"
             mega_menu_items={vec![
-                MegaMenuItem { title: "CRM", description: "Customer management", link: "/crm" },
-                MegaMenuItem { title: "Marketing", description: "Automation tools", link: "/marketing" },
-                MegaMenuItem { title: "Analytics", description: "Business insights", link: "/analytics" },
+                MegaMenuItem::new("CRM", "Customer management", "/crm"),
+                MegaMenuItem::new("Marketing", "Automation tools", "/marketing"),
+                MegaMenuItem::new("Analytics", "Business insights", "/analytics"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/platform", name: "Platform", icon_start: None, icon_end: None },
+                Menu::new(1, "Platform", "/platform"),
             ]}
         />
     }
@@ -459,9 +459,9 @@ pub fn example10() -> Html {
     html! {
         <Navbar
             menus={vec![
-                Menu { id: 1, link: "/docs", name: "Docs", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/api", name: "API", icon_start: None, icon_end: None },
-                Menu { id: 3, link: "/guides", name: "Guides", icon_start: None, icon_end: None },
+                Menu::new(1, "Docs", "/docs"),
+                Menu::new(2, "API", "/api"),
+                Menu::new(3, "Guides", "/guides"),
             ]}
         />
     }
@@ -483,14 +483,14 @@ pub fn example11() -> Html {
     html! {
         <Navbar
             menus={vec![
-                Menu { id: 1, link: "/courses", name: "Courses", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/tutorials", name: "Tutorials", icon_start: None, icon_end: None },
-                Menu { id: 3, link: "/certifications", name: "Certifications", icon_start: None, icon_end: None },
+                Menu::new(1, "Courses", "/courses"),
+                Menu::new(2, "Tutorials", "/tutorials"),
+                Menu::new(3, "Certifications", "/certifications"),
             ]}
             show_profile_menu=true
             dropdown_items={vec![
-                DropdownItem { id: 1, link: "/dashboard", label: "Dashboard", icon: None },
-                DropdownItem { id: 2, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Dashboard", "/dashboard"),
+                DropdownItem::new(2, "Logout", "/logout"),
             ]}
         />
     }
@@ -516,19 +516,19 @@ pub fn example12() -> Html {
             show_profile_menu=true
             search_placeholder="Search courses, docs..."
             mega_menu_items={vec![
-                MegaMenuItem { title: "Docs", description: "Official docs", link: "/docs" },
-                MegaMenuItem { title: "Tutorials", description: "Step-by-step guides", link: "/tutorials" },
-                MegaMenuItem { title: "API", description: "Full API Reference", link: "/api" },
+                MegaMenuItem::new("Docs", "Official docs", "/docs"),
+                MegaMenuItem::new("Tutorials", "Step-by-step guides", "/tutorials"),
+                MegaMenuItem::new("API", "Full API Reference", "/api"),
             ]}
             dropdown_items={vec![
-                DropdownItem { id: 1, link: "/account", label: "Account", icon: None },
-                DropdownItem { id: 2, link: "/notifications", label: "Notifications", icon: None },
-                DropdownItem { id: 3, link: "/logout", label: "Logout", icon: None },
+                DropdownItem::new(1, "Account", "/account"),
+                DropdownItem::new(2, "Notifications", "/notifications"),
+                DropdownItem::new(3, "Logout", "/logout"),
             ]}
             menus={vec![
-                Menu { id: 1, link: "/", name: "Home", icon_start: None, icon_end: None },
-                Menu { id: 2, link: "/explore", name: "Explore", icon_start: None, icon_end: None },
-                Menu { id: 3, link: "/pricing", name: "Pricing", icon_start: None, icon_end: None },
+                Menu::new(1, "Home", "/"),
+                Menu::new(2, "Explore", "/explore"),
+                Menu::new(3, "Pricing", "/pricing"),
             ]}
         />
     }
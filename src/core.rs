@@ -0,0 +1,485 @@
+//! Framework-agnostic navbar model.
+//!
+//! This module holds the item data model shared by every framework adapter, along with
+//! [`NavbarState`], a small pure state machine describing which parts of the navbar are open.
+//! Adapters only translate DOM events into [`NavbarAction`]s and render the resulting state, so
+//! behavior is defined (and can be tested) once, on the host, without a browser.
+//!
+//! # Example
+//!
+//! ```rust
+//! use navbar::core::{NavbarAction, NavbarState, Popup};
+//!
+//! let state = NavbarState::new(true).apply(NavbarAction::Toggle(Popup::MobileMenu));
+//! assert!(state.mobile_menu_open);
+//!
+//! // Growing past the breakpoint switches to the desktop layout and closes the mobile menu.
+//! let state = state.apply(NavbarAction::SetMobile(false));
+//! assert!(!state.is_mobile);
+//! assert!(!state.mobile_menu_open);
+//! ```
+
+use std::borrow::Cow;
+
+mod breakpoint;
+mod keyboard;
+mod layout;
+mod mobile;
+mod notification;
+mod palette;
+mod route;
+mod scroll;
+mod search;
+mod theme;
+mod toggle;
+mod transition;
+
+pub use breakpoint::{
+    Breakpoint, BreakpointTracker, Breakpoints, DESKTOP_CLASS, InitialLayout, MOBILE_BREAKPOINT,
+    MOBILE_CLASS, Viewport, is_mobile_user_agent, is_mobile_width, responsive_css,
+};
+pub use keyboard::{ComboboxKey, MenuKey, Orientation, focus_trap_target};
+pub use layout::NavbarPart;
+pub use mobile::MobileVariant;
+pub use notification::{
+    BADGE_MAX, BELL_ICON_PATH, Notification, VISUALLY_HIDDEN_STYLE, badge_text, unread_count,
+};
+pub use palette::{
+    Command, CommandAction, CommandFn, CommandGroup, CommandSet, RecentCommands,
+    is_palette_shortcut,
+};
+#[cfg(any(feature = "yew-router", feature = "dioxus-router"))]
+pub(crate) use route::is_route_link;
+pub use route::{ActiveMatch, RouteMatcher};
+pub use scroll::{SCROLL_TOLERANCE, ScrollBehavior, ScrollState, ScrollTracker};
+pub use search::{
+    AsyncSuggestionFn, SearchIndex, Suggestion, SuggestionFn, SuggestionFuture, SuggestionProvider,
+    Suggestions, fuzzy_score, highlight_match,
+};
+pub use theme::{NavbarTheme, ThemeMode, ThemePreference};
+pub use toggle::ToggleAnimation;
+pub use transition::{Transition, TransitionPhase};
+
+/// Text of a menu item: a borrowed `&'static str` or an owned `String`.
+///
+/// Item constructors take `impl Into<Text>`, so string literals, `String`s (from an API
+/// response or a translation catalog) and `format!` output can all be used directly.
+pub type Text = Cow<'static, str>;
+
+/// Represents a standard menu item in the navigation bar.
+///
+/// `I` is the framework's icon type (`Html` in Yew, `Element` in Dioxus, `ViewFn` in Leptos).
+/// Each adapter exposes this as a concrete `Menu` alias.
+///
+/// ```rust
+/// use navbar::core::Menu;
+///
+/// let user = String::from("ada");
+/// let menu = Menu::<()>::new(1, "Account", "#").with_children(vec![
+///     Menu::new(2, format!("Signed in as {user}"), format!("/users/{user}")),
+///     Menu::new(3, "Settings", "/settings"),
+/// ]);
+/// assert_eq!(menu.children[0].link, "/users/ada");
+/// ```
+///
+/// The struct is `#[non_exhaustive]`: build it with [`Menu::new`] and the `with_*` methods, so
+/// fields added later don't break existing code.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Menu<I> {
+    /// Unique identifier for the menu item.
+    pub id: usize,
+
+    /// The destination URL when the menu item is clicked.
+    pub link: Text,
+
+    /// The display name of the menu item.
+    pub name: Text,
+
+    /// Optional icon displayed before the name.
+    pub icon_start: Option<I>,
+
+    /// Optional icon displayed after the name.
+    pub icon_end: Option<I>,
+
+    /// Child items, shown in a submenu: a flyout on desktop and an accordion in the mobile menu.
+    ///
+    /// Children may have children of their own, to any depth. Leave empty for a plain link.
+    pub children: Vec<Menu<I>>,
+}
+
+impl<I> Menu<I> {
+    /// Creates a menu item without icons or children.
+    pub fn new(id: usize, name: impl Into<Text>, link: impl Into<Text>) -> Self {
+        Self {
+            id,
+            link: link.into(),
+            name: name.into(),
+            icon_start: None,
+            icon_end: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the icon displayed before the name.
+    pub fn with_icon_start(self, icon: I) -> Self {
+        Self {
+            icon_start: Some(icon),
+            ..self
+        }
+    }
+
+    /// Sets the icon displayed after the name.
+    pub fn with_icon_end(self, icon: I) -> Self {
+        Self {
+            icon_end: Some(icon),
+            ..self
+        }
+    }
+
+    /// Sets the child items shown in this item's submenu.
+    pub fn with_children(self, children: Vec<Menu<I>>) -> Self {
+        Self { children, ..self }
+    }
+
+    /// Returns `true` if this item opens a submenu rather than following its link.
+    pub fn has_submenu(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    /// Returns `true` if this item, or any item in its submenus, is active at `path`.
+    ///
+    /// ```rust
+    /// use navbar::core::{ActiveMatch, Menu};
+    ///
+    /// let docs = Menu::<()>::new(1, "Docs", "#").with_children(vec![Menu::new(2, "Guide", "/docs/guide")]);
+    /// assert!(docs.contains_active(&ActiveMatch::Exact, "/docs/guide"));
+    /// assert!(!docs.contains_active(&ActiveMatch::Exact, "/blog"));
+    /// ```
+    pub fn contains_active(&self, active_match: &ActiveMatch, path: &str) -> bool {
+        active_match.is_active(&self.link, path)
+            || self
+                .children
+                .iter()
+                .any(|child| child.contains_active(active_match, path))
+    }
+}
+
+/// Represents an item in a dropdown menu.
+///
+/// Used for profile or secondary dropdowns in the navbar. `I` is the framework's icon type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropdownItem<I> {
+    /// Unique identifier for the dropdown item.
+    pub id: usize,
+
+    /// Optional icon displayed before the label.
+    pub icon: Option<I>,
+
+    /// The label/text for the dropdown item.
+    pub label: Text,
+
+    /// The destination URL for the item.
+    pub link: Text,
+}
+
+impl<I> DropdownItem<I> {
+    /// Creates a dropdown item without an icon.
+    pub fn new(id: usize, label: impl Into<Text>, link: impl Into<Text>) -> Self {
+        Self {
+            id,
+            icon: None,
+            label: label.into(),
+            link: link.into(),
+        }
+    }
+
+    /// Sets the icon displayed before the label.
+    pub fn with_icon(self, icon: I) -> Self {
+        Self {
+            icon: Some(icon),
+            ..self
+        }
+    }
+}
+
+/// Represents an item in a mega menu.
+///
+/// Used for showcasing grouped or featured links in a grid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MegaMenuItem {
+    /// The title or name of the item.
+    pub title: Text,
+
+    /// A short description explaining the item.
+    pub description: Text,
+
+    /// The link URL associated with the item.
+    pub link: Text,
+}
+
+impl MegaMenuItem {
+    /// Creates a mega menu item.
+    pub fn new(
+        title: impl Into<Text>,
+        description: impl Into<Text>,
+        link: impl Into<Text>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            link: link.into(),
+        }
+    }
+}
+
+/// A popup owned by the navbar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Popup {
+    /// The mobile (hamburger) menu.
+    MobileMenu,
+    /// The profile dropdown.
+    Dropdown,
+    /// The mega menu panel.
+    MegaMenu,
+    /// The notification feed.
+    Notifications,
+}
+
+impl Popup {
+    /// Every popup of the navbar.
+    pub const ALL: [Self; 4] = [
+        Self::MobileMenu,
+        Self::Dropdown,
+        Self::MegaMenu,
+        Self::Notifications,
+    ];
+}
+
+/// Events that drive the [`NavbarState`] state machine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NavbarAction {
+    /// The viewport crossed the mobile breakpoint; `true` means the mobile layout applies.
+    SetMobile(bool),
+    /// Opens a popup, closing any other.
+    Open(Popup),
+    /// Closes a popup, e.g. after a click outside of it.
+    Close(Popup),
+    /// Opens a closed popup, or closes an open one.
+    Toggle(Popup),
+    /// Marks the menu item with the given id as active, or clears it with `None`.
+    SetActive(Option<usize>),
+    /// Closes every popup.
+    CloseAll,
+}
+
+/// Open/close state of a navbar, independent of any UI framework.
+///
+/// The state is a plain `Copy` value; [`NavbarState::apply`] returns the next state for an
+/// action without side effects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NavbarState {
+    /// Whether the viewport is at or below the mobile breakpoint.
+    pub is_mobile: bool,
+
+    /// Whether the mobile (hamburger) menu is expanded.
+    pub mobile_menu_open: bool,
+
+    /// Whether the profile dropdown is expanded.
+    pub dropdown_open: bool,
+
+    /// Whether the mega menu panel is shown.
+    pub mega_menu_open: bool,
+
+    /// Whether the notification feed is expanded.
+    pub notifications_open: bool,
+
+    /// Id of the currently active top-level menu item, if any.
+    pub active_item: Option<usize>,
+}
+
+impl NavbarState {
+    /// Creates a closed navbar state for the given layout.
+    pub fn new(is_mobile: bool) -> Self {
+        Self {
+            is_mobile,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a closed navbar state from a viewport width and mobile breakpoint.
+    ///
+    /// An unknown width (e.g. no `window`) assumes the desktop layout.
+    pub fn for_width(width: Option<f64>, mobile_breakpoint: f64) -> Self {
+        Self::new(width.is_some_and(|width| is_mobile_width(width, mobile_breakpoint)))
+    }
+
+    /// Returns `true` if the given popup is open.
+    pub fn is_open(&self, popup: Popup) -> bool {
+        match popup {
+            Popup::MobileMenu => self.mobile_menu_open,
+            Popup::Dropdown => self.dropdown_open,
+            Popup::MegaMenu => self.mega_menu_open,
+            Popup::Notifications => self.notifications_open,
+        }
+    }
+
+    /// Returns `true` if any popup (mobile menu, dropdown, mega menu or notifications) is open.
+    pub fn any_open(&self) -> bool {
+        self.mobile_menu_open
+            || self.dropdown_open
+            || self.mega_menu_open
+            || self.notifications_open
+    }
+
+    fn with_popup(self, popup: Popup, open: bool) -> Self {
+        let mut next = if open {
+            self.apply(NavbarAction::CloseAll)
+        } else {
+            self
+        };
+        match popup {
+            Popup::MobileMenu => next.mobile_menu_open = open,
+            Popup::Dropdown => next.dropdown_open = open,
+            Popup::MegaMenu => next.mega_menu_open = open,
+            Popup::Notifications => next.notifications_open = open,
+        }
+        next
+    }
+
+    /// Returns the state that follows `action`.
+    ///
+    /// Only one popup is open at a time: opening one closes the others. Switching layouts
+    /// closes every popup, since the new layout renders a different set of them.
+    ///
+    /// ```rust
+    /// use navbar::core::{NavbarAction, NavbarState, Popup};
+    ///
+    /// let state = NavbarState::new(false)
+    ///     .apply(NavbarAction::Open(Popup::MegaMenu))
+    ///     .apply(NavbarAction::Toggle(Popup::Dropdown));
+    /// assert!(state.dropdown_open);
+    /// assert!(!state.mega_menu_open);
+    ///
+    /// let state = state.apply(NavbarAction::SetMobile(true));
+    /// assert!(!state.any_open());
+    /// ```
+    #[must_use]
+    pub fn apply(self, action: NavbarAction) -> Self {
+        match action {
+            NavbarAction::SetMobile(is_mobile) if is_mobile == self.is_mobile => self,
+            NavbarAction::SetMobile(is_mobile) => Self {
+                is_mobile,
+                active_item: self.active_item,
+                ..Self::default()
+            },
+            NavbarAction::Open(popup) => self.with_popup(popup, true),
+            NavbarAction::Close(popup) => self.with_popup(popup, false),
+            NavbarAction::Toggle(popup) => self.with_popup(popup, !self.is_open(popup)),
+            NavbarAction::SetActive(active_item) => Self {
+                active_item,
+                ..self
+            },
+            NavbarAction::CloseAll => Self {
+                mobile_menu_open: false,
+                dropdown_open: false,
+                mega_menu_open: false,
+                notifications_open: false,
+                ..self
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open(popup: Popup) -> NavbarState {
+        NavbarState::new(false).apply(NavbarAction::Open(popup))
+    }
+
+    #[test]
+    fn open_closes_the_other_popups() {
+        for popup in Popup::ALL {
+            for other in Popup::ALL {
+                let state = open(other).apply(NavbarAction::Open(popup));
+                for checked in Popup::ALL {
+                    assert_eq!(
+                        state.is_open(checked),
+                        checked == popup,
+                        "{popup:?} after {other:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn close_only_closes_its_popup() {
+        let state = open(Popup::Notifications);
+        assert_eq!(state.apply(NavbarAction::Close(Popup::Dropdown)), state);
+        assert!(
+            !state
+                .apply(NavbarAction::Close(Popup::Notifications))
+                .any_open()
+        );
+    }
+
+    #[test]
+    fn toggle_opens_then_closes() {
+        for popup in Popup::ALL {
+            let state = NavbarState::new(false).apply(NavbarAction::Toggle(popup));
+            assert!(state.is_open(popup));
+            assert!(!state.apply(NavbarAction::Toggle(popup)).any_open());
+        }
+    }
+
+    #[test]
+    fn toggle_switches_from_another_popup() {
+        let state = open(Popup::Dropdown).apply(NavbarAction::Toggle(Popup::Notifications));
+        assert!(state.notifications_open);
+        assert!(!state.dropdown_open);
+    }
+
+    #[test]
+    fn set_mobile_resets_popups_and_keeps_the_active_item() {
+        let state = open(Popup::MegaMenu).apply(NavbarAction::SetActive(Some(3)));
+        let mobile = state.apply(NavbarAction::SetMobile(true));
+        assert!(mobile.is_mobile);
+        assert!(!mobile.any_open());
+        assert_eq!(mobile.active_item, Some(3));
+    }
+
+    #[test]
+    fn set_mobile_to_the_current_layout_keeps_popups() {
+        let state = NavbarState::new(true).apply(NavbarAction::Open(Popup::MobileMenu));
+        assert_eq!(state.apply(NavbarAction::SetMobile(true)), state);
+    }
+
+    #[test]
+    fn set_active_keeps_popups() {
+        let state = open(Popup::Notifications).apply(NavbarAction::SetActive(Some(2)));
+        assert_eq!(state.active_item, Some(2));
+        assert!(state.notifications_open);
+        assert_eq!(state.apply(NavbarAction::SetActive(None)).active_item, None);
+    }
+
+    #[test]
+    fn close_all_keeps_layout_and_active_item() {
+        let state = NavbarState::new(true)
+            .apply(NavbarAction::SetActive(Some(1)))
+            .apply(NavbarAction::Open(Popup::Notifications))
+            .apply(NavbarAction::CloseAll);
+        assert!(!state.any_open());
+        assert!(state.is_mobile);
+        assert_eq!(state.active_item, Some(1));
+    }
+
+    #[test]
+    fn for_width_switches_at_the_breakpoint() {
+        assert!(NavbarState::for_width(Some(768.0), 768.0).is_mobile);
+        assert!(!NavbarState::for_width(Some(768.5), 768.0).is_mobile);
+        assert!(!NavbarState::for_width(None, 768.0).is_mobile);
+    }
+}
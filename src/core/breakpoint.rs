@@ -0,0 +1,308 @@
+//! Responsive breakpoints and viewport classification.
+
+use super::Text;
+
+/// Default viewport width, in pixels, at or below which the navbar switches to its mobile layout.
+pub const MOBILE_BREAKPOINT: f64 = 768.0;
+
+/// Returns `true` if a viewport of the given width should use the mobile layout.
+///
+/// The mobile layout applies at or below `breakpoint`, mirroring a `(max-width: ...)` media query.
+///
+/// ```rust
+/// use navbar::core::{MOBILE_BREAKPOINT, is_mobile_width};
+///
+/// assert!(is_mobile_width(768.0, MOBILE_BREAKPOINT));
+/// assert!(!is_mobile_width(1024.0, MOBILE_BREAKPOINT));
+/// assert!(is_mobile_width(1024.0, 1024.0));
+/// ```
+pub fn is_mobile_width(width: f64, breakpoint: f64) -> bool {
+    width <= breakpoint
+}
+
+/// Returns `true` if a `User-Agent` header looks like a phone or other small-screen device.
+///
+/// This is a hint for the first render on the server, where there is no viewport to measure; the
+/// navbar switches to the real layout once it runs in the browser.
+///
+/// ```rust
+/// use navbar::core::is_mobile_user_agent;
+///
+/// assert!(is_mobile_user_agent(
+///     "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 Mobile/15E148"
+/// ));
+/// assert!(!is_mobile_user_agent(
+///     "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 Chrome/124.0 Safari/537.36"
+/// ));
+/// ```
+pub fn is_mobile_user_agent(user_agent: &str) -> bool {
+    const MOBILE_TOKENS: [&str; 7] = [
+        "Mobi",
+        "Android",
+        "iPhone",
+        "iPod",
+        "BlackBerry",
+        "Opera Mini",
+        "IEMobile",
+    ];
+    MOBILE_TOKENS.iter().any(|token| user_agent.contains(token))
+}
+
+/// Layout the navbar renders before it can measure the viewport.
+///
+/// On the server, and on the first (hydrating) render in the browser, the navbar uses this layout
+/// and switches to the measured one once mounted. With [`InitialLayout::Viewport`] the browser
+/// measures the viewport right away, which avoids a switch in client-side rendered apps but makes
+/// the first render differ from server-rendered markup on mobile; pick one of the other layouts
+/// when server rendering.
+///
+/// ```rust
+/// use navbar::core::InitialLayout;
+///
+/// assert!(!InitialLayout::Viewport.is_mobile(None));
+/// assert!(InitialLayout::Mobile.is_mobile(None));
+/// assert!(InitialLayout::UserAgent("Mozilla/5.0 (Linux; Android 14) Mobile".into()).is_mobile(None));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InitialLayout {
+    /// The measured viewport in the browser, or the desktop layout without one.
+    #[default]
+    Viewport,
+    /// The desktop layout.
+    Desktop,
+    /// The mobile layout.
+    Mobile,
+    /// The mobile layout if the `User-Agent` header looks like a mobile device (see
+    /// [`is_mobile_user_agent`]), else the desktop layout.
+    UserAgent(Text),
+}
+
+impl InitialLayout {
+    /// Returns `true` if the first render uses the mobile layout, given the measured viewport.
+    pub fn is_mobile(&self, viewport: Option<Viewport>) -> bool {
+        match self {
+            Self::Viewport => viewport.is_some_and(|v| v.is_mobile),
+            Self::Desktop => false,
+            Self::Mobile => true,
+            Self::UserAgent(user_agent) => is_mobile_user_agent(user_agent),
+        }
+    }
+}
+
+/// Class of the desktop block (menus, search, button, profile menu) in the CSS responsive mode.
+pub const DESKTOP_CLASS: &str = "navbar-desktop";
+
+/// Class of the mobile block (the toggle and the mobile menu) in the CSS responsive mode.
+pub const MOBILE_CLASS: &str = "navbar-mobile";
+
+/// Returns the stylesheet of the CSS responsive mode for the given mobile breakpoint.
+///
+/// In that mode the navbar renders both its desktop and mobile blocks, marks its `<nav>` with
+/// `data-navbar-breakpoint="<breakpoint>"`, and lets these media queries show one of them, so the
+/// right layout is painted before any script runs. The rules are scoped to that attribute, so
+/// navbars with different breakpoints can share a page. The queries mirror
+/// [`is_mobile_width`]: the mobile block shows at or below the breakpoint.
+///
+/// ```rust
+/// use navbar::core::responsive_css;
+///
+/// let css = responsive_css(768.0);
+/// assert!(css.contains("@media (max-width: 768px)"));
+/// assert!(css.contains("@media not all and (max-width: 768px)"));
+/// assert!(css.contains("[data-navbar-breakpoint=\"768\"] .navbar-desktop"));
+/// ```
+pub fn responsive_css(mobile_breakpoint: f64) -> String {
+    let scope = format!("[data-navbar-breakpoint=\"{mobile_breakpoint}\"]");
+    format!(
+        "@media (max-width: {mobile_breakpoint}px) {{ {scope} .{DESKTOP_CLASS} {{ display: none !important; }} }} \
+         @media not all and (max-width: {mobile_breakpoint}px) {{ {scope} .{MOBILE_CLASS} {{ display: none !important; }} }} \
+         {scope} .{MOBILE_CLASS} > summary {{ list-style: none; }} \
+         {scope} .{MOBILE_CLASS} > summary::-webkit-details-marker {{ display: none; }}"
+    )
+}
+
+/// A named responsive breakpoint, ordered from narrowest to widest.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Narrower than or equal to the `sm` width.
+    #[default]
+    Xs,
+    /// Wider than `sm`, up to `md`.
+    Sm,
+    /// Wider than `md`, up to `lg`.
+    Md,
+    /// Wider than `lg`, up to `xl`.
+    Lg,
+    /// Wider than `xl`.
+    Xl,
+}
+
+/// Pixel widths of the named breakpoints.
+///
+/// A breakpoint applies once the viewport is wider than its width, so a `mobile_breakpoint` of
+/// `md` means the mobile layout is used for every breakpoint below [`Breakpoint::Md`].
+/// The defaults match Tailwind CSS (`640`, `768`, `1024`, `1280`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Breakpoints {
+    /// Width of the `sm` breakpoint.
+    pub sm: f64,
+    /// Width of the `md` breakpoint.
+    pub md: f64,
+    /// Width of the `lg` breakpoint.
+    pub lg: f64,
+    /// Width of the `xl` breakpoint.
+    pub xl: f64,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            sm: 640.0,
+            md: 768.0,
+            lg: 1024.0,
+            xl: 1280.0,
+        }
+    }
+}
+
+impl Breakpoints {
+    /// Returns the pixel width of a named breakpoint (`0.0` for [`Breakpoint::Xs`]).
+    pub fn width(&self, breakpoint: Breakpoint) -> f64 {
+        match breakpoint {
+            Breakpoint::Xs => 0.0,
+            Breakpoint::Sm => self.sm,
+            Breakpoint::Md => self.md,
+            Breakpoint::Lg => self.lg,
+            Breakpoint::Xl => self.xl,
+        }
+    }
+
+    /// Returns every width at which the layout or named breakpoint can change.
+    pub fn boundaries(&self, mobile_breakpoint: f64) -> [f64; 5] {
+        [mobile_breakpoint, self.sm, self.md, self.lg, self.xl]
+    }
+
+    /// Returns the named breakpoint a viewport of the given width falls into.
+    ///
+    /// ```rust
+    /// use navbar::core::{Breakpoint, Breakpoints};
+    ///
+    /// let bps = Breakpoints::default();
+    /// assert_eq!(bps.classify(375.0), Breakpoint::Xs);
+    /// assert_eq!(bps.classify(768.0), Breakpoint::Sm);
+    /// assert_eq!(bps.classify(769.0), Breakpoint::Md);
+    /// assert_eq!(bps.classify(1920.0), Breakpoint::Xl);
+    /// ```
+    pub fn classify(&self, width: f64) -> Breakpoint {
+        if width > self.xl {
+            Breakpoint::Xl
+        } else if width > self.lg {
+            Breakpoint::Lg
+        } else if width > self.md {
+            Breakpoint::Md
+        } else if width > self.sm {
+            Breakpoint::Sm
+        } else {
+            Breakpoint::Xs
+        }
+    }
+
+    /// Describes a viewport of the given width against these breakpoints and a mobile breakpoint.
+    pub fn viewport(&self, width: f64, mobile_breakpoint: f64) -> Viewport {
+        Viewport {
+            width,
+            breakpoint: self.classify(width),
+            is_mobile: is_mobile_width(width, mobile_breakpoint),
+        }
+    }
+}
+
+/// A snapshot of the viewport as seen by the navbar.
+///
+/// Passed to `on_breakpoint_change` callbacks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Viewport {
+    /// The viewport width in pixels.
+    pub width: f64,
+    /// The named breakpoint the width falls into.
+    pub breakpoint: Breakpoint,
+    /// Whether the navbar renders its mobile layout at this width.
+    pub is_mobile: bool,
+}
+
+impl Viewport {
+    /// Returns `true` if `other` is on a different breakpoint or layout than `self`.
+    ///
+    /// ```rust
+    /// use navbar::core::{Breakpoints, MOBILE_BREAKPOINT};
+    ///
+    /// let bps = Breakpoints::default();
+    /// let a = bps.viewport(800.0, MOBILE_BREAKPOINT);
+    /// assert!(!a.crossed(&bps.viewport(900.0, MOBILE_BREAKPOINT)));
+    /// assert!(a.crossed(&bps.viewport(700.0, MOBILE_BREAKPOINT)));
+    /// ```
+    pub fn crossed(&self, other: &Viewport) -> bool {
+        self.breakpoint != other.breakpoint || self.is_mobile != other.is_mobile
+    }
+}
+
+/// Remembers the last reported [`Viewport`] so that only breakpoint crossings are reported.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BreakpointTracker {
+    last: Option<Viewport>,
+}
+
+impl BreakpointTracker {
+    /// Records `viewport` and returns it if it differs in breakpoint or layout from the last one.
+    ///
+    /// The first observation is always reported.
+    ///
+    /// ```rust
+    /// use navbar::core::{BreakpointTracker, Breakpoints, MOBILE_BREAKPOINT};
+    ///
+    /// let bps = Breakpoints::default();
+    /// let mut tracker = BreakpointTracker::default();
+    /// assert!(tracker.observe(bps.viewport(800.0, MOBILE_BREAKPOINT)).is_some());
+    /// assert!(tracker.observe(bps.viewport(820.0, MOBILE_BREAKPOINT)).is_none());
+    /// assert!(tracker.observe(bps.viewport(600.0, MOBILE_BREAKPOINT)).is_some());
+    /// ```
+    pub fn observe(&mut self, viewport: Viewport) -> Option<Viewport> {
+        let crossed = self.last.is_none_or(|last| last.crossed(&viewport));
+        self.last = Some(viewport);
+        crossed.then_some(viewport)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mobile_width_includes_the_breakpoint() {
+        assert!(is_mobile_width(767.0, MOBILE_BREAKPOINT));
+        assert!(is_mobile_width(MOBILE_BREAKPOINT, MOBILE_BREAKPOINT));
+        assert!(!is_mobile_width(768.1, MOBILE_BREAKPOINT));
+    }
+
+    #[test]
+    fn classify_keeps_a_width_equal_to_a_breakpoint_below_it() {
+        let breakpoints = Breakpoints::default();
+        assert_eq!(breakpoints.classify(640.0), Breakpoint::Xs);
+        assert_eq!(breakpoints.classify(641.0), Breakpoint::Sm);
+        assert_eq!(breakpoints.classify(1024.0), Breakpoint::Md);
+        assert_eq!(breakpoints.classify(1280.0), Breakpoint::Lg);
+        assert_eq!(breakpoints.classify(1281.0), Breakpoint::Xl);
+    }
+
+    #[test]
+    fn viewport_is_mobile_at_the_mobile_breakpoint() {
+        let viewport = Breakpoints::default().viewport(768.0, MOBILE_BREAKPOINT);
+        assert!(viewport.is_mobile);
+        assert_eq!(viewport.breakpoint, Breakpoint::Sm);
+        assert!(
+            !Breakpoints::default()
+                .viewport(769.0, MOBILE_BREAKPOINT)
+                .is_mobile
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Keyboard navigation following the WAI-ARIA menubar, menu and combobox patterns.
+
+/// How the items of a menu are laid out, which decides the arrow keys that move between them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// Items in a row, like a menubar: `ArrowLeft`/`ArrowRight` move between them.
+    #[default]
+    Horizontal,
+    /// Items in a column, like a dropdown menu: `ArrowUp`/`ArrowDown` move between them.
+    Vertical,
+    /// Items in a wrapping row, like the mega menu cards: either arrow pair moves between them.
+    Both,
+}
+
+impl Orientation {
+    /// Returns the `aria-orientation` value for this layout, if it has one.
+    pub fn aria_orientation(self) -> Option<&'static str> {
+        match self {
+            Self::Horizontal => Some("horizontal"),
+            Self::Vertical => Some("vertical"),
+            Self::Both => None,
+        }
+    }
+}
+
+/// A key press that a menubar or menu responds to, following the WAI-ARIA menubar pattern.
+///
+/// ```rust
+/// use navbar::core::{MenuKey, Orientation};
+///
+/// let key = MenuKey::from_key("ArrowRight", Orientation::Horizontal).unwrap();
+/// assert_eq!(key, MenuKey::Next);
+///
+/// // Focus wraps around at either end.
+/// assert_eq!(key.target(Some(2), 3), Some(0));
+/// assert_eq!(MenuKey::Previous.target(Some(0), 3), Some(2));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MenuKey {
+    /// Focus the next item.
+    Next,
+    /// Focus the previous item.
+    Previous,
+    /// Focus the first item (`Home`).
+    First,
+    /// Focus the last item (`End`).
+    Last,
+    /// Open the submenu of the focused item.
+    Open,
+    /// Close the menu and return focus to its trigger (`Escape`, or `ArrowLeft` in a vertical
+    /// menu).
+    Close,
+    /// Focus is leaving the menu (`Tab`); close it without moving focus.
+    Leave,
+}
+
+impl MenuKey {
+    /// Maps a `KeyboardEvent.key` value pressed on a menu item to a command.
+    ///
+    /// Returns `None` for keys the browser should handle, such as `Enter` on a link.
+    pub fn from_key(key: &str, orientation: Orientation) -> Option<Self> {
+        use Orientation::{Both, Horizontal, Vertical};
+
+        match (key, orientation) {
+            ("ArrowRight", Horizontal | Both) | ("ArrowDown", Vertical | Both) => Some(Self::Next),
+            ("ArrowLeft", Horizontal | Both) | ("ArrowUp", Vertical | Both) => Some(Self::Previous),
+            ("ArrowDown", Horizontal) | ("ArrowRight", Vertical) => Some(Self::Open),
+            ("Home", _) => Some(Self::First),
+            ("End", _) => Some(Self::Last),
+            ("Escape", _) | ("ArrowLeft", Vertical) => Some(Self::Close),
+            ("Tab", _) => Some(Self::Leave),
+            _ => None,
+        }
+    }
+
+    /// Maps a `KeyboardEvent.key` value pressed on a menu button (a trigger such as "More" or
+    /// the profile avatar) to a command.
+    ///
+    /// `Enter`, `Space` and `ArrowDown` open the menu focusing its first item, `ArrowUp` opens
+    /// it focusing the last item, and `Escape` closes it.
+    ///
+    /// ```rust
+    /// use navbar::core::MenuKey;
+    ///
+    /// assert_eq!(MenuKey::from_trigger_key(" "), Some(MenuKey::First));
+    /// assert_eq!(MenuKey::from_trigger_key("ArrowUp"), Some(MenuKey::Last));
+    /// assert_eq!(MenuKey::from_trigger_key("a"), None);
+    /// ```
+    pub fn from_trigger_key(key: &str) -> Option<Self> {
+        match key {
+            "Enter" | " " | "ArrowDown" => Some(Self::First),
+            "ArrowUp" => Some(Self::Last),
+            "Escape" => Some(Self::Close),
+            _ => None,
+        }
+    }
+
+    /// Maps a `KeyboardEvent.key` value pressed on a menu item that has a submenu to the item
+    /// of the submenu that receives focus once it opens.
+    ///
+    /// `Enter`, `Space` and the key that opens a submenu in this `orientation` (`ArrowDown` in a
+    /// menubar, `ArrowRight` in a vertical menu) focus its first item; `ArrowUp` in a menubar
+    /// focuses the last.
+    ///
+    /// ```rust
+    /// use navbar::core::{MenuKey, Orientation};
+    ///
+    /// assert_eq!(MenuKey::submenu_focus("ArrowDown", Orientation::Horizontal), Some(MenuKey::First));
+    /// assert_eq!(MenuKey::submenu_focus("ArrowRight", Orientation::Vertical), Some(MenuKey::First));
+    /// assert_eq!(MenuKey::submenu_focus("ArrowUp", Orientation::Horizontal), Some(MenuKey::Last));
+    /// assert_eq!(MenuKey::submenu_focus("ArrowUp", Orientation::Vertical), None);
+    /// ```
+    pub fn submenu_focus(key: &str, orientation: Orientation) -> Option<Self> {
+        match (key, orientation) {
+            ("Enter" | " ", _) => Some(Self::First),
+            ("ArrowUp", Orientation::Horizontal) => Some(Self::Last),
+            _ => match Self::from_key(key, orientation) {
+                Some(Self::Open) => Some(Self::First),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the index of the item that receives focus after this key, for a menu of `len`
+    /// items where `current` is focused.
+    ///
+    /// Without a focused item, `Next` starts at the first item and `Previous` at the last.
+    /// Returns `None` for keys that do not move focus, or for an empty menu.
+    pub fn target(self, current: Option<usize>, len: usize) -> Option<usize> {
+        if len == 0 {
+            return None;
+        }
+        match self {
+            Self::Next => Some(current.map_or(0, |index| (index + 1) % len)),
+            Self::Previous => Some(current.map_or(len - 1, |index| (index % len + len - 1) % len)),
+            Self::First => Some(0),
+            Self::Last => Some(len - 1),
+            Self::Open | Self::Close | Self::Leave => None,
+        }
+    }
+}
+
+/// A key press on a combobox input (the search field) that moves through its listbox.
+///
+/// Other keys, including `Home`, `End` and the horizontal arrows, stay with the text input.
+///
+/// ```rust
+/// use navbar::core::ComboboxKey;
+///
+/// let key = ComboboxKey::from_key("ArrowDown").unwrap();
+/// assert_eq!(key.target(None, 3), Some(0));
+/// assert_eq!(ComboboxKey::Previous.target(Some(0), 3), Some(2));
+/// assert_eq!(ComboboxKey::from_key("Home"), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ComboboxKey {
+    /// Highlight the next option (`ArrowDown`).
+    Next,
+    /// Highlight the previous option (`ArrowUp`).
+    Previous,
+    /// Choose the highlighted option (`Enter`).
+    Select,
+    /// Close the listbox (`Escape`).
+    Close,
+}
+
+impl ComboboxKey {
+    /// Maps a `KeyboardEvent.key` value pressed in the combobox input to a command.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "ArrowDown" => Some(Self::Next),
+            "ArrowUp" => Some(Self::Previous),
+            "Enter" => Some(Self::Select),
+            "Escape" => Some(Self::Close),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the option highlighted after this key, for a listbox of `len`
+    /// options where `current` is highlighted.
+    ///
+    /// Highlighting wraps around at either end, like [`MenuKey::target`].
+    pub fn target(self, current: Option<usize>, len: usize) -> Option<usize> {
+        match self {
+            Self::Next => MenuKey::Next.target(current, len),
+            Self::Previous => MenuKey::Previous.target(current, len),
+            Self::Select | Self::Close => None,
+        }
+    }
+}
+
+/// Returns the index of the element that `Tab` (or `Shift+Tab` when `backwards`) should focus to
+/// keep focus inside a modal panel with `len` focusable elements, where `current` is focused.
+///
+/// Focus wraps from the last element to the first and back; focus outside the elements (e.g. on
+/// the panel itself) moves to the first or, backwards, the last. Returns `None` when the browser's
+/// own tab order already stays inside the panel, or when there is nothing to focus.
+///
+/// ```rust
+/// use navbar::core::focus_trap_target;
+///
+/// assert_eq!(focus_trap_target(Some(2), 3, false), Some(0));
+/// assert_eq!(focus_trap_target(Some(0), 3, true), Some(2));
+/// assert_eq!(focus_trap_target(Some(1), 3, false), None);
+/// assert_eq!(focus_trap_target(None, 3, false), Some(0));
+/// ```
+pub fn focus_trap_target(current: Option<usize>, len: usize, backwards: bool) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    match (current, backwards) {
+        (None, false) => Some(0),
+        (None, true) => Some(last),
+        (Some(index), false) if index >= last => Some(0),
+        (Some(0), true) => Some(last),
+        _ => None,
+    }
+}
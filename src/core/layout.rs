@@ -0,0 +1,51 @@
+//! Order and visibility of the built-in pieces of the navbar.
+
+/// A built-in piece of the navbar, as listed in its `parts` prop.
+///
+/// The desktop layout renders the listed parts in order, after the logo and any `start` and
+/// `center` content; parts left out are hidden, on mobile too. The `show_*` props still apply
+/// to the parts they govern, so a listed search bar only shows with `show_search`.
+///
+/// The enum is `#[non_exhaustive]`, so new parts can be added without breaking `match`es on it.
+///
+/// ```rust
+/// use navbar::core::NavbarPart;
+///
+/// // The profile menu first, then the links; no search bar, "More" button, call-to-action or
+/// // notifications, and no theme toggle.
+/// let parts = vec![NavbarPart::Profile, NavbarPart::Menu];
+/// assert!(!parts.contains(&NavbarPart::Search));
+/// assert_eq!(NavbarPart::DEFAULT_ORDER[0], NavbarPart::Menu);
+/// assert_eq!(NavbarPart::DEFAULT_ORDER.last(), Some(&NavbarPart::ThemeToggle));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NavbarPart {
+    /// The top-level menu links.
+    Menu,
+    /// The "More" button and its mega menu (with `show_mega_menu`).
+    MegaMenu,
+    /// The search bar (with `show_search`).
+    Search,
+    /// The call-to-action button (with a non-empty `button_text`).
+    Button,
+    /// The notification bell and its feed (with `show_notifications`).
+    Notifications,
+    /// The profile menu (with `show_profile_menu`).
+    Profile,
+    /// The light/dark/system theme toggle (with `show_theme_toggle`).
+    ThemeToggle,
+}
+
+impl NavbarPart {
+    /// Every part, in the order the navbar renders them by default.
+    pub const DEFAULT_ORDER: [Self; 7] = [
+        Self::Menu,
+        Self::MegaMenu,
+        Self::Search,
+        Self::Button,
+        Self::Notifications,
+        Self::Profile,
+        Self::ThemeToggle,
+    ];
+}
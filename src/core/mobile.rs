@@ -0,0 +1,75 @@
+//! Layouts of the mobile menu: a dropdown under the toggle, off-canvas drawers and a full-screen
+//! sheet.
+
+use super::Transition;
+
+/// How the mobile menu is laid out when it opens.
+///
+/// The drawers and the sheet are modal: they cover the page with a backdrop, keep keyboard focus
+/// inside the menu, lock the page scroll while open, and close on `Escape` or a click on the
+/// backdrop.
+///
+/// ```rust
+/// use navbar::core::MobileVariant;
+///
+/// assert!(!MobileVariant::Dropdown.is_modal());
+/// assert!(MobileVariant::DrawerLeft.is_modal());
+/// assert!(MobileVariant::DrawerRight.panel_style().contains("right: 0;"));
+/// assert!(MobileVariant::Dropdown.transition(200).is_none());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MobileVariant {
+    /// A box under the hamburger toggle, positioned by the menu's own style.
+    #[default]
+    Dropdown,
+    /// A full-height panel sliding in from the left edge.
+    DrawerLeft,
+    /// A full-height panel sliding in from the right edge.
+    DrawerRight,
+    /// A panel covering the whole viewport, sliding up from the bottom.
+    Sheet,
+}
+
+impl MobileVariant {
+    /// Returns `true` if the menu is shown as a modal panel over a backdrop.
+    pub fn is_modal(self) -> bool {
+        self != Self::Dropdown
+    }
+
+    /// Returns the positioning style appended to the menu's container style.
+    ///
+    /// It only sets placement and size, so colors, padding and the like still come from the
+    /// container style.
+    pub fn panel_style(self) -> &'static str {
+        match self {
+            Self::Dropdown => "",
+            Self::DrawerLeft => {
+                "position: fixed; top: 0; bottom: 0; left: 0; right: auto; width: min(20rem, 85vw); max-width: 100%; overflow-y: auto; z-index: 1001;"
+            }
+            Self::DrawerRight => {
+                "position: fixed; top: 0; bottom: 0; left: auto; right: 0; width: min(20rem, 85vw); max-width: 100%; overflow-y: auto; z-index: 1001;"
+            }
+            Self::Sheet => {
+                "position: fixed; top: 0; bottom: 0; left: 0; right: 0; width: auto; overflow-y: auto; z-index: 1001;"
+            }
+        }
+    }
+
+    /// Returns the slide-in transition of the panel over `duration_ms`, or `None` for the
+    /// dropdown.
+    pub fn transition(self, duration_ms: u32) -> Option<Transition> {
+        let hidden = match self {
+            Self::Dropdown => return None,
+            Self::DrawerLeft => "transform: translateX(-100%);",
+            Self::DrawerRight => "transform: translateX(100%);",
+            Self::Sheet => "transform: translateY(100%);",
+        };
+        let transition = format!("transition: transform {duration_ms}ms ease;");
+        Some(
+            Transition::new(duration_ms + 50)
+                .with_enter(hidden, "")
+                .with_entering(format!("transform: none; {transition}"), "")
+                .with_exit(format!("{hidden} {transition}"), ""),
+        )
+    }
+}
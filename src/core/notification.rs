@@ -0,0 +1,100 @@
+//! Notification feed of the notification menu and its unread badge.
+
+use super::Text;
+
+/// Unread count above which the badge shows `"{max}+"`, by default.
+pub const BADGE_MAX: usize = 99;
+
+/// Path of the 24×24 bell icon drawn in the notification button.
+pub const BELL_ICON_PATH: &str =
+    "M18 8a6 6 0 0 0-12 0c0 7-3 9-3 9h18s-3-2-3-9M13.73 21a2 2 0 0 1-3.46 0";
+
+/// Style that hides text visually while keeping it available to screen readers.
+pub const VISUALLY_HIDDEN_STYLE: &str = "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+/// An entry of the notification feed.
+///
+/// ```rust
+/// use navbar::core::{Notification, unread_count};
+///
+/// let feed = vec![
+///     Notification::new(1, "Build passed", "2 min ago"),
+///     Notification::new(2, "New follower", "1 h ago").with_read(true),
+/// ];
+/// assert_eq!(unread_count(&feed), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Notification {
+    /// Unique identifier for the notification.
+    pub id: usize,
+
+    /// What happened, e.g. "Your build passed".
+    pub title: Text,
+
+    /// When it happened, as displayed, e.g. "5 min ago".
+    pub time: Text,
+
+    /// Whether the user has seen it.
+    pub read: bool,
+}
+
+impl Notification {
+    /// Creates an unread notification.
+    pub fn new(id: usize, title: impl Into<Text>, time: impl Into<Text>) -> Self {
+        Self {
+            id,
+            title: title.into(),
+            time: time.into(),
+            read: false,
+        }
+    }
+
+    /// Sets whether the notification has been read.
+    pub fn with_read(self, read: bool) -> Self {
+        Self { read, ..self }
+    }
+}
+
+/// Returns the number of unread notifications.
+pub fn unread_count(notifications: &[Notification]) -> usize {
+    notifications.iter().filter(|n| !n.read).count()
+}
+
+/// Returns the text of the unread badge: `None` with nothing unread, and `"{max}+"` above `max`.
+///
+/// ```rust
+/// use navbar::core::{BADGE_MAX, badge_text};
+///
+/// assert_eq!(badge_text(0, BADGE_MAX), None);
+/// assert_eq!(badge_text(7, BADGE_MAX).as_deref(), Some("7"));
+/// assert_eq!(badge_text(120, BADGE_MAX).as_deref(), Some("99+"));
+/// ```
+pub fn badge_text(count: usize, max: usize) -> Option<String> {
+    match count {
+        0 => None,
+        count if count > max => Some(format!("{max}+")),
+        count => Some(count.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn badge_text_is_hidden_without_unread_items() {
+        assert_eq!(badge_text(0, BADGE_MAX), None);
+    }
+
+    #[test]
+    fn badge_text_shows_counts_up_to_the_max() {
+        assert_eq!(badge_text(1, BADGE_MAX).as_deref(), Some("1"));
+        assert_eq!(badge_text(BADGE_MAX, BADGE_MAX).as_deref(), Some("99"));
+    }
+
+    #[test]
+    fn badge_text_caps_counts_over_the_max() {
+        assert_eq!(badge_text(BADGE_MAX + 1, BADGE_MAX).as_deref(), Some("99+"));
+        assert_eq!(badge_text(10, 9).as_deref(), Some("9+"));
+    }
+}
@@ -0,0 +1,320 @@
+//! Command palette model: navbar items and app actions, filtered, ranked and grouped by section.
+
+use super::search::fuzzy_score;
+use super::{DropdownItem, MegaMenuItem, Menu, Text};
+use std::fmt;
+use std::rc::Rc;
+
+/// An app action run from the command palette.
+pub type CommandFn = Rc<dyn Fn()>;
+
+/// What choosing a [`Command`] does.
+#[derive(Clone)]
+pub enum CommandAction {
+    /// Navigates to a link, like clicking the navbar item.
+    Navigate(Text),
+    /// Runs an app action.
+    Run(CommandFn),
+}
+
+impl PartialEq for CommandAction {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Navigate(a), Self::Navigate(b)) => a == b,
+            (Self::Run(a), Self::Run(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for CommandAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Navigate(link) => f.debug_tuple("Navigate").field(link).finish(),
+            Self::Run(_) => f.write_str("Run(..)"),
+        }
+    }
+}
+
+/// An entry of the command palette: a link or an app action.
+///
+/// ```rust
+/// use navbar::core::{Command, CommandAction};
+///
+/// let docs = Command::link("Docs", "/docs");
+/// assert_eq!(docs.action, CommandAction::Navigate("/docs".into()));
+///
+/// let theme = Command::action("toggle-theme", "Toggle dark mode", || {}).with_section("Preferences");
+/// assert_eq!(theme.section, "Preferences");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Command {
+    /// Stable identifier, used to remember recently used commands.
+    pub id: Text,
+
+    /// The text shown for the command.
+    pub label: Text,
+
+    /// Optional secondary text shown under the label.
+    pub description: Option<Text>,
+
+    /// The section the command is grouped under.
+    pub section: Text,
+
+    /// What choosing the command does.
+    pub action: CommandAction,
+}
+
+impl Command {
+    /// Creates a command navigating to `link`, in the "Pages" section. Its id is the link.
+    pub fn link(label: impl Into<Text>, link: impl Into<Text>) -> Self {
+        let link = link.into();
+        Self {
+            id: link.clone(),
+            label: label.into(),
+            description: None,
+            section: "Pages".into(),
+            action: CommandAction::Navigate(link),
+        }
+    }
+
+    /// Creates a command running `action`, in the "Actions" section.
+    pub fn action(
+        id: impl Into<Text>,
+        label: impl Into<Text>,
+        action: impl Fn() + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            description: None,
+            section: "Actions".into(),
+            action: CommandAction::Run(Rc::new(action)),
+        }
+    }
+
+    /// Sets the section the command is grouped under.
+    pub fn with_section(self, section: impl Into<Text>) -> Self {
+        Self {
+            section: section.into(),
+            ..self
+        }
+    }
+
+    /// Sets the secondary text shown under the label.
+    pub fn with_description(self, description: impl Into<Text>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+}
+
+/// Commands listed under one section heading of the palette.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommandGroup {
+    /// The section heading.
+    pub section: Text,
+
+    /// The commands of the section, in display order.
+    pub commands: Vec<Command>,
+}
+
+/// The commands of a palette: navbar items and app actions.
+///
+/// Navbar items with a link become [`Command::link`]s in the given section; a submenu item is
+/// described by the names of its parent items.
+///
+/// ```rust
+/// use navbar::core::{Command, CommandSet, Menu};
+///
+/// let commands = CommandSet::new()
+///     .with_menus("Pages", &[Menu::<()>::new(1, "Pricing", "/pricing"), Menu::new(2, "Blog", "/blog")])
+///     .with_commands(vec![Command::action("logout", "Log out", || {}).with_section("Account")]);
+///
+/// // Without a query, every command is listed by section, after the recently used ones.
+/// let groups = commands.filter("", &["/blog".to_string()], "Recent");
+/// let sections: Vec<_> = groups.iter().map(|g| g.section.as_ref()).collect();
+/// assert_eq!(sections, ["Recent", "Pages", "Account"]);
+/// assert_eq!(groups[0].commands[0].label, "Blog");
+///
+/// // A query keeps the fuzzy matches, best first.
+/// let groups = commands.filter("lo", &[], "Recent");
+/// assert_eq!(groups[0].commands[0].label, "Log out");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CommandSet {
+    commands: Vec<Command>,
+}
+
+impl CommandSet {
+    /// Creates an empty command set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds menu items and, recursively, their submenus, under `section`.
+    pub fn with_menus<I>(mut self, section: &str, menus: &[Menu<I>]) -> Self {
+        self.add_menus(section, menus, None);
+        self
+    }
+
+    /// Adds dropdown items, such as the profile menu, under `section`.
+    pub fn with_dropdown_items<I>(mut self, section: &str, items: &[DropdownItem<I>]) -> Self {
+        for item in items {
+            self.add_link(section, item.label.clone(), None, &item.link);
+        }
+        self
+    }
+
+    /// Adds mega menu items under `section`.
+    pub fn with_mega_menu_items(mut self, section: &str, items: &[MegaMenuItem]) -> Self {
+        for item in items {
+            let description = Some(item.description.clone());
+            self.add_link(section, item.title.clone(), description, &item.link);
+        }
+        self
+    }
+
+    /// Adds app commands, each under its own section.
+    pub fn with_commands(mut self, commands: impl IntoIterator<Item = Command>) -> Self {
+        self.commands.extend(commands);
+        self
+    }
+
+    /// Returns the command with the given id.
+    pub fn get(&self, id: &str) -> Option<&Command> {
+        self.commands.iter().find(|command| command.id == id)
+    }
+
+    /// Returns the commands to list for `query`, grouped by section.
+    ///
+    /// Without a query, the commands whose ids are in `recent` come first, under
+    /// `recent_section`, followed by every command grouped by section in the order they were
+    /// added. With a query, only fuzzy matches on the label (or, ranked lower, the description)
+    /// are kept, best match first; sections are ordered by their best match.
+    pub fn filter(
+        &self,
+        query: &str,
+        recent: &[String],
+        recent_section: &str,
+    ) -> Vec<CommandGroup> {
+        let mut groups: Vec<CommandGroup> = Vec::new();
+        let mut add = |section: &Text, command: &Command| match groups
+            .iter_mut()
+            .find(|group| &group.section == section)
+        {
+            Some(group) => group.commands.push(command.clone()),
+            None => groups.push(CommandGroup {
+                section: section.clone(),
+                commands: vec![command.clone()],
+            }),
+        };
+
+        if query.trim().is_empty() {
+            let recent_section = Text::from(recent_section.to_string());
+            for command in recent.iter().filter_map(|id| self.get(id)) {
+                add(&recent_section, command);
+            }
+            for command in &self.commands {
+                add(&command.section, command);
+            }
+        } else {
+            let mut ranked: Vec<_> = self
+                .commands
+                .iter()
+                .filter_map(|command| {
+                    let label = fuzzy_score(&command.label, query);
+                    let description = command
+                        .description
+                        .as_deref()
+                        .and_then(|description| fuzzy_score(description, query))
+                        .map(|score| score / 2);
+                    Some((label.max(description)?, command))
+                })
+                .collect();
+            ranked.sort_by(|(a, _), (b, _)| b.cmp(a));
+            for (_, command) in ranked {
+                add(&command.section, command);
+            }
+        }
+        groups
+    }
+
+    fn add_menus<I>(&mut self, section: &str, menus: &[Menu<I>], parent: Option<&str>) {
+        for menu in menus {
+            let description = parent.map(|parent| Text::from(parent.to_string()));
+            self.add_link(section, menu.name.clone(), description, &menu.link);
+            let trail = match parent {
+                Some(parent) => format!("{parent} › {}", menu.name),
+                None => menu.name.to_string(),
+            };
+            self.add_menus(section, &menu.children, Some(&trail));
+        }
+    }
+
+    fn add_link(&mut self, section: &str, label: Text, description: Option<Text>, link: &Text) {
+        if link.is_empty() || link == "#" {
+            return;
+        }
+        let mut command = Command::link(label, link.clone()).with_section(section.to_string());
+        command.description = description;
+        self.commands.push(command);
+    }
+}
+
+/// Ids of the most recently used commands, most recent first.
+///
+/// ```rust
+/// use navbar::core::RecentCommands;
+///
+/// let mut recent = RecentCommands::new(2);
+/// recent.push("/docs");
+/// recent.push("/blog");
+/// recent.push("/docs");
+/// recent.push("/pricing");
+/// assert_eq!(recent.ids(), ["/pricing", "/docs"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecentCommands {
+    ids: Vec<String>,
+    limit: usize,
+}
+
+impl RecentCommands {
+    /// Creates an empty list that remembers up to `limit` commands.
+    pub fn new(limit: usize) -> Self {
+        Self::from_ids(Vec::new(), limit)
+    }
+
+    /// Creates a list from previously saved ids, most recent first.
+    pub fn from_ids(mut ids: Vec<String>, limit: usize) -> Self {
+        ids.truncate(limit);
+        Self { ids, limit }
+    }
+
+    /// Records `id` as the most recently used command.
+    pub fn push(&mut self, id: &str) {
+        self.ids.retain(|recent| recent != id);
+        self.ids.insert(0, id.to_string());
+        self.ids.truncate(self.limit);
+    }
+
+    /// Returns the remembered ids, most recent first.
+    pub fn ids(&self) -> &[String] {
+        &self.ids
+    }
+}
+
+/// Returns `true` if a key press is the palette shortcut: `key` with `Ctrl` (or `Cmd` on macOS).
+///
+/// ```rust
+/// use navbar::core::is_palette_shortcut;
+///
+/// assert!(is_palette_shortcut("K", "k", true, false));
+/// assert!(is_palette_shortcut("k", "k", false, true));
+/// assert!(!is_palette_shortcut("k", "k", false, false));
+/// ```
+pub fn is_palette_shortcut(pressed: &str, key: &str, ctrl: bool, meta: bool) -> bool {
+    (ctrl || meta) && pressed.eq_ignore_ascii_case(key)
+}
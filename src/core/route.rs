@@ -0,0 +1,142 @@
+//! Matching menu item links against the current location.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// A custom active-route matcher, called with `(link, path)`.
+pub type RouteMatcher = Arc<dyn Fn(&str, &str) -> bool + Send + Sync>;
+
+/// How a menu item's `link` is compared with the current path to decide whether it is active.
+///
+/// Links and paths are compared by their path component only: any scheme and host, query string,
+/// fragment and trailing slash are ignored. Links without a path (such as `"#"`) are never
+/// active.
+///
+/// ```rust
+/// use navbar::core::ActiveMatch;
+///
+/// assert!(ActiveMatch::Exact.is_active("/docs/", "/docs"));
+/// assert!(!ActiveMatch::Exact.is_active("/docs", "/docs/intro"));
+/// assert!(ActiveMatch::Prefix.is_active("/docs", "/docs/intro"));
+/// assert!(!ActiveMatch::Prefix.is_active("/docs", "/docsearch"));
+///
+/// // The root link only matches the root path, even in prefix mode.
+/// assert!(!ActiveMatch::Prefix.is_active("/", "/docs"));
+///
+/// let blog = ActiveMatch::custom(|link, path| link == "/blog" && path.starts_with("/posts/"));
+/// assert!(blog.is_active("/blog", "/posts/hello"));
+/// ```
+#[derive(Clone, Default)]
+pub enum ActiveMatch {
+    /// Active when the path equals the link.
+    #[default]
+    Exact,
+    /// Active when the path equals the link or is nested below it.
+    Prefix,
+    /// Active when the function returns `true` for `(link, path)`.
+    ///
+    /// The function receives the raw link and current path, without normalization.
+    Custom(RouteMatcher),
+}
+
+impl ActiveMatch {
+    /// Creates a [`ActiveMatch::Custom`] matcher from a function of `(link, path)`.
+    pub fn custom(matcher: impl Fn(&str, &str) -> bool + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(matcher))
+    }
+
+    /// Returns `true` if an item linking to `link` is active at `path`.
+    pub fn is_active(&self, link: &str, path: &str) -> bool {
+        let normalized = || Some((link_path(link)?, link_path(path)?));
+        match self {
+            Self::Custom(matcher) => matcher(link, path),
+            Self::Exact => normalized().is_some_and(|(link, path)| link == path),
+            Self::Prefix => normalized().is_some_and(|(link, path)| match link {
+                "/" => path == "/",
+                _ => path
+                    .strip_prefix(link)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/')),
+            }),
+        }
+    }
+}
+
+impl PartialEq for ActiveMatch {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Exact, Self::Exact) | (Self::Prefix, Self::Prefix) => true,
+            (Self::Custom(a), Self::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for ActiveMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exact => f.write_str("Exact"),
+            Self::Prefix => f.write_str("Prefix"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Returns the path component of a link or URL, without a trailing slash (except for `/`).
+fn link_path(link: &str) -> Option<&str> {
+    let link = match link.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("/", |start| &rest[start..]),
+        None => link,
+    };
+    let end = link.find(['?', '#']).unwrap_or(link.len());
+    let path = &link[..end];
+    if path.is_empty() {
+        return None;
+    }
+    let trimmed = path.trim_end_matches('/');
+    Some(if trimmed.is_empty() { "/" } else { trimmed })
+}
+
+/// Returns `true` if a link is an app-internal path that a client-side router can navigate to.
+#[cfg(any(feature = "yew-router", feature = "dioxus-router"))]
+pub(crate) fn is_route_link(link: &str) -> bool {
+    link.starts_with('/') && !link.starts_with("//")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn link_path_strips_query_hash_and_trailing_slash() {
+        assert_eq!(link_path("/docs/?page=2"), Some("/docs"));
+        assert_eq!(link_path("/docs#install"), Some("/docs"));
+        assert_eq!(link_path("/docs//"), Some("/docs"));
+        assert_eq!(link_path("https://example.com/docs?x#y"), Some("/docs"));
+        assert_eq!(link_path("https://example.com"), Some("/"));
+        assert_eq!(link_path("/"), Some("/"));
+        assert_eq!(link_path("#"), None);
+        assert_eq!(link_path("?q=1"), None);
+    }
+
+    #[test]
+    fn exact_match_ignores_query_hash_and_trailing_slash() {
+        assert!(ActiveMatch::Exact.is_active("/docs?tab=api", "/docs/"));
+        assert!(ActiveMatch::Exact.is_active("/docs/", "/docs#intro"));
+        assert!(ActiveMatch::Exact.is_active("https://example.com/docs", "/docs"));
+        assert!(!ActiveMatch::Exact.is_active("#", "/"));
+    }
+
+    #[test]
+    fn prefix_match_stops_at_segment_boundaries() {
+        assert!(ActiveMatch::Prefix.is_active("/docs/", "/docs/intro?x=1"));
+        assert!(!ActiveMatch::Prefix.is_active("/docs", "/docsearch"));
+        assert!(ActiveMatch::Prefix.is_active("/", "/?ref=home"));
+        assert!(!ActiveMatch::Prefix.is_active("/", "/blog"));
+    }
+
+    #[test]
+    fn custom_match_sees_the_raw_link_and_path() {
+        let matcher = ActiveMatch::custom(|link, path| link == "/docs/" && path == "/docs?x");
+        assert!(matcher.is_active("/docs/", "/docs?x"));
+    }
+}
@@ -0,0 +1,185 @@
+//! Scroll-driven navbar behavior: sticky, hide-on-scroll and shrink.
+
+/// Distance, in pixels, the page must scroll in one direction before a hidden navbar is shown
+/// again or a shown one is hidden, so small jitters do not toggle it.
+pub const SCROLL_TOLERANCE: f64 = 8.0;
+
+/// How the navbar reacts to the page scrolling.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ScrollBehavior {
+    /// Scrolls away with the page; scrolling is not tracked.
+    #[default]
+    Static,
+    /// Stays at the top of the viewport.
+    Sticky,
+    /// Stays at the top, slides out while scrolling down and back in while scrolling up.
+    ///
+    /// The navbar is never hidden within the given number of pixels from the top.
+    HideOnScroll(f64),
+    /// Stays at the top and switches to its scrolled style past the given number of pixels.
+    Shrink(f64),
+}
+
+impl ScrollBehavior {
+    /// Returns `true` if the behavior depends on the scroll position.
+    pub fn is_tracked(&self) -> bool {
+        matches!(self, Self::HideOnScroll(_) | Self::Shrink(_))
+    }
+
+    /// Returns the positioning style the behavior adds to the `<nav>`.
+    pub fn style(&self) -> &'static str {
+        match self {
+            Self::Static => "",
+            Self::Sticky | Self::Shrink(_) => "position: sticky; top: 0; z-index: 1000;",
+            Self::HideOnScroll(_) => {
+                "position: sticky; top: 0; z-index: 1000; transition: transform 0.2s ease-in-out;"
+            }
+        }
+    }
+}
+
+/// What the navbar shows for the current scroll position.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScrollState {
+    /// The navbar is slid out of view ([`ScrollBehavior::HideOnScroll`]).
+    pub hidden: bool,
+    /// The page is scrolled past the shrink threshold ([`ScrollBehavior::Shrink`]).
+    pub scrolled: bool,
+}
+
+impl ScrollState {
+    /// Returns the `<nav>` style: `base`, then the behavior's positioning, then the hidden or
+    /// scrolled styles that apply.
+    ///
+    /// ```rust
+    /// use navbar::core::{ScrollBehavior, ScrollState};
+    ///
+    /// let state = ScrollState { hidden: false, scrolled: true };
+    /// assert_eq!(
+    ///     state.nav_style(ScrollBehavior::Shrink(80.0), "padding: 1rem;", "padding: 0.5rem;"),
+    ///     "padding: 1rem; position: sticky; top: 0; z-index: 1000; padding: 0.5rem;",
+    /// );
+    /// ```
+    pub fn nav_style(&self, behavior: ScrollBehavior, base: &str, scrolled_style: &str) -> String {
+        let hidden = if self.hidden {
+            "transform: translateY(-100%);"
+        } else {
+            ""
+        };
+        let scrolled = if self.scrolled { scrolled_style } else { "" };
+        [base, behavior.style(), hidden, scrolled]
+            .into_iter()
+            .filter(|style| !style.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Derives a [`ScrollState`] from successive scroll positions.
+///
+/// ```rust
+/// use navbar::core::{ScrollBehavior, ScrollTracker};
+///
+/// let mut tracker = ScrollTracker::new(ScrollBehavior::HideOnScroll(64.0));
+/// assert!(!tracker.observe(40.0).hidden); // Still near the top.
+/// assert!(tracker.observe(300.0).hidden); // Scrolling down.
+/// assert!(tracker.observe(296.0).hidden); // Within the tolerance.
+/// assert!(!tracker.observe(200.0).hidden); // Scrolling up.
+///
+/// let mut tracker = ScrollTracker::new(ScrollBehavior::Shrink(80.0));
+/// assert!(!tracker.observe(80.0).scrolled);
+/// assert!(tracker.observe(81.0).scrolled);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollTracker {
+    behavior: ScrollBehavior,
+    /// Position where the current scroll direction started.
+    anchor: f64,
+    state: ScrollState,
+}
+
+impl ScrollTracker {
+    /// Creates a tracker for `behavior`, starting at the top of the page.
+    pub fn new(behavior: ScrollBehavior) -> Self {
+        Self {
+            behavior,
+            ..Self::default()
+        }
+    }
+
+    /// Records the vertical scroll position `y` and returns the resulting state.
+    pub fn observe(&mut self, y: f64) -> ScrollState {
+        match self.behavior {
+            ScrollBehavior::HideOnScroll(offset) => {
+                if y <= offset {
+                    self.state.hidden = false;
+                    self.anchor = y;
+                } else if self.state.hidden {
+                    // Follows the page down; only a scroll back up by the tolerance reveals it.
+                    self.anchor = self.anchor.max(y);
+                    if self.anchor - y >= SCROLL_TOLERANCE {
+                        self.state.hidden = false;
+                        self.anchor = y;
+                    }
+                } else {
+                    self.anchor = self.anchor.min(y);
+                    if y - self.anchor >= SCROLL_TOLERANCE {
+                        self.state.hidden = true;
+                        self.anchor = y;
+                    }
+                }
+            }
+            ScrollBehavior::Shrink(threshold) => self.state.scrolled = y > threshold,
+            ScrollBehavior::Static | ScrollBehavior::Sticky => {}
+        }
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hides_after_scrolling_down_by_the_tolerance() {
+        let mut tracker = ScrollTracker::new(ScrollBehavior::HideOnScroll(0.0));
+        assert!(!tracker.observe(SCROLL_TOLERANCE - 1.0).hidden);
+        assert!(tracker.observe(SCROLL_TOLERANCE).hidden);
+    }
+
+    #[test]
+    fn reveals_after_scrolling_up_by_the_tolerance() {
+        let mut tracker = ScrollTracker::new(ScrollBehavior::HideOnScroll(0.0));
+        assert!(tracker.observe(500.0).hidden);
+        assert!(tracker.observe(600.0).hidden);
+        // Measured from the lowest position reached, not from where it was hidden.
+        assert!(tracker.observe(600.0 - SCROLL_TOLERANCE + 1.0).hidden);
+        assert!(!tracker.observe(600.0 - SCROLL_TOLERANCE).hidden);
+    }
+
+    #[test]
+    fn small_jitter_does_not_toggle() {
+        let mut tracker = ScrollTracker::new(ScrollBehavior::HideOnScroll(0.0));
+        assert!(tracker.observe(100.0).hidden);
+        assert!(!tracker.observe(90.0).hidden);
+        for y in [94.0, 88.0, 95.0, 90.0] {
+            assert!(!tracker.observe(y).hidden, "at {y}");
+        }
+    }
+
+    #[test]
+    fn never_hides_within_the_offset() {
+        let mut tracker = ScrollTracker::new(ScrollBehavior::HideOnScroll(64.0));
+        assert!(!tracker.observe(64.0).hidden);
+        assert!(tracker.observe(200.0).hidden);
+        assert!(!tracker.observe(60.0).hidden);
+    }
+
+    #[test]
+    fn static_and_sticky_ignore_scrolling() {
+        for behavior in [ScrollBehavior::Static, ScrollBehavior::Sticky] {
+            let mut tracker = ScrollTracker::new(behavior);
+            assert_eq!(tracker.observe(1000.0), ScrollState::default());
+        }
+    }
+}
@@ -0,0 +1,439 @@
+//! Search suggestions shown under the navbar search field (WAI-ARIA combobox pattern).
+
+use super::{DropdownItem, MegaMenuItem, Menu, Text};
+use std::fmt;
+use std::future::Future;
+use std::ops::Range;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// A suggestion listed under the search field.
+///
+/// ```rust
+/// use navbar::core::Suggestion;
+///
+/// let suggestion = Suggestion::new(1, "Getting started")
+///     .with_description("Install and render a first navbar")
+///     .with_link("/docs/start");
+/// assert_eq!(suggestion.link.as_deref(), Some("/docs/start"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suggestion {
+    /// Unique identifier for the suggestion.
+    pub id: usize,
+
+    /// The text shown for the suggestion, with the matched part highlighted.
+    pub label: Text,
+
+    /// Optional secondary text shown under the label.
+    pub description: Option<Text>,
+
+    /// Optional destination; choosing the suggestion navigates to it.
+    pub link: Option<Text>,
+}
+
+impl Suggestion {
+    /// Creates a suggestion without a description or link.
+    pub fn new(id: usize, label: impl Into<Text>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            description: None,
+            link: None,
+        }
+    }
+
+    /// Sets the secondary text shown under the label.
+    pub fn with_description(self, description: impl Into<Text>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// Sets the destination that choosing the suggestion navigates to.
+    pub fn with_link(self, link: impl Into<Text>) -> Self {
+        Self {
+            link: Some(link.into()),
+            ..self
+        }
+    }
+}
+
+/// The future returned by an asynchronous [`SuggestionProvider`].
+pub type SuggestionFuture = Pin<Box<dyn Future<Output = Vec<Suggestion>>>>;
+
+/// A synchronous suggestion function, called with the query.
+pub type SuggestionFn = Rc<dyn Fn(&str) -> Vec<Suggestion>>;
+
+/// An asynchronous suggestion function, called with the query.
+pub type AsyncSuggestionFn = Rc<dyn Fn(String) -> SuggestionFuture>;
+
+/// Supplies the suggestions for a search query.
+///
+/// A synchronous provider is called on every keystroke. An asynchronous one (e.g. an HTTP
+/// request) is called once typing pauses, and responses to outdated queries are discarded.
+///
+/// ```rust
+/// use navbar::core::{Suggestion, SuggestionProvider};
+///
+/// let pages = ["Pricing", "Privacy", "Blog"];
+/// let provider = SuggestionProvider::new(move |query| {
+///     pages
+///         .iter()
+///         .enumerate()
+///         .filter(|(_, page)| page.to_lowercase().contains(&query.to_lowercase()))
+///         .map(|(id, page)| Suggestion::new(id, *page))
+///         .collect()
+/// });
+/// assert!(!provider.is_async());
+///
+/// let remote = SuggestionProvider::from_async(|query: String| async move {
+///     vec![Suggestion::new(0, format!("Results for {query}"))]
+/// });
+/// assert!(remote.is_async());
+/// ```
+#[derive(Clone)]
+pub enum SuggestionProvider {
+    /// Returns the suggestions for a query right away.
+    Sync(SuggestionFn),
+    /// Resolves the suggestions for a query later.
+    Async(AsyncSuggestionFn),
+}
+
+impl SuggestionProvider {
+    /// Creates a synchronous provider from a function of the query.
+    pub fn new(provider: impl Fn(&str) -> Vec<Suggestion> + 'static) -> Self {
+        Self::Sync(Rc::new(provider))
+    }
+
+    /// Creates an asynchronous provider from a function returning a future of the suggestions.
+    pub fn from_async<F>(provider: impl Fn(String) -> F + 'static) -> Self
+    where
+        F: Future<Output = Vec<Suggestion>> + 'static,
+    {
+        Self::Async(Rc::new(move |query| Box::pin(provider(query))))
+    }
+
+    /// Returns `true` if the suggestions are resolved asynchronously.
+    pub fn is_async(&self) -> bool {
+        matches!(self, Self::Async(_))
+    }
+}
+
+impl PartialEq for SuggestionProvider {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Sync(a), Self::Sync(b)) => Rc::ptr_eq(a, b),
+            (Self::Async(a), Self::Async(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for SuggestionProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sync(_) => f.write_str("Sync(..)"),
+            Self::Async(_) => f.write_str("Async(..)"),
+        }
+    }
+}
+
+/// The suggestions for the current query.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Suggestions {
+    /// No query (or no provider): the listbox is hidden.
+    #[default]
+    Idle,
+    /// An asynchronous provider has not answered the current query yet.
+    Loading,
+    /// The suggestions for the current query, possibly none.
+    Ready(Vec<Suggestion>),
+}
+
+impl Suggestions {
+    /// Returns the suggestions that can be chosen, empty unless [`Suggestions::Ready`].
+    pub fn items(&self) -> &[Suggestion] {
+        match self {
+            Self::Ready(items) => items,
+            Self::Idle | Self::Loading => &[],
+        }
+    }
+
+    /// Returns `true` if the listbox should be shown, with suggestions, a loading or an empty
+    /// state.
+    pub fn is_open(&self) -> bool {
+        !matches!(self, Self::Idle)
+    }
+}
+
+/// Splits `text` around the part that matches `query`, for highlighting.
+///
+/// The first case-insensitive occurrence of `query` is matched; failing that, the characters of
+/// a [fuzzy match](fuzzy_score). Returns `(segment, matched)` pairs in order, covering all of
+/// `text`.
+///
+/// ```rust
+/// use navbar::core::highlight_match;
+///
+/// assert_eq!(
+///     highlight_match("Release notes", "note"),
+///     vec![("Release ", false), ("note", true), ("s", false)]
+/// );
+/// assert_eq!(
+///     highlight_match("Release notes", "rn"),
+///     vec![("R", true), ("elease ", false), ("n", true), ("otes", false)]
+/// );
+/// assert_eq!(highlight_match("Blog", "docs"), vec![("Blog", false)]);
+/// ```
+pub fn highlight_match<'a>(text: &'a str, query: &str) -> Vec<(&'a str, bool)> {
+    let query = query.trim();
+    if query.is_empty() {
+        return vec![(text, false)];
+    }
+    let substring = text
+        .char_indices()
+        .find_map(|(start, _)| Some(start..start + match_len(&text[start..], query)?));
+    let ranges = match substring {
+        Some(range) => vec![range],
+        None => fuzzy_match(text, query).map_or_else(Vec::new, |m| m.ranges),
+    };
+
+    let mut segments = Vec::new();
+    let mut end = 0;
+    for range in ranges {
+        segments.push((&text[end..range.start], false));
+        end = range.end;
+        segments.push((&text[range], true));
+    }
+    segments.push((&text[end..], false));
+    segments.retain(|(segment, _)| !segment.is_empty());
+    if segments.is_empty() {
+        segments.push((text, false));
+    }
+    segments
+}
+
+/// Returns the byte length of the prefix of `text` that equals `query`, ignoring case.
+fn match_len(text: &str, query: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+    for expected in query.chars() {
+        let (_, found) = chars.next()?;
+        if !chars_eq(found, expected) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(text.len(), |(end, _)| end))
+}
+
+/// Returns `true` if two characters are equal, ignoring case.
+fn chars_eq(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// The characters of a text matched by a fuzzy query, and how well they match.
+struct FuzzyMatch {
+    score: i64,
+    ranges: Vec<Range<usize>>,
+}
+
+/// Matches the characters of `query` (ignoring case and whitespace) in order in `text`.
+fn fuzzy_match(text: &str, query: &str) -> Option<FuzzyMatch> {
+    let mut pending = query.chars().filter(|c| !c.is_whitespace()).peekable();
+    pending.peek()?;
+
+    let mut score = 0;
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut previous: Option<char> = None;
+    let mut last_match: Option<usize> = None;
+    for (index, (start, c)) in text.char_indices().enumerate() {
+        let Some(&expected) = pending.peek() else {
+            break;
+        };
+        if chars_eq(c, expected) {
+            pending.next();
+            let word_start = previous
+                .is_none_or(|p| !p.is_alphanumeric() || (p.is_lowercase() && c.is_uppercase()));
+            let gap = index - last_match.map_or(0, |last| last + 1);
+            score += 1 + if word_start { 8 } else { 0 } - gap.min(4) as i64;
+            let end = start + c.len_utf8();
+            match ranges.last_mut() {
+                Some(range) if last_match == Some(index - 1) => {
+                    score += 5;
+                    range.end = end;
+                }
+                _ => ranges.push(start..end),
+            }
+            last_match = Some(index);
+        }
+        previous = Some(c);
+    }
+
+    pending
+        .peek()
+        .is_none()
+        .then_some(FuzzyMatch { score, ranges })
+}
+
+/// Scores how well `query` fuzzily matches `text`, or returns `None` if it does not match.
+///
+/// Every character of `query` (ignoring case and whitespace) must appear in `text`, in order.
+/// Consecutive characters and characters at the start of a word score higher; gaps score lower.
+///
+/// ```rust
+/// use navbar::core::fuzzy_score;
+///
+/// assert!(fuzzy_score("Release notes", "relnot").is_some());
+/// assert!(fuzzy_score("Release notes", "notes rel").is_none());
+///
+/// // Word starts rank above letters buried inside a word.
+/// assert!(fuzzy_score("Release notes", "rn") > fuzzy_score("Pricing", "rn"));
+/// ```
+pub fn fuzzy_score(text: &str, query: &str) -> Option<i64> {
+    fuzzy_match(text, query).map(|m| m.score)
+}
+
+/// A searchable index of navbar items, ranked by [`fuzzy_score`].
+///
+/// Indexes menu items (with their submenus), dropdown items and mega menu items that have a
+/// link. Items match on their name, label or title, and, with a lower rank, on their
+/// description; a submenu item is described by the names of its parent items.
+///
+/// ```rust
+/// use navbar::core::{MegaMenuItem, Menu, SearchIndex};
+///
+/// let menus = vec![
+///     Menu::<()>::new(1, "Docs", "#").with_children(vec![Menu::new(2, "Guide", "/docs/guide")]),
+///     Menu::new(3, "Pricing", "/pricing"),
+/// ];
+/// let index = SearchIndex::new()
+///     .with_menus(&menus)
+///     .with_mega_menu_items(&[MegaMenuItem::new("Changelog", "Release notes", "/changelog")]);
+///
+/// let results = index.search("gd");
+/// assert_eq!(results[0].label, "Guide");
+/// assert_eq!(results[0].description.as_deref(), Some("Docs"));
+///
+/// // Descriptions are searched too.
+/// assert_eq!(index.search("release")[0].link.as_deref(), Some("/changelog"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchIndex {
+    entries: Vec<Suggestion>,
+}
+
+impl SearchIndex {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds menu items and, recursively, their submenus.
+    pub fn with_menus<I>(mut self, menus: &[Menu<I>]) -> Self {
+        self.add_menus(menus, None);
+        self
+    }
+
+    /// Adds dropdown items, such as the profile menu.
+    pub fn with_dropdown_items<I>(mut self, items: &[DropdownItem<I>]) -> Self {
+        for item in items {
+            self.add(item.label.clone(), None, &item.link);
+        }
+        self
+    }
+
+    /// Adds mega menu items.
+    pub fn with_mega_menu_items(mut self, items: &[MegaMenuItem]) -> Self {
+        for item in items {
+            self.add(
+                item.title.clone(),
+                Some(item.description.clone()),
+                &item.link,
+            );
+        }
+        self
+    }
+
+    /// Returns the number of indexed items.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no item is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the items matching `query`, best match first, as suggestions with their `link`.
+    pub fn search(&self, query: &str) -> Vec<Suggestion> {
+        let mut ranked: Vec<_> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let label = fuzzy_score(&entry.label, query);
+                let description = entry
+                    .description
+                    .as_deref()
+                    .and_then(|description| fuzzy_score(description, query))
+                    .map(|score| score / 2);
+                Some((label.max(description)?, entry))
+            })
+            .collect();
+        ranked.sort_by(|(a, _), (b, _)| b.cmp(a));
+        ranked.into_iter().map(|(_, entry)| entry.clone()).collect()
+    }
+
+    fn add_menus<I>(&mut self, menus: &[Menu<I>], parent: Option<&str>) {
+        for menu in menus {
+            self.add(
+                menu.name.clone(),
+                parent.map(|p| p.to_string().into()),
+                &menu.link,
+            );
+            let trail = match parent {
+                Some(parent) => format!("{parent} › {}", menu.name),
+                None => menu.name.to_string(),
+            };
+            self.add_menus(&menu.children, Some(&trail));
+        }
+    }
+
+    fn add(&mut self, label: Text, description: Option<Text>, link: &Text) {
+        if link.is_empty() || link == "#" {
+            return;
+        }
+        let mut entry = Suggestion::new(self.entries.len(), label).with_link(link.clone());
+        entry.description = description;
+        self.entries.push(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_score_requires_every_query_character_in_order() {
+        assert!(fuzzy_score("Settings", "stg").is_some());
+        assert!(fuzzy_score("Settings", "gts").is_none());
+        assert!(fuzzy_score("Settings", "").is_none());
+        assert!(fuzzy_score("Settings", "SET tings").is_some());
+    }
+
+    #[test]
+    fn fuzzy_score_ranks_prefixes_above_scattered_matches() {
+        assert!(fuzzy_score("Docs", "doc") > fuzzy_score("Product catalog", "doc"));
+    }
+
+    #[test]
+    fn fuzzy_score_ranks_word_starts_above_inner_letters() {
+        assert!(fuzzy_score("Release notes", "rn") > fuzzy_score("Pricing", "rn"));
+        assert!(fuzzy_score("GitHub", "gh") > fuzzy_score("Graph", "gh"));
+    }
+
+    #[test]
+    fn fuzzy_score_ranks_consecutive_characters_higher() {
+        assert!(fuzzy_score("Blog", "blo") > fuzzy_score("Bill of lading", "blo"));
+    }
+}
@@ -0,0 +1,194 @@
+//! Navbar colors, exposed to the default styles as CSS custom properties, and the light/dark
+//! mode picked by a theme toggle.
+
+use super::Text;
+
+/// The colors of a navbar, set as CSS custom properties on its `<nav>`.
+///
+/// Every default style refers to these variables (with the light colors as fallbacks, so
+/// subcomponents used on their own keep their look), so changing the theme re-skins the whole
+/// navbar, popups included. Custom themes start from a preset:
+///
+/// ```rust
+/// use navbar::core::NavbarTheme;
+///
+/// let brand = NavbarTheme {
+///     accent: "#e11d48".into(),
+///     ..NavbarTheme::dark()
+/// };
+/// assert!(brand.css_variables().contains("--navbar-accent: #e11d48;"));
+/// assert_eq!(NavbarTheme::default(), NavbarTheme::light());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NavbarTheme {
+    /// Background of the navbar (`--navbar-bg`).
+    pub background: Text,
+
+    /// Text, links and the hamburger lines (`--navbar-fg`).
+    pub text: Text,
+
+    /// Background of popups, inputs and buttons: dropdowns, menus, suggestions, the command
+    /// palette (`--navbar-surface`).
+    pub surface: Text,
+
+    /// Background of secondary blocks, such as the profile links in the mobile menu
+    /// (`--navbar-surface-muted`).
+    pub surface_muted: Text,
+
+    /// Borders, dividers and the avatar fallback (`--navbar-border`).
+    pub border: Text,
+
+    /// Background of highlighted suggestions and commands (`--navbar-highlight`).
+    pub highlight: Text,
+
+    /// Background of the call-to-action button (`--navbar-accent`).
+    pub accent: Text,
+
+    /// Text on the accent color and on the avatar fallback (`--navbar-accent-text`).
+    pub accent_text: Text,
+
+    /// Color of drop shadows (`--navbar-shadow`).
+    pub shadow: Text,
+
+    /// Color of the backdrop behind modal panels (`--navbar-backdrop`).
+    pub backdrop: Text,
+}
+
+impl NavbarTheme {
+    /// Dark text on white surfaces: the look of the default styles.
+    pub fn light() -> Self {
+        Self {
+            background: "#fff".into(),
+            text: "black".into(),
+            surface: "white".into(),
+            surface_muted: "#f9f9f9".into(),
+            border: "#ccc".into(),
+            highlight: "#f0f0f0".into(),
+            accent: "#007bff".into(),
+            accent_text: "white".into(),
+            shadow: "rgba(0,0,0,0.1)".into(),
+            backdrop: "rgba(0,0,0,0.4)".into(),
+        }
+    }
+
+    /// Light text on dark surfaces.
+    pub fn dark() -> Self {
+        Self {
+            background: "#111827".into(),
+            text: "#f3f4f6".into(),
+            surface: "#1f2937".into(),
+            surface_muted: "#273244".into(),
+            border: "#4b5563".into(),
+            highlight: "#374151".into(),
+            accent: "#3b82f6".into(),
+            accent_text: "white".into(),
+            shadow: "rgba(0,0,0,0.5)".into(),
+            backdrop: "rgba(0,0,0,0.6)".into(),
+        }
+    }
+
+    /// Returns the custom property declarations of the theme, for a `style` attribute.
+    pub fn css_variables(&self) -> String {
+        [
+            ("--navbar-bg", &self.background),
+            ("--navbar-fg", &self.text),
+            ("--navbar-surface", &self.surface),
+            ("--navbar-surface-muted", &self.surface_muted),
+            ("--navbar-border", &self.border),
+            ("--navbar-highlight", &self.highlight),
+            ("--navbar-accent", &self.accent),
+            ("--navbar-accent-text", &self.accent_text),
+            ("--navbar-shadow", &self.shadow),
+            ("--navbar-backdrop", &self.backdrop),
+        ]
+        .iter()
+        .map(|(name, value)| format!("{name}: {value};"))
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+}
+
+impl Default for NavbarTheme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+/// The color scheme a user picked: light, dark, or whatever the system prefers.
+///
+/// ```rust
+/// use navbar::core::ThemeMode;
+///
+/// assert_eq!(ThemeMode::parse("dark"), Some(ThemeMode::Dark));
+/// assert_eq!(ThemeMode::System.as_str(), "system");
+/// assert!(ThemeMode::System.is_dark(true));
+/// assert!(!ThemeMode::Light.is_dark(true));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ThemeMode {
+    /// Always light.
+    Light,
+    /// Always dark.
+    Dark,
+    /// Follows `prefers-color-scheme`.
+    #[default]
+    System,
+}
+
+impl ThemeMode {
+    /// Every mode, in the order a theme toggle lists them.
+    pub const ALL: [Self; 3] = [Self::Light, Self::Dark, Self::System];
+
+    /// Returns the name the mode is stored under: `"light"`, `"dark"` or `"system"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+            Self::System => "system",
+        }
+    }
+
+    /// Parses a stored mode name, as returned by [`ThemeMode::as_str`].
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.as_str() == name)
+    }
+
+    /// Returns `true` if the mode shows the dark theme, given whether the system prefers dark.
+    pub fn is_dark(self, system_dark: bool) -> bool {
+        match self {
+            Self::Light => false,
+            Self::Dark => true,
+            Self::System => system_dark,
+        }
+    }
+}
+
+/// The theme a theme toggle applies: the user's [`ThemeMode`] and the system preference it
+/// falls back to.
+///
+/// ```rust
+/// use navbar::core::{ThemeMode, ThemePreference};
+///
+/// let preference = ThemePreference { mode: ThemeMode::System, system_dark: true };
+/// assert_eq!(preference.theme(), "dark");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ThemePreference {
+    /// The mode the user picked.
+    pub mode: ThemeMode,
+
+    /// Whether the system prefers a dark color scheme (`prefers-color-scheme: dark`).
+    pub system_dark: bool,
+}
+
+impl ThemePreference {
+    /// Returns `true` if the dark theme applies.
+    pub fn is_dark(&self) -> bool {
+        self.mode.is_dark(self.system_dark)
+    }
+
+    /// Returns the applied theme, `"light"` or `"dark"`, as set on `<html>`.
+    pub fn theme(&self) -> &'static str {
+        if self.is_dark() { "dark" } else { "light" }
+    }
+}
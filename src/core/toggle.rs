@@ -0,0 +1,71 @@
+//! Icon animations of the hamburger toggle.
+
+/// Transition shared by the toggle and its lines while they animate.
+const TOGGLE_TRANSITION: &str = "transition: transform 0.3s ease, opacity 0.3s ease;";
+
+/// How the three lines of the hamburger toggle animate when the mobile menu opens.
+///
+/// The styles are appended to the toggle's and the lines' own styles. They move the outer lines
+/// by `--navbar-toggle-offset` (default `7px`, the distance between two line centers with 2px
+/// lines 5px apart); set that variable in the toggle style when changing the line size or gap.
+///
+/// ```rust
+/// use navbar::core::ToggleAnimation;
+///
+/// assert_eq!(ToggleAnimation::None.line_style(0, true), "");
+/// assert!(ToggleAnimation::Cross.line_style(1, true).contains("opacity: 0;"));
+/// assert!(ToggleAnimation::Spin.toggle_style(true).contains("rotate(180deg)"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ToggleAnimation {
+    /// The lines stay put; style the open state with `[aria-expanded="true"]` instead.
+    #[default]
+    None,
+    /// The outer lines rotate into an X and the middle one fades out.
+    Cross,
+    /// The whole icon turns half a circle while morphing into an X.
+    Spin,
+    /// The outer lines fold into the head of a left-pointing arrow.
+    Arrow,
+}
+
+impl ToggleAnimation {
+    /// Returns the style added to the toggle button.
+    pub fn toggle_style(self, open: bool) -> &'static str {
+        match (self, open) {
+            (Self::Spin, true) => {
+                "transform: rotate(180deg); transition: transform 0.3s ease, opacity 0.3s ease;"
+            }
+            (Self::Spin, false) => TOGGLE_TRANSITION,
+            _ => "",
+        }
+    }
+
+    /// Returns the style added to the line at `index` (0 to 2, from the top).
+    pub fn line_style(self, index: usize, open: bool) -> &'static str {
+        if self == Self::None {
+            return "";
+        }
+        if !open {
+            return TOGGLE_TRANSITION;
+        }
+        match (self, index) {
+            (Self::Cross | Self::Spin, 0) => {
+                "transform: translateY(var(--navbar-toggle-offset, 7px)) rotate(45deg); transition: transform 0.3s ease, opacity 0.3s ease;"
+            }
+            (Self::Cross | Self::Spin, 1) => {
+                "opacity: 0; transition: transform 0.3s ease, opacity 0.3s ease;"
+            }
+            (Self::Cross | Self::Spin, _) => {
+                "transform: translateY(calc(var(--navbar-toggle-offset, 7px) * -1)) rotate(-45deg); transition: transform 0.3s ease, opacity 0.3s ease;"
+            }
+            (_, 0) => {
+                "transform: translate(calc(var(--navbar-toggle-offset, 7px) * -1), calc(var(--navbar-toggle-offset, 7px) / 2)) rotate(-40deg) scaleX(0.5); transition: transform 0.3s ease, opacity 0.3s ease;"
+            }
+            (_, 1) => TOGGLE_TRANSITION,
+            _ => {
+                "transform: translate(calc(var(--navbar-toggle-offset, 7px) * -1), calc(var(--navbar-toggle-offset, 7px) / -2)) rotate(40deg) scaleX(0.5); transition: transform 0.3s ease, opacity 0.3s ease;"
+            }
+        }
+    }
+}
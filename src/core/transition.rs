@@ -0,0 +1,196 @@
+//! Enter and exit transitions for popups that mount and unmount.
+
+use super::Text;
+
+/// Where a transitioning element is in its enter/exit cycle.
+///
+/// An element is mounted in [`Enter`](Self::Enter) with its starting styles, moves to
+/// [`Entering`](Self::Entering) once those have been painted (so CSS transitions run towards the
+/// entering styles), and stays there while shown. Hiding it switches to [`Exit`](Self::Exit),
+/// and it is unmounted ([`Exited`](Self::Exited)) when its transition ends.
+///
+/// ```rust
+/// use navbar::core::TransitionPhase;
+///
+/// let phase = TransitionPhase::initial(false).show(true);
+/// assert_eq!(phase, TransitionPhase::Enter);
+/// let phase = phase.advance();
+/// assert_eq!(phase, TransitionPhase::Entering);
+/// let phase = phase.hide(true);
+/// assert_eq!(phase, TransitionPhase::Exit);
+/// assert!(phase.is_mounted());
+/// assert!(!phase.advance().is_mounted());
+///
+/// // Without animation, elements mount and unmount at once.
+/// assert_eq!(TransitionPhase::Entering.hide(false), TransitionPhase::Exited);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TransitionPhase {
+    /// Just mounted, with the enter styles.
+    Enter,
+    /// Shown, transitioning to (or resting at) the entering styles.
+    Entering,
+    /// Hiding, transitioning to the exit styles before unmounting.
+    Exit,
+    /// Unmounted.
+    #[default]
+    Exited,
+}
+
+impl TransitionPhase {
+    /// Returns the phase of an element that is initially shown or not, without animating it in.
+    pub fn initial(shown: bool) -> Self {
+        if shown { Self::Entering } else { Self::Exited }
+    }
+
+    /// Returns `true` if the element is rendered in this phase.
+    pub fn is_mounted(self) -> bool {
+        self != Self::Exited
+    }
+
+    /// Returns the phase after the element is asked to show.
+    ///
+    /// An element caught exiting turns straight back to [`Entering`](Self::Entering), so its
+    /// transition reverses from where it is.
+    pub fn show(self, animate: bool) -> Self {
+        match self {
+            Self::Exited if animate => Self::Enter,
+            Self::Exited | Self::Exit => Self::Entering,
+            phase => phase,
+        }
+    }
+
+    /// Returns the phase after the element is asked to hide.
+    pub fn hide(self, animate: bool) -> Self {
+        match self {
+            Self::Enter | Self::Entering if animate => Self::Exit,
+            Self::Enter | Self::Entering => Self::Exited,
+            phase => phase,
+        }
+    }
+
+    /// Returns the phase after the current one has run its course: enter styles painted, or
+    /// exit transition ended.
+    pub fn advance(self) -> Self {
+        match self {
+            Self::Enter => Self::Entering,
+            Self::Exit => Self::Exited,
+            phase => phase,
+        }
+    }
+}
+
+/// Styles and classes applied to a popup in each [`TransitionPhase`].
+///
+/// `timeout_ms` bounds the exit: the element is unmounted on `transitionend`, or after the
+/// timeout if no transition ends (none was declared, or it was interrupted).
+///
+/// ```rust
+/// use navbar::core::{Transition, TransitionPhase};
+///
+/// let fade = Transition::fade(150);
+/// assert_eq!(fade.style(TransitionPhase::Enter), "opacity: 0;");
+/// assert_eq!(fade.timeout_ms, 200);
+///
+/// let slide = Transition::new(300)
+///     .with_enter("transform: translateX(100%);", "")
+///     .with_entering("transform: none; transition: transform 0.3s;", "")
+///     .with_exit("transform: translateX(100%); transition: transform 0.3s;", "sliding-out");
+/// assert_eq!(slide.class(TransitionPhase::Exit), "sliding-out");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Transition {
+    /// Style in [`TransitionPhase::Enter`], where the enter transition starts.
+    pub enter_style: Text,
+    /// Class in [`TransitionPhase::Enter`].
+    pub enter_class: Text,
+    /// Style in [`TransitionPhase::Entering`], the shown state the enter transition runs to.
+    pub entering_style: Text,
+    /// Class in [`TransitionPhase::Entering`].
+    pub entering_class: Text,
+    /// Style in [`TransitionPhase::Exit`], the state the exit transition runs to.
+    pub exit_style: Text,
+    /// Class in [`TransitionPhase::Exit`].
+    pub exit_class: Text,
+    /// Longest time (ms) to wait for `transitionend` before unmounting.
+    pub timeout_ms: u32,
+}
+
+impl Transition {
+    /// Creates a transition without styles, unmounting at the latest after `timeout_ms`.
+    pub fn new(timeout_ms: u32) -> Self {
+        Self {
+            timeout_ms,
+            ..Self::default()
+        }
+    }
+
+    /// Fades in and out over `duration_ms`.
+    pub fn fade(duration_ms: u32) -> Self {
+        let transition = format!("transition: opacity {duration_ms}ms ease;");
+        Self::new(duration_ms + 50)
+            .with_enter("opacity: 0;", "")
+            .with_entering(format!("opacity: 1; {transition}"), "")
+            .with_exit(format!("opacity: 0; {transition}"), "")
+    }
+
+    /// Fades in while sliding down from slightly above, and back out, over `duration_ms`.
+    pub fn slide_down(duration_ms: u32) -> Self {
+        let transition =
+            format!("transition: opacity {duration_ms}ms ease, transform {duration_ms}ms ease;");
+        Self::new(duration_ms + 50)
+            .with_enter("opacity: 0; transform: translateY(-0.5rem);", "")
+            .with_entering(format!("opacity: 1; transform: none; {transition}"), "")
+            .with_exit(
+                format!("opacity: 0; transform: translateY(-0.5rem); {transition}"),
+                "",
+            )
+    }
+
+    /// Sets the style and class of [`TransitionPhase::Enter`].
+    pub fn with_enter(self, style: impl Into<Text>, class: impl Into<Text>) -> Self {
+        Self {
+            enter_style: style.into(),
+            enter_class: class.into(),
+            ..self
+        }
+    }
+
+    /// Sets the style and class of [`TransitionPhase::Entering`].
+    pub fn with_entering(self, style: impl Into<Text>, class: impl Into<Text>) -> Self {
+        Self {
+            entering_style: style.into(),
+            entering_class: class.into(),
+            ..self
+        }
+    }
+
+    /// Sets the style and class of [`TransitionPhase::Exit`].
+    pub fn with_exit(self, style: impl Into<Text>, class: impl Into<Text>) -> Self {
+        Self {
+            exit_style: style.into(),
+            exit_class: class.into(),
+            ..self
+        }
+    }
+
+    /// Returns the style of `phase` (empty once exited).
+    pub fn style(&self, phase: TransitionPhase) -> &str {
+        match phase {
+            TransitionPhase::Enter => &self.enter_style,
+            TransitionPhase::Entering => &self.entering_style,
+            TransitionPhase::Exit => &self.exit_style,
+            TransitionPhase::Exited => "",
+        }
+    }
+
+    /// Returns the class of `phase` (empty once exited).
+    pub fn class(&self, phase: TransitionPhase) -> &str {
+        match phase {
+            TransitionPhase::Enter => &self.enter_class,
+            TransitionPhase::Entering => &self.entering_class,
+            TransitionPhase::Exit => &self.exit_class,
+            TransitionPhase::Exited => "",
+        }
+    }
+}
@@ -1,23 +1,57 @@
 #![doc = include_str!("../DIOXUS.md")]
 
+use crate::core::{
+    ActiveMatch, BADGE_MAX, BELL_ICON_PATH, BreakpointTracker, Breakpoints, ComboboxKey, Command,
+    CommandAction, CommandSet, DESKTOP_CLASS, InitialLayout, MOBILE_BREAKPOINT, MOBILE_CLASS,
+    MenuKey, MobileVariant, NavbarAction, NavbarPart, NavbarState, NavbarTheme, Notification,
+    Orientation, Popup, RecentCommands, ScrollBehavior, ScrollState, ScrollTracker, SearchIndex,
+    Suggestion, SuggestionProvider, Suggestions, ThemeMode, ThemePreference, ToggleAnimation,
+    Transition, TransitionPhase, VISUALLY_HIDDEN_STYLE, Viewport, badge_text, highlight_match,
+    responsive_css, unread_count,
+};
+use crate::dom::{
+    Debounce, FocusReturn, FocusTrap, IdScope, OutsideClickListener, PhaseTimer, ScrollLock,
+    ScrollObserver, ViewportObserver, element_id, focus_element, focus_menu_item, focus_within,
+    is_details_open, item_id, listen_shortcut, load_recent_commands, load_theme_mode,
+    location_path, popup_id, prefers_dark_scheme, prefers_reduced_motion, save_recent_commands,
+    save_theme_mode, scroll_into_view, set_document_theme, viewport_width, watch_color_scheme,
+    watch_location,
+};
 use dioxus::prelude::*;
 use gloo::events::EventListener;
-use web_sys::wasm_bindgen::JsCast;
-use web_sys::wasm_bindgen::closure::Closure;
-use web_sys::window;
+use gloo::timers::future::TimeoutFuture;
+use router::use_link_router;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+pub use crate::core::MegaMenuItem;
+#[cfg(feature = "dioxus-router")]
+pub use router::WithRoute;
+
+mod router;
+
+/// Represents a standard menu item in the navigation bar.
+///
+/// This is the shared [`crate::core::Menu`] model with Dioxus `Element` icons.
+pub type Menu = crate::core::Menu<Element>;
+
+/// Represents an item in a dropdown menu.
+///
+/// This is the shared [`crate::core::DropdownItem`] model with a Dioxus `Element` icon.
+pub type DropdownItem = crate::core::DropdownItem<Element>;
 
 /// Properties for rendering a logo inside a navigation bar.
 #[derive(Props, PartialEq, Clone)]
 pub struct LogoProps {
     /// Path to the logo image.
-    #[props(default = "/logo.png")]
-    pub logo_src: &'static str,
+    #[props(into, default = "/logo.png".to_string())]
+    pub logo_src: String,
     /// Alternative text for the logo image.
-    #[props(default = "Logo")]
-    pub logo_alt: &'static str,
+    #[props(into, default = "Logo".to_string())]
+    pub logo_alt: String,
     /// URL to redirect when the logo is clicked.
-    #[props(default = "/")]
-    pub logo_link: &'static str,
+    #[props(into, default = "/".to_string())]
+    pub logo_link: String,
     /// Inline CSS style for the logo image.
     #[props(default = "height: 40px;")]
     pub logo_style: &'static str,
@@ -34,9 +68,11 @@ pub struct LogoProps {
 
 #[component]
 pub fn NavbarLogo(props: LogoProps) -> Element {
+    let router = use_link_router();
     rsx! {
         a {
-            href: props.logo_link,
+            href: router.href(&props.logo_link),
+            onclick: router.onclick(&props.logo_link, ""),
             style: props.link_style,
             class: "{props.link_class}",
             img {
@@ -54,11 +90,36 @@ pub fn NavbarLogo(props: LogoProps) -> Element {
 pub struct ToggleProps {
     /// Callback triggered when the toggle is clicked.
     pub onclick: EventHandler<MouseEvent>,
-    /// Inline style for the toggle container.
-    #[props(default = "display: flex; flex-direction: column; gap: 5px; cursor: pointer;")]
+    /// Key presses on the toggle, e.g. [`TriggerProps::onkeydown`].
+    #[props(default)]
+    pub onkeydown: EventHandler<KeyboardEvent>,
+    /// Id of the toggle, e.g. [`TriggerProps::id`], which gets focus back when the menu is
+    /// closed with `Escape`.
+    #[props(default = None)]
+    pub id: Option<String>,
+    /// Whether the menu the toggle controls is open, exposed as `aria-expanded`.
+    #[props(default = false)]
+    pub open: bool,
+    /// Id of the menu the toggle controls, exposed as `aria-controls`.
+    #[props(default = None)]
+    pub controls: Option<String>,
+    /// Accessible label (`aria-label`) of the toggle button.
+    #[props(into, default = "Toggle Menu".to_string())]
+    pub label: String,
+    /// How the icon animates when the menu opens.
+    #[props(default)]
+    pub animation: ToggleAnimation,
+    /// Renders the toggle as the `<summary>` of a surrounding `<details>`, which opens and closes
+    /// without JavaScript until `onclick` takes over (its default action is then prevented).
+    #[props(default = false)]
+    pub disclosure: bool,
+    /// Inline style for the toggle button.
+    #[props(
+        default = "display: flex; flex-direction: column; gap: 5px; cursor: pointer; background: none; border: none; padding: 0;"
+    )]
     pub toggle_style: &'static str,
     /// Inline style for each line/bar in the hamburger icon.
-    #[props(default = "width: 25px; height: 3px; background: black;")]
+    #[props(default = "width: 25px; height: 3px; background: var(--navbar-fg, black);")]
     pub line_style: &'static str,
     /// Optional class for the toggle container.
     #[props(default = String::new())]
@@ -70,37 +131,67 @@ pub struct ToggleProps {
 
 #[component]
 pub fn NavbarToggle(props: ToggleProps) -> Element {
+    let line_style = |index: usize| {
+        with_phase(
+            props.line_style,
+            props.animation.line_style(index, props.open),
+        )
+    };
+
+    let lines = rsx! {
+        for index in 0..3 {
+            span {
+                key: "{index}",
+                aria_hidden: "true",
+                style: line_style(index),
+                class: "{props.line_class}",
+            }
+        }
+    };
+
+    if props.disclosure {
+        return rsx! {
+            summary {
+                id: props.id.clone(),
+                aria_label: "{props.label}",
+                aria_controls: props.controls.clone(),
+                aria_expanded: "{props.open}",
+                style: with_phase(props.toggle_style, props.animation.toggle_style(props.open)),
+                class: "{props.toggle_class}",
+                onclick: move |e: MouseEvent| {
+                    e.prevent_default();
+                    props.onclick.call(e);
+                },
+                onkeydown: move |e| props.onkeydown.call(e),
+                {lines}
+            }
+        };
+    }
     rsx! {
-        div {
-            tabindex: "0",
-            role: "button",
-            aria_label: "Toggle Menu",
-            style: props.toggle_style,
+        button {
+            id: props.id.clone(),
+            r#type: "button",
+            aria_label: "{props.label}",
+            aria_controls: props.controls.clone(),
+            aria_expanded: "{props.open}",
+            style: with_phase(props.toggle_style, props.animation.toggle_style(props.open)),
             class: "{props.toggle_class}",
             onclick: move |e| props.onclick.call(e),
-            div { style: props.line_style, class: "{props.line_class}" }
-            div { style: props.line_style, class: "{props.line_class}" }
-            div { style: props.line_style, class: "{props.line_class}" }
+            onkeydown: move |e| props.onkeydown.call(e),
+            {lines}
         }
     }
 }
 
-#[derive(Clone, PartialEq)]
-pub struct Menu {
-    pub id: usize,
-    pub name: &'static str,
-    pub link: &'static str,
-    pub icon_start: Option<Element>,
-    pub icon_end: Option<Element>,
-}
-
 /// Properties for rendering a horizontal navigation menu.
 #[derive(Props, PartialEq, Clone)]
 pub struct MenuProps {
     /// List of menu items to display.
     pub menus: Vec<Menu>,
     /// Inline style for each menu item anchor tag.
-    #[props(default = "padding: 0.5rem 1rem; text-decoration: none; color: black;")]
+    #[props(
+        default = "padding: 0.5rem 1rem; text-decoration: none; color: var(--navbar-fg, black);"
+    )]
     pub menu_item_style: &'static str,
     /// Inline style for the entire list container.
     #[props(default = "display: flex; gap: 1rem; list-style: none; margin: 0; padding: 0;")]
@@ -111,288 +202,1004 @@ pub struct MenuProps {
     /// Optional class for the list container.
     #[props(default = String::new())]
     pub list_class: String,
+    /// Layout of the items, which decides the arrow keys that move focus between them.
+    #[props(default)]
+    pub orientation: Orientation,
+    /// Style for the submenu of an item with `children`.
+    #[props(default = SUBMENU_STYLE)]
+    pub submenu_style: &'static str,
+    /// Optional class for the submenu of an item with `children`.
+    #[props(default = String::new())]
+    pub submenu_class: String,
+    /// Expands submenus inline below their item (an accordion) instead of as flyouts.
+    #[props(default = false)]
+    pub accordion: bool,
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[props(default = None)]
+    pub current_path: Option<String>,
+    /// How item links are matched against the current path.
+    #[props(default)]
+    pub active_match: ActiveMatch,
+    /// Style added to the active item's link, which also gets `aria-current="page"`.
+    #[props(default = "font-weight: bold;")]
+    pub active_style: &'static str,
+    /// Optional class added to the active item's link.
+    #[props(default = String::new())]
+    pub active_class: String,
 }
 
+/// Renders the items as a WAI-ARIA menubar.
+///
+/// Only one item is in the tab sequence at a time (roving `tabindex`); the arrow keys for the
+/// `orientation`, `Home` and `End` move focus between items. Items with `children` open a
+/// submenu, as a flyout or (with `accordion`) inline.
 #[component]
 pub fn NavbarMenu(props: MenuProps) -> Element {
+    let id = use_element_id("menu");
+    let router = use_link_router();
+    let location = use_location_path();
+    let path = props
+        .current_path
+        .clone()
+        .unwrap_or_else(|| location.cloned());
+    let mut focused = use_signal(|| 0);
+    let len = props.menus.len();
+    let orientation = props.orientation;
+    let on_item_keydown = {
+        let id = id.clone();
+        move |index: usize| {
+            let id = id.clone();
+            move |e: KeyboardEvent| {
+                let moved = MenuKey::from_key(&e.key().to_string(), orientation)
+                    .is_some_and(|key| focus_menu_item(&id, key, Some(index), len));
+                if moved {
+                    e.prevent_default();
+                }
+            }
+        }
+    };
+
+    let active = |link: &str| props.active_match.is_active(link, &path);
+    let tabindex = |index: usize| {
+        if index == focused().min(len - 1) {
+            "0"
+        } else {
+            "-1"
+        }
+    };
+
     rsx! {
         ul {
+            role: "menubar",
+            aria_orientation: orientation.aria_orientation(),
             style: props.list_style,
             class: "{props.list_class}",
-            for menu in props.menus.iter() {
+            for (index, menu) in props.menus.iter().enumerate() {
+                if menu.has_submenu() {
+                    NavbarSubmenu {
+                        key: "{menu.id}",
+                        item: menu.clone(),
+                        id: item_id(&id, index),
+                        orientation,
+                        accordion: props.accordion,
+                        tabindex: tabindex(index),
+                        on_keydown: on_item_keydown(index),
+                        on_focus: move |_| focused.set(index),
+                        item_style: props.menu_item_style,
+                        item_class: props.menu_item_class.clone(),
+                        submenu_style: props.submenu_style,
+                        submenu_class: props.submenu_class.clone(),
+                        path: path.clone(),
+                        active_match: props.active_match.clone(),
+                        active_style: props.active_style,
+                        active_class: props.active_class.clone(),
+                    }
+                } else {
                 li {
                     key: "{menu.id}",
+                    role: "none",
                     a {
-                        href: menu.link,
-                        style: props.menu_item_style,
-                        class: "{props.menu_item_class}",
+                        id: item_id(&id, index),
+                        href: router.href(&menu.link),
+                        onclick: router.onclick(&menu.link, ""),
+                        role: "menuitem",
+                        tabindex: if index == focused().min(len - 1) { "0" } else { "-1" },
+                        onkeydown: on_item_keydown(index),
+                        onfocus: move |_| focused.set(index),
+                        aria_current: active(&menu.link).then_some("page"),
+                        style: with_active(props.menu_item_style, props.active_style, active(&menu.link)),
+                        class: with_active(&props.menu_item_class, &props.active_class, active(&menu.link)),
                         { menu.icon_start.clone().unwrap_or(rsx!("")) }
                         "{menu.name}"
                         { menu.icon_end.clone().unwrap_or(rsx!("")) }
                     }
                 }
+                }
             }
         }
     }
 }
 
-/// Properties for rendering a styled button with a link.
+/// Default style for a desktop submenu flyout.
+const SUBMENU_STYLE: &str = "position: absolute; top: 100%; left: 0; z-index: 1000; min-width: 12rem; margin: 0; padding: 0.5rem 0; list-style: none; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1));";
+
+/// Properties for rendering a menu item that opens a submenu of its `children`.
 #[derive(Props, PartialEq, Clone)]
-pub struct ButtonProps {
-    /// The URL the button should navigate to.
-    #[props(default = "#")]
-    pub href: &'static str,
-    /// Text displayed inside the button.
-    #[props(default = "Click Me")]
-    pub text: &'static str,
-    /// Style for the outer div wrapping the button.
-    #[props(default = "padding: 0.5rem 1rem; background-color: #007bff; border-radius: 4px;")]
-    pub button_style: &'static str,
-    /// Style applied to the anchor inside the button.
-    #[props(default = "color: white; text-decoration: none;")]
-    pub link_style: &'static str,
-    /// Target attribute for the anchor link (e.g. `_blank`, `_self`).
-    #[props(default = "_self")]
-    pub target: &'static str,
-    /// Optional class for the outer button div.
-    #[props(default = String::new())]
-    pub button_class: String,
-    /// Optional class for the inner anchor tag.
-    #[props(default = String::new())]
-    pub link_class: String,
+struct SubmenuProps {
+    /// The item whose children are shown.
+    item: Menu,
+    /// Element id of the item, which labels its submenu.
+    id: String,
+    /// Layout of the menu containing the item.
+    orientation: Orientation,
+    /// Expands the submenu inline below the item instead of as a flyout.
+    accordion: bool,
+    /// `tabindex` of the item in its containing menu.
+    tabindex: &'static str,
+    /// Keys pressed on the item that do not open or close its submenu.
+    on_keydown: EventHandler<KeyboardEvent>,
+    /// Called when the item receives focus.
+    #[props(default)]
+    on_focus: EventHandler<FocusEvent>,
+    /// Called after a link in the submenu, at any depth, is followed.
+    #[props(default)]
+    on_navigate: EventHandler<()>,
+    item_style: &'static str,
+    item_class: String,
+    submenu_style: &'static str,
+    submenu_class: String,
+    path: String,
+    active_match: ActiveMatch,
+    active_style: &'static str,
+    active_class: String,
 }
 
+/// Renders a menu item with `children` as a WAI-ARIA menu button followed by its submenu.
+///
+/// As a flyout, the submenu opens on hover or click and closes on mouse leave, a click outside,
+/// `Escape` or after following one of its links. As an accordion it stays open until its item is
+/// activated again. Opening it from the keyboard (`Enter`, `Space`, or `ArrowDown`/`ArrowRight`
+/// depending on `orientation`) focuses its first item; `Escape` or `ArrowLeft` returns focus to
+/// the item.
 #[component]
-pub fn NavbarButton(props: ButtonProps) -> Element {
+fn NavbarSubmenu(props: SubmenuProps) -> Element {
+    let wrapper_id = use_element_id("submenu");
+    let menu_id = format!("{}-menu", props.id);
+    let router = use_link_router();
+    let mut open = use_signal(|| false);
+    let len = props.item.children.len();
+    let accordion = props.accordion;
+
+    use_click_outside(
+        wrapper_id.clone(),
+        open() && !accordion,
+        EventHandler::new(move |_| open.set(false)),
+    );
+
+    // Item to focus once the submenu opens after a key press on its item.
+    let initial_focus = use_hook(|| Rc::new(Cell::new(None::<MenuKey>)));
+    {
+        let initial_focus = initial_focus.clone();
+        let menu_id = menu_id.clone();
+        use_effect(use_reactive((&open(), &len), move |(is_open, len)| {
+            if let Some(key) = initial_focus.take().filter(|_| is_open) {
+                focus_menu_item(&menu_id, key, None, len);
+            }
+        }));
+    }
+
+    let on_item_keydown = {
+        let menu_id = menu_id.clone();
+        let orientation = props.orientation;
+        let on_keydown = props.on_keydown;
+        move |e: KeyboardEvent| {
+            let key = e.key().to_string();
+            let is_open = *open.peek();
+            let closes = is_open
+                && (MenuKey::from_key(&key, orientation) == Some(MenuKey::Close)
+                    || accordion && matches!(key.as_str(), "Enter" | " "));
+            if closes {
+                e.prevent_default();
+                open.set(false);
+            } else if let Some(focus) = MenuKey::submenu_focus(&key, orientation) {
+                e.prevent_default();
+                if is_open {
+                    focus_menu_item(&menu_id, focus, None, len);
+                } else {
+                    initial_focus.set(Some(focus));
+                    open.set(true);
+                }
+            } else {
+                on_keydown.call(e);
+            }
+        }
+    };
+
+    let on_child_keydown = {
+        let id = props.id.clone();
+        let menu_id = menu_id.clone();
+        move |index: usize| {
+            let id = id.clone();
+            let menu_id = menu_id.clone();
+            move |e: KeyboardEvent| match MenuKey::from_key(
+                &e.key().to_string(),
+                Orientation::Vertical,
+            ) {
+                Some(MenuKey::Close) => {
+                    e.prevent_default();
+                    open.set(false);
+                    focus_element(&id);
+                }
+                Some(MenuKey::Leave) if !accordion => open.set(false),
+                Some(key) => {
+                    let moved = focus_menu_item(&menu_id, key, Some(index), len);
+                    if moved {
+                        e.prevent_default();
+                    }
+                }
+                None => {}
+            }
+        }
+    };
+
+    // Following a link closes every flyout up to the menubar.
+    let on_navigate = props.on_navigate;
+    let mut navigate = move || {
+        if !accordion {
+            open.set(false);
+        }
+        on_navigate.call(());
+    };
+    let on_child_click = move |link: &str| {
+        let mut route = router.onclick(link, "");
+        move |e: MouseEvent| {
+            route(e);
+            navigate();
+        }
+    };
+
+    let (wrapper_style, submenu_style) = match (accordion, props.orientation) {
+        (true, _) => (None, props.submenu_style.to_string()),
+        (false, Orientation::Vertical) => (
+            Some("position: relative;"),
+            format!("{} top: 0; left: 100%;", props.submenu_style),
+        ),
+        (false, _) => (Some("position: relative;"), props.submenu_style.to_string()),
+    };
+    let active = props.item.contains_active(&props.active_match, &props.path);
+    let child_active = |link: &str| props.active_match.is_active(link, &props.path);
+
     rsx! {
-        div {
-            style: props.button_style,
-            class: "{props.button_class}",
+        li {
+            id: "{wrapper_id}",
+            role: "none",
+            style: wrapper_style,
+            // Only a mouse opens the flyout on hover; a tap is left to the click that toggles it.
+            onpointerenter: move |e: PointerEvent| {
+                if !accordion && e.pointer_type() == "mouse" {
+                    open.set(true);
+                }
+            },
+            onpointerleave: move |e: PointerEvent| {
+                if !accordion && e.pointer_type() == "mouse" {
+                    open.set(false);
+                }
+            },
             a {
-                href: props.href,
-                target: props.target,
-                style: props.link_style,
-                class: "{props.link_class}",
-                "{props.text}"
+                id: "{props.id}",
+                href: router.href(&props.item.link),
+                role: "menuitem",
+                tabindex: props.tabindex,
+                aria_haspopup: "menu",
+                aria_expanded: "{open}",
+                aria_controls: "{menu_id}",
+                onclick: move |e: MouseEvent| {
+                    e.prevent_default();
+                    open.toggle();
+                },
+                onkeydown: on_item_keydown,
+                onfocus: move |e| props.on_focus.call(e),
+                style: with_active(props.item_style, props.active_style, active),
+                class: with_active(&props.item_class, &props.active_class, active),
+                { props.item.icon_start.clone().unwrap_or(rsx!("")) }
+                "{props.item.name}"
+                { props.item.icon_end.clone().unwrap_or(rsx!("")) }
+            }
+            if open() {
+                ul {
+                    id: "{menu_id}",
+                    role: "menu",
+                    aria_labelledby: "{props.id}",
+                    style: submenu_style,
+                    class: "{props.submenu_class}",
+                    for (index, child) in props.item.children.iter().enumerate() {
+                        if child.has_submenu() {
+                            NavbarSubmenu {
+                                key: "{child.id}",
+                                item: child.clone(),
+                                id: item_id(&menu_id, index),
+                                orientation: Orientation::Vertical,
+                                accordion,
+                                tabindex: "-1",
+                                on_keydown: on_child_keydown(index),
+                                on_navigate: move |_| navigate(),
+                                item_style: props.item_style,
+                                item_class: props.item_class.clone(),
+                                submenu_style: props.submenu_style,
+                                submenu_class: props.submenu_class.clone(),
+                                path: props.path.clone(),
+                                active_match: props.active_match.clone(),
+                                active_style: props.active_style,
+                                active_class: props.active_class.clone(),
+                            }
+                        } else {
+                            li {
+                                key: "{child.id}",
+                                role: "none",
+                                a {
+                                    id: item_id(&menu_id, index),
+                                    href: router.href(&child.link),
+                                    role: "menuitem",
+                                    tabindex: "-1",
+                                    onclick: on_child_click(&child.link),
+                                    onkeydown: on_child_keydown(index),
+                                    aria_current: child_active(&child.link).then_some("page"),
+                                    style: with_active(props.item_style, props.active_style, child_active(&child.link)),
+                                    class: with_active(&props.item_class, &props.active_class, child_active(&child.link)),
+                                    { child.icon_start.clone().unwrap_or(rsx!("")) }
+                                    "{child.name}"
+                                    { child.icon_end.clone().unwrap_or(rsx!("")) }
+                                }
+                            }
+                        }
+                    }
+                }
             }
         }
     }
 }
 
-#[derive(Clone, PartialEq)]
-pub struct DropdownItem {
-    pub id: usize,
-    pub label: &'static str,
-    pub link: &'static str,
-    pub icon: Option<Element>,
-}
+/// Default style of the backdrop behind a mobile drawer or sheet.
+const MOBILE_BACKDROP_STYLE: &str = "position: fixed; inset: 0; z-index: 1000; background: var(--navbar-backdrop, rgba(0,0,0,0.4));";
+
+/// Default style of the suggestions listbox under the search input.
+const SUGGESTIONS_STYLE: &str = "position: absolute; top: 100%; left: 0; right: 0; z-index: 1000; margin: 0.25rem 0 0; padding: 0.25rem 0; list-style: none; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); max-height: 20rem; overflow-y: auto;";
 
+/// Properties for rendering a search form.
 #[derive(Props, PartialEq, Clone)]
-pub struct ProfileMenuProps {
-    pub items: Vec<DropdownItem>,
+pub struct SearchProps {
+    /// Current search text. `None` leaves the input uncontrolled.
+    #[props(default = None)]
+    pub value: Option<String>,
+    /// Placeholder text for the search input.
+    #[props(into, default = "Search...".to_string())]
+    pub placeholder: String,
+    /// Style for the search input field.
     #[props(
-        default = "position: absolute; top: 100%; left: 0; background: white; box-shadow: 0 4px 8px rgba(0,0,0,0.1); z-index: 1000;"
+        default = "padding: 0.5rem; font-size: 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
     )]
-    pub dropdown_style: &'static str,
-    #[props(default = "padding: 0.5rem 1rem; white-space: nowrap;")]
-    pub item_style: &'static str,
-    #[props(default = "position: relative; display: inline-block; margin: 0 0 0 1rem;")]
-    pub wrapper_style: &'static str,
-    #[props(default = "background: none; border: none; cursor: pointer;")]
-    pub button_style: &'static str,
-    #[props(default = "width: 40px; height: 40px; border-radius: 50%; object-fit: cover;")]
-    pub avatar_img_style: &'static str,
+    pub input_style: &'static str,
+    /// Optional class for the search input.
+    #[props(default = String::new())]
+    pub input_class: String,
+    /// Callback fired with the text on every keystroke.
+    #[props(default)]
+    pub on_input: EventHandler<String>,
+    /// Callback fired with the text on `Enter` or a click on the search button.
+    #[props(default)]
+    pub on_submit: EventHandler<String>,
+    /// Whether to show a submit button next to the input.
+    #[props(default = false)]
+    pub show_button: bool,
+    /// Text of the submit button.
+    #[props(into, default = "Search".to_string())]
+    pub button_text: String,
+    /// Style for the submit button.
     #[props(
-        default = "width: 40px; height: 40px; border-radius: 50%; background-color: #ccc; color: #fff; display: flex; align-items: center; justify-content: center; font-weight: bold;"
+        default = "padding: 0.5rem 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;"
     )]
-    pub avatar_fallback_style: &'static str,
-    #[props(default = "Profile")]
-    pub profile_text: &'static str,
-    #[props(default = None)]
-    pub profile_image_url: Option<String>,
-    pub is_open: bool,
-    pub toggle: EventHandler<MouseEvent>,
-    #[props(default = String::new())]
-    pub dropdown_class: String,
+    pub button_style: &'static str,
+    /// Optional class for the submit button.
     #[props(default = String::new())]
-    pub item_class: String,
+    pub button_class: String,
+    /// Provider of the suggestions listed under the input as the user types.
+    #[props(default = None)]
+    pub suggestions: Option<SuggestionProvider>,
+    /// Callback fired with the chosen suggestion, before navigating to its `link` (if any).
+    #[props(default)]
+    pub on_suggestion_select: EventHandler<Suggestion>,
+    /// Delay (ms) after the last keystroke before an asynchronous provider is called.
+    #[props(default = 300)]
+    pub debounce_ms: u32,
+    /// Text shown while an asynchronous provider is loading.
+    #[props(into, default = "Loading...".to_string())]
+    pub suggestions_loading_text: String,
+    /// Text shown when the provider finds no suggestions.
+    #[props(into, default = "No results".to_string())]
+    pub suggestions_empty_text: String,
+    /// Style for the suggestions listbox.
+    #[props(default = SUGGESTIONS_STYLE)]
+    pub suggestions_style: &'static str,
+    /// Optional class for the suggestions listbox.
     #[props(default = String::new())]
-    pub wrapper_class: String,
+    pub suggestions_class: String,
+    /// Style for each suggestion.
+    #[props(default = "padding: 0.5rem 1rem; cursor: pointer;")]
+    pub suggestion_style: &'static str,
+    /// Optional class for each suggestion.
     #[props(default = String::new())]
-    pub avatar_class: String,
+    pub suggestion_class: String,
+    /// Style added to the highlighted suggestion.
+    #[props(default = "background: var(--navbar-highlight, #f0f0f0);")]
+    pub suggestion_active_style: &'static str,
+    /// Optional class added to the highlighted suggestion.
     #[props(default = String::new())]
-    pub button_class: String,
+    pub suggestion_active_class: String,
 }
 
+/// Renders the search input inside a `role="search"` form, so `Enter` submits it.
+///
+/// With `suggestions`, the input is a WAI-ARIA combobox: a listbox of suggestions opens under it
+/// as the user types, `ArrowDown`/`ArrowUp` highlight a suggestion, `Enter` chooses it and
+/// `Escape` (or a click outside) closes the listbox.
 #[component]
-pub fn ProfileMenu(props: ProfileMenuProps) -> Element {
-    let fallback = props.profile_text.chars().next().unwrap_or('P');
+pub fn NavbarSearch(props: SearchProps) -> Element {
+    let id = use_element_id("search");
+    let listbox_id = use_element_id("suggestions");
+    let router = use_link_router();
+    // Mirrors the typed text, for when `value` is not controlled by the parent.
+    let mut text = use_signal(String::new);
+    let query = props.value.clone().unwrap_or_else(|| text.cloned());
+    let suggestions = use_suggestions(props.suggestions.clone(), query.clone(), props.debounce_ms);
+    let mut active = use_signal(|| None::<usize>);
+    let mut dismissed = use_signal(|| false);
+    let combobox = props.suggestions.is_some();
+    let open = combobox && !dismissed() && suggestions.read().is_open();
+
+    use_effect(move || {
+        suggestions.read();
+        active.set(None);
+    });
+    use_click_outside(
+        id.clone(),
+        open,
+        EventHandler::new(move |_| dismissed.set(true)),
+    );
+
+    let on_suggestion_select = props.on_suggestion_select;
+    let mut select = move |index: usize| {
+        let suggestion = suggestions.read().items().get(index).cloned();
+        if let Some(suggestion) = suggestion {
+            dismissed.set(true);
+            on_suggestion_select.call(suggestion.clone());
+            if let Some(link) = &suggestion.link {
+                router.navigate(link);
+            }
+        }
+    };
+
+    let value = props.value.clone();
+    let active_option = active()
+        .filter(|_| open)
+        .map(|index| item_id(&listbox_id, index));
+
     rsx! {
-        div {
-            style: props.wrapper_style,
-            class: "{props.wrapper_class}",
-            button {
-                onclick: move |e| props.toggle.call(e),
-                aria_haspopup: "true",
-                aria_expanded: props.is_open.to_string(),
-                style: props.button_style,
-                class: "{props.button_class}",
-                match &props.profile_image_url {
-                    Some(url) => rsx!(
-                        img {
-                            src: "{url}",
-                            alt: "Profile",
-                            style: props.avatar_img_style,
-                            class: "{props.avatar_class}"
+        form {
+            id: "{id}",
+            role: "search",
+            style: "position: relative; display: flex; gap: 0.5rem; margin: 0;",
+            onsubmit: move |e: FormEvent| {
+                e.prevent_default();
+                dismissed.set(true);
+                props.on_submit.call(value.clone().unwrap_or_else(|| text.cloned()));
+            },
+            input {
+                r#type: "search",
+                role: combobox.then_some("combobox"),
+                aria_autocomplete: combobox.then_some("list"),
+                aria_expanded: combobox.then(|| open.to_string()),
+                aria_controls: combobox.then(|| listbox_id.clone()),
+                aria_activedescendant: active_option,
+                aria_label: "{props.placeholder}",
+                placeholder: "{props.placeholder}",
+                style: props.input_style,
+                class: "{props.input_class}",
+                value: props.value,
+                oninput: move |e: FormEvent| {
+                    text.set(e.value());
+                    dismissed.set(false);
+                    props.on_input.call(e.value());
+                },
+                onkeydown: move |e: KeyboardEvent| {
+                    if !combobox {
+                        return;
+                    }
+                    let len = suggestions.read().items().len();
+                    match ComboboxKey::from_key(&e.key().to_string()) {
+                        Some(ComboboxKey::Close) if open => {
+                            e.prevent_default();
+                            dismissed.set(true);
                         }
-                    ),
-                    None => rsx!(
-                        div {
-                            style: props.avatar_fallback_style,
-                            class: "{props.avatar_class}",
-                            "{fallback}"
+                        Some(ComboboxKey::Select) if open => {
+                            // Without a highlighted suggestion, `Enter` submits the search instead.
+                            if let Some(index) = active() {
+                                e.prevent_default();
+                                select(index);
+                            }
                         }
-                    )
-                }
-            }
-            if props.is_open {
-                ul {
-                    style: props.dropdown_style,
-                    class: "{props.dropdown_class}",
-                    for item in props.items.iter() {
-                        li {
-                            key: "{item.id}",
-                            style: props.item_style,
-                            class: "{props.item_class}",
-                            a {
-                                href: item.link,
-                                { item.icon.clone().unwrap_or(rsx!("")) }
-                                "{item.label}"
+                        Some(key @ (ComboboxKey::Next | ComboboxKey::Previous)) => {
+                            e.prevent_default();
+                            if open {
+                                active.set(key.target(active(), len));
+                            } else {
+                                dismissed.set(false);
                             }
                         }
+                        _ => {}
                     }
+                },
+            }
+            if props.show_button {
+                button {
+                    r#type: "submit",
+                    style: props.button_style,
+                    class: "{props.button_class}",
+                    "{props.button_text}"
+                }
+            }
+            if open {
+                SearchSuggestions {
+                    id: listbox_id.clone(),
+                    suggestions: suggestions(),
+                    query: query,
+                    active: active(),
+                    on_select: select,
+                    on_highlight: move |index| active.set(Some(index)),
+                    loading_text: props.suggestions_loading_text.clone(),
+                    empty_text: props.suggestions_empty_text.clone(),
+                    list_style: props.suggestions_style,
+                    list_class: props.suggestions_class.clone(),
+                    item_style: props.suggestion_style,
+                    item_class: props.suggestion_class.clone(),
+                    active_style: props.suggestion_active_style,
+                    active_class: props.suggestion_active_class.clone(),
                 }
             }
         }
     }
 }
 
-#[derive(Clone, PartialEq)]
-pub struct MegaMenuItem {
-    pub link: &'static str,
-    pub title: &'static str,
-    pub description: &'static str,
-}
-
+/// Properties for rendering the listbox of search suggestions.
 #[derive(Props, PartialEq, Clone)]
-pub struct MegaMenuProps {
-    pub items: Vec<MegaMenuItem>,
-    #[props(default = "position: absolute; top: 60px; left: 0; z-index: 999;")]
-    pub wrapper_style: &'static str,
-    #[props(
-        default = "background: white; display: flex; gap: 2rem; padding: 1rem; box-shadow: 0 2px 8px rgba(0,0,0,0.1);"
-    )]
-    pub card_style: &'static str,
-    #[props(default = "text-decoration: none; color: black;")]
-    pub item_link_style: &'static str,
-    #[props(default = String::new())]
-    pub wrapper_class: String,
+pub struct SearchSuggestionsProps {
+    /// Element id of the listbox, referenced by the input's `aria-controls`.
+    #[props(into)]
+    pub id: String,
+    /// The suggestions for the current query.
+    pub suggestions: Suggestions,
+    /// The current query, highlighted in each suggestion.
+    #[props(into, default = String::new())]
+    pub query: String,
+    /// Index of the highlighted suggestion.
+    #[props(default = None)]
+    pub active: Option<usize>,
+    /// Callback fired with the index of a clicked suggestion.
+    #[props(default)]
+    pub on_select: EventHandler<usize>,
+    /// Callback fired with the index of the suggestion under the pointer.
+    #[props(default)]
+    pub on_highlight: EventHandler<usize>,
+    /// Text shown while the suggestions are loading.
+    #[props(into, default = "Loading...".to_string())]
+    pub loading_text: String,
+    /// Text shown when there are no suggestions.
+    #[props(into, default = "No results".to_string())]
+    pub empty_text: String,
+    /// Style for the listbox.
+    #[props(default = SUGGESTIONS_STYLE)]
+    pub list_style: &'static str,
+    /// Optional class for the listbox.
     #[props(default = String::new())]
-    pub card_class: String,
+    pub list_class: String,
+    /// Style for each suggestion.
+    #[props(default = "padding: 0.5rem 1rem; cursor: pointer;")]
+    pub item_style: &'static str,
+    /// Optional class for each suggestion.
     #[props(default = String::new())]
     pub item_class: String,
+    /// Style added to the highlighted suggestion.
+    #[props(default = "background: var(--navbar-highlight, #f0f0f0);")]
+    pub active_style: &'static str,
+    /// Optional class added to the highlighted suggestion.
+    #[props(default = String::new())]
+    pub active_class: String,
 }
 
+/// Renders the suggestions as a `role="listbox"`, with the matched part of each label in a
+/// `<mark>`, or a loading or empty state.
 #[component]
-pub fn MegaMenu(props: MegaMenuProps) -> Element {
+pub fn SearchSuggestions(props: SearchSuggestionsProps) -> Element {
+    let status = match &props.suggestions {
+        Suggestions::Loading => Some(&props.loading_text),
+        Suggestions::Ready(items) if items.is_empty() => Some(&props.empty_text),
+        _ => None,
+    };
+
     rsx! {
-        div {
-            style: props.wrapper_style,
-            class: "{props.wrapper_class}",
-            div {
-                style: props.card_style,
-                class: "{props.card_class}",
-                for item in props.items.iter() {
-                    a {
-                        href: item.link,
-                        style: props.item_link_style,
-                        class: "{props.item_class}",
-                        div {
-                            p { strong { "{item.title}" } }
-                            p { "{item.description}" }
+        ul {
+            id: "{props.id}",
+            role: "listbox",
+            aria_busy: (props.suggestions == Suggestions::Loading).then_some("true"),
+            style: props.list_style,
+            class: "{props.list_class}",
+            if let Some(text) = status {
+                li {
+                    role: "option",
+                    aria_disabled: "true",
+                    style: props.item_style,
+                    class: "{props.item_class}",
+                    "{text}"
+                }
+            }
+            for (index, suggestion) in props.suggestions.items().iter().enumerate() {
+                li {
+                    key: "{suggestion.id}",
+                    id: item_id(&props.id, index),
+                    role: "option",
+                    aria_selected: (props.active == Some(index)).to_string(),
+                    style: with_active(props.item_style, props.active_style, props.active == Some(index)),
+                    class: with_active(&props.item_class, &props.active_class, props.active == Some(index)),
+                    // Keeps focus in the input, so the listbox stays open until the click.
+                    onmousedown: move |e: MouseEvent| e.prevent_default(),
+                    onmouseenter: move |_| props.on_highlight.call(index),
+                    onclick: move |_| props.on_select.call(index),
+                    for (segment, matched) in highlight_match(&suggestion.label, &props.query) {
+                        if matched {
+                            mark { "{segment}" }
+                        } else {
+                            "{segment}"
                         }
                     }
+                    if let Some(description) = &suggestion.description {
+                        div { style: "font-size: 0.875em; opacity: 0.7;", "{description}" }
+                    }
                 }
             }
         }
     }
 }
 
+/// Default style of the theme toggle's group of buttons.
+const THEME_TOGGLE_STYLE: &str = "display: inline-flex; gap: 0.25rem; margin-left: 1rem;";
+
+/// Default style of each theme toggle button.
+const THEME_BUTTON_STYLE: &str = "padding: 0.25rem 0.5rem; border: 1px solid var(--navbar-border, #ccc); border-radius: 4px; background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;";
+
+/// Default style of the backdrop behind the command palette.
+const PALETTE_BACKDROP_STYLE: &str = "position: fixed; inset: 0; z-index: 2000; display: flex; justify-content: center; align-items: flex-start; padding-top: 15vh; background: var(--navbar-backdrop, rgba(0,0,0,0.4));";
+
+/// Default style of the command palette dialog.
+const PALETTE_DIALOG_STYLE: &str = "width: min(36rem, 90vw); background: var(--navbar-surface, white); border-radius: 8px; box-shadow: 0 8px 24px var(--navbar-shadow, rgba(0,0,0,0.2)); overflow: hidden;";
+
+/// Properties for the `CommandPalette` component.
 #[derive(Props, PartialEq, Clone)]
-pub struct MobileMenuProps {
+pub struct CommandPaletteProps {
+    /// Menu items listed under `pages_section`, submenus included.
+    #[props(default)]
     pub menus: Vec<Menu>,
-    #[props(default = "padding: 0.5rem 1rem;")]
-    pub menu_item_style: &'static str,
+    /// Dropdown items listed under `account_section`.
+    #[props(default)]
     pub dropdown_items: Vec<DropdownItem>,
-    #[props(default = "background: #f9f9f9; padding: 1rem; border-radius: 4px;")]
-    pub dropdown_style: &'static str,
-    #[props(default = "padding: 0.5rem;")]
-    pub dropdown_item_style: &'static str,
-    #[props(default = false)]
-    pub show_profile_menu: bool,
-    #[props(default = false)]
-    pub show_search: bool,
-    #[props(default = "padding: 0.5rem; width: 100%; border: 1px solid #ccc;")]
-    pub search_input_style: &'static str,
-    #[props(default = "Search...")]
-    pub search_placeholder: &'static str,
-    #[props(
-        default = "display: flex; flex-direction: column; gap: 1rem; padding: 1rem; position: absolute; top: 100%; left: 0; background: white; z-index: 999; width: max-content;"
-    )]
-    pub container_style: &'static str,
+    /// Mega menu items listed under `more_section`.
+    #[props(default)]
+    pub mega_menu_items: Vec<MegaMenuItem>,
+    /// App commands (links or actions), each listed under its own section.
+    #[props(default)]
+    pub commands: Vec<Command>,
+    /// Key that opens the palette together with `Ctrl` (or `Cmd` on macOS).
+    #[props(into, default = "k".to_string())]
+    pub shortcut: String,
+    /// Callback fired with the chosen command, before it navigates or runs.
+    #[props(default)]
+    pub on_select: EventHandler<Command>,
+    /// Accessible name of the dialog.
+    #[props(into, default = "Command palette".to_string())]
+    pub label: String,
+    /// Placeholder text for the filter input.
+    #[props(into, default = "Search pages and actions...".to_string())]
+    pub placeholder: String,
+    /// Text shown when nothing matches the filter.
+    #[props(into, default = "No results".to_string())]
+    pub empty_text: String,
+    /// Section heading of the menu items.
+    #[props(into, default = "Pages".to_string())]
+    pub pages_section: String,
+    /// Section heading of the dropdown items.
+    #[props(into, default = "Account".to_string())]
+    pub account_section: String,
+    /// Section heading of the mega menu items.
+    #[props(into, default = "More".to_string())]
+    pub more_section: String,
+    /// Section heading of the recently used commands, listed first while the filter is empty.
+    #[props(into, default = "Recent".to_string())]
+    pub recent_section: String,
+    /// Number of recently used commands to remember.
+    #[props(default = 5)]
+    pub recent_limit: usize,
+    /// `localStorage` key the recently used commands are saved under; empty to keep them in
+    /// memory only.
+    #[props(into, default = "navbar-command-palette-recent".to_string())]
+    pub recent_storage_key: String,
+    /// Style for the backdrop covering the page.
+    #[props(default = PALETTE_BACKDROP_STYLE)]
+    pub backdrop_style: &'static str,
+    /// Optional class for the backdrop.
     #[props(default = String::new())]
-    pub container_class: String,
+    pub backdrop_class: String,
+    /// Style for the dialog.
+    #[props(default = PALETTE_DIALOG_STYLE)]
+    pub dialog_style: &'static str,
+    /// Optional class for the dialog.
     #[props(default = String::new())]
-    pub menu_item_class: String,
+    pub dialog_class: String,
+    /// Style for the filter input.
+    #[props(
+        default = "width: 100%; box-sizing: border-box; padding: 0.75rem 1rem; font-size: 1rem; border: none; border-bottom: 1px solid var(--navbar-border, #eee); outline: none; background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
+    )]
+    pub input_style: &'static str,
+    /// Optional class for the filter input.
     #[props(default = String::new())]
-    pub dropdown_class: String,
+    pub input_class: String,
+    /// Style for the section headings.
+    #[props(
+        default = "padding: 0.5rem 1rem 0.25rem; font-size: 0.75rem; font-weight: 600; text-transform: uppercase; opacity: 0.6;"
+    )]
+    pub section_style: &'static str,
+    /// Optional class for the section headings.
     #[props(default = String::new())]
-    pub dropdown_item_class: String,
+    pub section_class: String,
+    /// Style for each command.
+    #[props(default = "padding: 0.5rem 1rem; cursor: pointer;")]
+    pub item_style: &'static str,
+    /// Optional class for each command.
     #[props(default = String::new())]
-    pub search_input_class: String,
+    pub item_class: String,
+    /// Style added to the highlighted command.
+    #[props(default = "background: var(--navbar-highlight, #f0f0f0);")]
+    pub item_active_style: &'static str,
+    /// Optional class added to the highlighted command.
+    #[props(default = String::new())]
+    pub item_active_class: String,
 }
 
+/// A command palette over the navbar's items and app commands, opened with `Ctrl+K` / `Cmd+K`.
+///
+/// The palette is a modal dialog with a filter input and a listbox of commands grouped by section.
+/// Typing fuzzy-filters the commands (best match first), `ArrowDown`/`ArrowUp` move the
+/// highlight, `Enter` chooses the highlighted command and `Escape` or a click on the backdrop
+/// closes the palette, returning focus to where it was. Choosing a command navigates to its link
+/// (through the router, if any) or runs its action, and remembers it in the "Recent" section,
+/// saved to `localStorage`.
+///
+/// The [`Navbar`] renders one with `show_command_palette`; render it directly for full styling.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::core::Command;
+/// use navbar::dioxus::{CommandPalette, Menu};
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         CommandPalette {
+///             menus: vec![Menu::new(1, "Docs", "/docs")],
+///             commands: vec![Command::action("logout", "Log out", || {}).with_section("Account")],
+///         }
+///     }
+/// }
+/// ```
 #[component]
-pub fn MobileMenu(props: MobileMenuProps) -> Element {
+pub fn CommandPalette(props: CommandPaletteProps) -> Element {
+    let input_id = use_element_id("command-palette-input");
+    let listbox_id = use_element_id("command-palette-list");
+    let router = use_link_router();
+    let mut open = use_signal(|| false);
+    let mut query = use_signal(String::new);
+    let mut active = use_signal(|| 0_usize);
+    let mut recent = use_signal(|| {
+        RecentCommands::from_ids(
+            load_recent_commands(&props.recent_storage_key),
+            props.recent_limit,
+        )
+    });
+    let focus_return = use_hook(|| Rc::new(FocusReturn::default()));
+    let shortcut = use_hook(|| Rc::new(RefCell::new(None::<EventListener>)));
+
+    let commands = use_memo(use_reactive(
+        (
+            &props.menus,
+            &props.dropdown_items,
+            &props.mega_menu_items,
+            &props.commands,
+            &props.pages_section,
+            &props.account_section,
+            &props.more_section,
+        ),
+        |(menus, dropdown_items, mega_menu_items, commands, pages, account, more)| {
+            CommandSet::new()
+                .with_menus(&pages, &menus)
+                .with_dropdown_items(&account, &dropdown_items)
+                .with_mega_menu_items(&more, &mega_menu_items)
+                .with_commands(commands)
+        },
+    ));
+    let groups = commands
+        .read()
+        .filter(&query.read(), recent.read().ids(), &props.recent_section);
+    let listed: Vec<Command> = groups
+        .iter()
+        .flat_map(|group| group.commands.iter().cloned())
+        .collect();
+    let offsets: Vec<usize> = groups
+        .iter()
+        .scan(0, |offset, group| {
+            let start = *offset;
+            *offset += group.commands.len();
+            Some(start)
+        })
+        .collect();
+
+    use_effect(use_reactive(&props.shortcut, move |key| {
+        *shortcut.borrow_mut() = listen_shortcut(key, move || open.set(true));
+    }));
+    {
+        let input_id = input_id.clone();
+        use_effect(move || {
+            if open() {
+                focus_return.capture();
+                focus_element(&input_id);
+            } else {
+                focus_return.restore();
+                query.set(String::new());
+                active.set(0);
+            }
+        });
+    }
+    {
+        let listbox_id = listbox_id.clone();
+        use_effect(move || scroll_into_view(&item_id(&listbox_id, active())));
+    }
+
+    let key = props.recent_storage_key.clone();
+    let on_select = props.on_select;
+    let choose = EventHandler::new(move |command: Command| {
+        open.set(false);
+        recent.write().push(&command.id);
+        save_recent_commands(&key, recent.read().ids());
+        on_select.call(command.clone());
+        match &command.action {
+            CommandAction::Navigate(link) => router.navigate(link),
+            CommandAction::Run(action) => action(),
+        }
+    });
+
+    let active_option = (!listed.is_empty()).then(|| item_id(&listbox_id, active()));
+    let onkeydown = move |e: KeyboardEvent| {
+        let key = e.key().to_string();
+        // The input is the only focusable element, which keeps focus inside the dialog.
+        if key == "Tab" {
+            e.prevent_default();
+            return;
+        }
+        match ComboboxKey::from_key(&key) {
+            Some(ComboboxKey::Close) => {
+                e.prevent_default();
+                open.set(false);
+            }
+            Some(ComboboxKey::Select) => {
+                e.prevent_default();
+                if let Some(command) = listed.get(active()).cloned() {
+                    choose.call(command);
+                }
+            }
+            Some(key @ (ComboboxKey::Next | ComboboxKey::Previous)) => {
+                e.prevent_default();
+                if let Some(index) = key.target(Some(active()), listed.len()) {
+                    active.set(index);
+                }
+            }
+            None => {}
+        }
+    };
+
+    if !open() {
+        return rsx! {};
+    }
+
+    let is_empty = groups.is_empty();
+    let filter = query.cloned();
     rsx! {
         div {
-            style: props.container_style,
-            class: "{props.container_class}",
-            NavbarMenu {
-                menus: props.menus.clone(),
-                menu_item_style: props.menu_item_style,
-                menu_item_class: props.menu_item_class.clone(),
-                list_style: "",
-                list_class: "",
-            }
-            if props.show_search {
+            style: props.backdrop_style,
+            class: "{props.backdrop_class}",
+            onclick: move |_| open.set(false),
+            div {
+                role: "dialog",
+                aria_modal: "true",
+                aria_label: "{props.label}",
+                style: props.dialog_style,
+                class: "{props.dialog_class}",
+                onclick: move |e: MouseEvent| e.stop_propagation(),
                 input {
+                    id: "{input_id}",
                     r#type: "text",
-                    style: props.search_input_style,
-                    placeholder: props.search_placeholder,
-                    class: "{props.search_input_class}",
+                    role: "combobox",
+                    aria_autocomplete: "list",
+                    aria_expanded: "true",
+                    aria_controls: "{listbox_id}",
+                    aria_activedescendant: active_option,
+                    aria_label: "{props.placeholder}",
+                    placeholder: "{props.placeholder}",
+                    autocomplete: "off",
+                    style: props.input_style,
+                    class: "{props.input_class}",
+                    value: "{query}",
+                    oninput: move |e: FormEvent| {
+                        query.set(e.value());
+                        active.set(0);
+                    },
+                    onkeydown: onkeydown,
                 }
-            }
-            if props.show_profile_menu {
-                ul {
-                    style: props.dropdown_style,
-                    class: "{props.dropdown_class}",
-                    for item in props.dropdown_items.iter() {
-                        li {
-                            key: "{item.id}",
-                            style: props.dropdown_item_style,
-                            class: "{props.dropdown_item_class}",
-                            a { href: item.link, "{item.label}" }
+                div {
+                    id: "{listbox_id}",
+                    role: "listbox",
+                    aria_label: "{props.label}",
+                    style: "max-height: 20rem; overflow-y: auto; padding: 0.25rem 0;",
+                    for (group_index, (group, offset)) in groups.into_iter().zip(offsets).enumerate() {
+                        div {
+                            key: "{group_index}",
+                            role: "group",
+                            aria_labelledby: "{listbox_id}-group-{group_index}",
+                            div {
+                                id: "{listbox_id}-group-{group_index}",
+                                role: "presentation",
+                                style: props.section_style,
+                                class: "{props.section_class}",
+                                "{group.section}"
+                            }
+                            for (position, command) in group.commands.into_iter().enumerate() {
+                                div {
+                                    id: item_id(&listbox_id, offset + position),
+                                    role: "option",
+                                    aria_selected: (active() == offset + position).to_string(),
+                                    style: with_active(props.item_style, props.item_active_style, active() == offset + position),
+                                    class: with_active(&props.item_class, &props.item_active_class, active() == offset + position),
+                                    onmouseenter: move |_| active.set(offset + position),
+                                    onclick: {
+                                        let command = command.clone();
+                                        move |_| choose.call(command.clone())
+                                    },
+                                    for (segment, matched) in highlight_match(&command.label, &filter) {
+                                        if matched {
+                                            mark { "{segment}" }
+                                        } else {
+                                            "{segment}"
+                                        }
+                                    }
+                                    if let Some(description) = &command.description {
+                                        div { style: "font-size: 0.875em; opacity: 0.7;", "{description}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if is_empty {
+                        div {
+                            role: "status",
+                            style: props.item_style,
+                            class: "{props.item_class}",
+                            "{props.empty_text}"
                         }
                     }
                 }
@@ -401,255 +1208,2436 @@ pub fn MobileMenu(props: MobileMenuProps) -> Element {
     }
 }
 
-/// Props for the `Navbar` component.
-///
-/// These props control the display, content, and styling of a responsive navigation bar.
-#[derive(Props, Clone, PartialEq)]
-pub struct NavbarProps {
-    /// Top-level menu items for the main navigation bar.
-    #[props(default)]
-    pub menus: Vec<Menu>,
-
-    /// Items for the profile dropdown menu.
-    #[props(default)]
-    pub dropdown_items: Vec<DropdownItem>,
-
-    /// Items shown in a mega menu panel.
-    #[props(default)]
-    pub mega_menu_items: Vec<MegaMenuItem>,
-
-    /// Whether to display the search input field.
-    #[props(default)]
-    pub show_search: bool,
-
-    /// Whether to show the mega menu.
+/// Properties for the `ThemeToggle` component.
+#[derive(Props, PartialEq, Clone)]
+pub struct ThemeToggleProps {
+    /// Mode used until the user picks one (or when nothing was saved).
     #[props(default)]
-    pub show_mega_menu: bool,
-
-    /// Whether to show the profile menu (avatar or dropdown).
+    pub default_mode: ThemeMode,
+    /// `localStorage` key the picked mode is saved under; empty to keep it in memory only.
+    #[props(into, default = "navbar-theme".to_string())]
+    pub storage_key: String,
+    /// Attribute of `<html>` set to the applied theme (`"light"` or `"dark"`); empty to leave
+    /// the document alone.
+    #[props(into, default = "data-theme".to_string())]
+    pub attribute: String,
+    /// Callback fired with the preference on mount and whenever the mode or, in system mode,
+    /// the system preference changes.
     #[props(default)]
-    pub show_profile_menu: bool,
-
-    /// Text for the main call-to-action button.
-    #[props(default = "Sign up")]
-    pub button_text: &'static str,
-
-    /// Href for the main call-to-action button.
-    #[props(default = "#")]
-    pub button_href: &'static str,
-
-    /// Source path for the logo image.
-    #[props(default = "/assets/logo.webp")]
-    pub logo_src: &'static str,
-
-    /// Alternative text for the logo image.
-    #[props(default = "logo")]
-    pub logo_alt: &'static str,
-
-    /// Link the logo should redirect to when clicked.
-    #[props(default = "/")]
-    pub logo_link: &'static str,
+    pub on_theme_change: EventHandler<ThemePreference>,
+    /// Accessible name of the group of buttons.
+    #[props(into, default = "Theme".to_string())]
+    pub label: String,
+    /// Text of the light mode button.
+    #[props(into, default = "Light".to_string())]
+    pub light_text: String,
+    /// Text of the dark mode button.
+    #[props(into, default = "Dark".to_string())]
+    pub dark_text: String,
+    /// Text of the system mode button.
+    #[props(into, default = "System".to_string())]
+    pub system_text: String,
+    /// Style for the group of buttons.
+    #[props(default = THEME_TOGGLE_STYLE)]
+    pub style: &'static str,
+    /// Optional class for the group of buttons.
+    #[props(default = String::new())]
+    pub class: String,
+    /// Style for each button.
+    #[props(default = THEME_BUTTON_STYLE)]
+    pub button_style: &'static str,
+    /// Optional class for each button.
+    #[props(default = String::new())]
+    pub button_class: String,
+    /// Style added to the button of the current mode.
+    #[props(default = "background: var(--navbar-highlight, #f0f0f0); font-weight: bold;")]
+    pub active_style: &'static str,
+    /// Optional class added to the button of the current mode.
+    #[props(default = String::new())]
+    pub active_class: String,
+}
 
-    /// Target attribute for the CTA button link.
-    #[props(default = "_blank")]
-    pub button_target: &'static str,
+/// A light/dark/system switch that themes the document.
+///
+/// The picked mode is saved to `localStorage` and restored once mounted: the first render, on
+/// the server and when hydrating, shows `default_mode`, so both agree. In system mode, the theme
+/// follows `prefers-color-scheme`, live. The applied theme is set on `<html>` as
+/// `data-theme="light"` or `data-theme="dark"`, for the app's own styles, and reported through
+/// `on_theme_change`. The buttons form a group with `aria-pressed` on the current mode.
+///
+/// The [`Navbar`] renders one with `show_theme_toggle`, switching to its `dark_theme`.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::dioxus::ThemeToggle;
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         ThemeToggle { storage_key: "app-theme" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ThemeToggle(props: ThemeToggleProps) -> Element {
+    let mut mode = use_signal(|| props.default_mode);
+    let mut system_dark = use_signal(|| false);
+    // The server can't read the saved mode, so the first render shows `default_mode` on both
+    // sides and the saved mode and the system preference are read once mounted.
+    let mut restored = use_signal(|| false);
+    let listener = use_hook(|| Rc::new(RefCell::new(None::<EventListener>)));
 
-    /// Placeholder text for the search input.
-    #[props(default = "Search...")]
-    pub search_placeholder: &'static str,
+    let storage_key = props.storage_key.clone();
+    use_effect(move || {
+        if let Some(saved) = load_theme_mode(&storage_key) {
+            mode.set(saved);
+        }
+        system_dark.set(prefers_dark_scheme());
+        restored.set(true);
+        *listener.borrow_mut() = watch_color_scheme(move |dark| system_dark.set(dark));
+    });
+    let on_theme_change = props.on_theme_change;
+    use_effect(use_reactive(&props.attribute, move |attribute| {
+        if !restored() {
+            return;
+        }
+        let preference = ThemePreference {
+            mode: mode(),
+            system_dark: system_dark(),
+        };
+        set_document_theme(&attribute, preference.theme());
+        on_theme_change.call(preference);
+    }));
 
-    /// Text label for the profile menu button/avatar.
-    #[props(default = "Profile")]
-    pub profile_button_text: &'static str,
+    rsx! {
+        div {
+            role: "group",
+            aria_label: "{props.label}",
+            style: props.style,
+            class: "{props.class}",
+            for option in ThemeMode::ALL {
+                button {
+                    key: "{option.as_str()}",
+                    r#type: "button",
+                    aria_pressed: (option == mode()).to_string(),
+                    onclick: {
+                        let key = props.storage_key.clone();
+                        move |_| {
+                            save_theme_mode(&key, option);
+                            mode.set(option);
+                        }
+                    },
+                    style: with_active(props.button_style, props.active_style, option == mode()),
+                    class: with_active(&props.button_class, &props.active_class, option == mode()),
+                    match option {
+                        ThemeMode::Light => props.light_text.clone(),
+                        ThemeMode::Dark => props.dark_text.clone(),
+                        ThemeMode::System => props.system_text.clone(),
+                    }
+                }
+            }
+        }
+    }
+}
 
-    // Styles
-    /// Style for the main navbar container.
+/// Properties for rendering a styled button with a link.
+#[derive(Props, PartialEq, Clone)]
+pub struct ButtonProps {
+    /// The URL the button should navigate to.
+    #[props(into, default = "#".to_string())]
+    pub href: String,
+    /// Text displayed inside the button.
+    #[props(into, default = "Click Me".to_string())]
+    pub text: String,
+    /// Style for the outer div wrapping the button.
     #[props(
-        default = "display: flex; align-items: center; justify-content: space-between; padding: 1rem; background-color: #fff;"
+        default = "padding: 0.5rem 1rem; background-color: var(--navbar-accent, #007bff); border-radius: 4px;"
     )]
-    pub navbar_style: &'static str,
+    pub button_style: &'static str,
+    /// Style applied to the anchor inside the button.
+    #[props(default = "color: var(--navbar-accent-text, white); text-decoration: none;")]
+    pub link_style: &'static str,
+    /// Target attribute for the anchor link (e.g. `_blank`, `_self`).
+    #[props(into, default = "_self".to_string())]
+    pub target: String,
+    /// Optional class for the outer button div.
+    #[props(default = String::new())]
+    pub button_class: String,
+    /// Optional class for the inner anchor tag.
+    #[props(default = String::new())]
+    pub link_class: String,
+}
 
-    /// Style for the inner container within the navbar.
-    #[props(default = "display: flex; align-items: center; width: 100%;")]
-    pub inner_style: &'static str,
+#[component]
+pub fn NavbarButton(props: ButtonProps) -> Element {
+    let router = use_link_router();
+    rsx! {
+        div {
+            style: props.button_style,
+            class: "{props.button_class}",
+            a {
+                href: router.href(&props.href),
+                onclick: router.onclick(&props.href, &props.target),
+                target: props.target,
+                style: props.link_style,
+                class: "{props.link_class}",
+                "{props.text}"
+            }
+        }
+    }
+}
 
-    /// Style for the maximum width container wrapping all navbar elements.
+#[derive(Props, PartialEq, Clone)]
+pub struct ProfileMenuProps {
+    pub items: Vec<DropdownItem>,
     #[props(
-        default = "max-width: 1200px; margin: auto; display: flex; width: 100%; align-items: center; justify-content: space-between;"
+        default = "position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); z-index: 1000;"
     )]
-    pub container_style: &'static str,
-
-    /// Style applied to the logo image.
-    #[props(default = "height: 40px;")]
-    pub logo_style: &'static str,
-
-    /// Style for the hamburger menu button.
-    #[props(default = "display: flex; flex-direction: column; gap: 5px; cursor: pointer;")]
-    pub menu_toggle_style: &'static str,
-
-    /// Style for the lines in the hamburger icon.
-    #[props(default = "width: 25px; height: 2px; background: black;")]
-    pub line_style: &'static str,
-
-    /// Style applied to the `<ul>` navigation container.
-    #[props(default = "display: flex; gap: 1rem; list-style: none; margin: 0; padding: 0;")]
-    pub nav_style: &'static str,
-
-    /// Style for individual `<li>` items in the navbar.
+    pub dropdown_style: &'static str,
+    #[props(default = "padding: 0.5rem 1rem; white-space: nowrap;")]
+    pub item_style: &'static str,
+    #[props(default = "position: relative; display: inline-block; margin: 0 0 0 1rem;")]
+    pub wrapper_style: &'static str,
+    #[props(default = "background: none; border: none; cursor: pointer;")]
+    pub button_style: &'static str,
+    #[props(default = "width: 40px; height: 40px; border-radius: 50%; object-fit: cover;")]
+    pub avatar_img_style: &'static str,
     #[props(
-        default = "text-decoration: none; color: black; padding: 0.5rem 1rem; transition: background 0.3s ease;"
+        default = "width: 40px; height: 40px; border-radius: 50%; background-color: var(--navbar-border, #ccc); color: var(--navbar-accent-text, #fff); display: flex; align-items: center; justify-content: center; font-weight: bold;"
     )]
-    pub menu_item_style: &'static str,
+    pub avatar_fallback_style: &'static str,
+    #[props(into, default = "Profile".to_string())]
+    pub profile_text: String,
+    #[props(default = None)]
+    pub profile_image_url: Option<String>,
+    pub is_open: bool,
+    pub toggle: EventHandler<MouseEvent>,
+    #[props(default = String::new())]
+    pub dropdown_class: String,
+    #[props(default = String::new())]
+    pub item_class: String,
+    #[props(default = String::new())]
+    pub wrapper_class: String,
+    #[props(default = String::new())]
+    pub avatar_class: String,
+    #[props(default = String::new())]
+    pub button_class: String,
+    /// Enter and exit transition of the dropdown list.
+    #[props(default = None)]
+    pub transition: Option<Transition>,
+    /// Whether a click outside the open dropdown closes it.
+    #[props(default = true)]
+    pub close_on_outside_click: bool,
+    /// Callback to close the dropdown, e.g. after a click outside of it or `Escape`.
+    #[props(default)]
+    pub on_close: EventHandler<()>,
+    /// Callback to open the dropdown from the keyboard (`ArrowDown`/`ArrowUp` on the button).
+    #[props(default)]
+    pub on_open: EventHandler<()>,
+    /// Item to focus when the dropdown is shown or this prop changes, e.g.
+    /// `Some(MenuKey::First)` after opening it from the keyboard.
+    #[props(default)]
+    pub initial_focus: Option<MenuKey>,
+    /// Id of the dropdown list, e.g. [`PopupProps::id`]. The button's id is `"{id}-button"`.
+    /// Generated if `None`.
+    #[props(default)]
+    pub id: Option<String>,
+    /// Handles key presses on the button in place of its built-in menu button keys, e.g.
+    /// [`TriggerProps::onkeydown`].
+    #[props(default)]
+    pub on_button_keydown: Option<EventHandler<KeyboardEvent>>,
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[props(default = None)]
+    pub current_path: Option<String>,
+    /// How item links are matched against the current path.
+    #[props(default)]
+    pub active_match: ActiveMatch,
+    /// Style added to the active item's link, which also gets `aria-current="page"`.
+    #[props(default = "font-weight: bold;")]
+    pub active_style: &'static str,
+    /// Optional class added to the active item's link.
+    #[props(default = String::new())]
+    pub active_class: String,
+}
 
-    /// Style for the dropdown menu panel.
-    #[props(
-        default = "position: absolute; top: 100%; left: 0; background: white; box-shadow: 0 4px 8px rgba(0,0,0,0.1); z-index: 1000;"
-    )]
+/// Renders the profile button and its dropdown following the WAI-ARIA menu button pattern.
+#[component]
+pub fn ProfileMenu(props: ProfileMenuProps) -> Element {
+    let generated_id = use_element_id("dropdown");
+    let id = props.id.clone().unwrap_or(generated_id);
+    use_click_outside(
+        id.clone(),
+        props.is_open && props.close_on_outside_click,
+        props.on_close,
+    );
+
+    let router = use_link_router();
+    let location = use_location_path();
+    let path = props
+        .current_path
+        .clone()
+        .unwrap_or_else(|| location.cloned());
+    let active = |link: &str| props.active_match.is_active(link, &path);
+    let len = props.items.len();
+    let (phase, on_transition_end) = use_transition(props.is_open, props.transition.clone());
+    let transition = props.transition.clone().unwrap_or_default();
+    let menu_button = use_menu_button(MenuButtonOptions {
+        id: id.clone(),
+        len,
+        is_open: props.is_open,
+        shown: props.is_open && phase.is_mounted(),
+        initial_focus: props.initial_focus,
+        on_open: props.on_open,
+        on_close: props.on_close,
+    });
+    let on_button_keydown = {
+        let custom = props.on_button_keydown;
+        let mut builtin = menu_button.on_button_keydown();
+        move |e: KeyboardEvent| match custom {
+            Some(custom) => custom.call(e),
+            None => builtin(e),
+        }
+    };
+
+    let fallback = props.profile_text.chars().next().unwrap_or('P');
+    rsx! {
+        div {
+            style: props.wrapper_style,
+            class: "{props.wrapper_class}",
+            button {
+                id: "{menu_button.button_id}",
+                onclick: move |e| props.toggle.call(e),
+                onkeydown: on_button_keydown,
+                aria_controls: "{id}",
+                aria_haspopup: "menu",
+                aria_expanded: props.is_open.to_string(),
+                style: props.button_style,
+                class: "{props.button_class}",
+                match &props.profile_image_url {
+                    Some(url) => rsx!(
+                        img {
+                            src: "{url}",
+                            alt: "Profile",
+                            style: props.avatar_img_style,
+                            class: "{props.avatar_class}"
+                        }
+                    ),
+                    None => rsx!(
+                        div {
+                            style: props.avatar_fallback_style,
+                            class: "{props.avatar_class}",
+                            "{fallback}"
+                        }
+                    )
+                }
+            }
+            if phase.is_mounted() {
+                ul {
+                    id: "{id}",
+                    role: "menu",
+                    aria_labelledby: "{menu_button.button_id}",
+                    style: with_phase(props.dropdown_style, transition.style(phase)),
+                    class: with_phase(&props.dropdown_class, transition.class(phase)),
+                    ontransitionend: move |_| on_transition_end.call(()),
+                    for (index, item) in props.items.iter().enumerate() {
+                        li {
+                            key: "{item.id}",
+                            role: "none",
+                            style: props.item_style,
+                            class: "{props.item_class}",
+                            a {
+                                id: item_id(&id, index),
+                                href: router.href(&item.link),
+                                onclick: router.onclick(&item.link, ""),
+                                role: "menuitem",
+                                tabindex: "-1",
+                                onkeydown: menu_button.on_item_keydown(index),
+                                aria_current: active(&item.link).then_some("page"),
+                                style: active(&item.link).then_some(props.active_style),
+                                class: active(&item.link).then_some(props.active_class.as_str()),
+                                { item.icon.clone().unwrap_or(rsx!("")) }
+                                "{item.label}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Properties for rendering the notification bell and its feed.
+#[derive(Props, PartialEq, Clone)]
+pub struct NotificationMenuProps {
+    /// Notifications listed in the feed, newest first.
+    pub items: Vec<Notification>,
+    /// Indicates if the feed is currently open.
+    pub is_open: bool,
+    /// Callback to toggle the feed.
+    pub toggle: EventHandler<MouseEvent>,
+    /// Callback to open the feed from the keyboard (`ArrowDown`/`ArrowUp` on the bell).
+    #[props(default)]
+    pub on_open: EventHandler<()>,
+    /// Callback to close the feed, e.g. after a click outside of it, `Escape` or a selection.
+    #[props(default)]
+    pub on_close: EventHandler<()>,
+    /// Called with the notification the user clicked.
+    #[props(default)]
+    pub on_select: EventHandler<Notification>,
+    /// Called when the user clicks "Mark all read". Shown while a notification is unread.
+    #[props(default)]
+    pub on_mark_all_read: EventHandler<()>,
+    /// Unread count above which the badge shows `"{badge_max}+"`.
+    #[props(default = BADGE_MAX)]
+    pub badge_max: usize,
+    /// Accessible name of the bell button.
+    #[props(into, default = "Notifications".to_string())]
+    pub label: String,
+    /// Text of the button that marks every notification as read.
+    #[props(into, default = "Mark all read".to_string())]
+    pub mark_all_read_text: String,
+    /// Text shown when the feed is empty.
+    #[props(into, default = "No notifications".to_string())]
+    pub empty_text: String,
+    /// Text read out by screen readers before an unread notification.
+    #[props(into, default = "Unread:".to_string())]
+    pub unread_text: String,
+    /// Enter and exit transition of the feed.
+    #[props(default = None)]
+    pub transition: Option<Transition>,
+    /// Whether a click outside the open feed closes it.
+    #[props(default = true)]
+    pub close_on_outside_click: bool,
+    /// Item to focus when the feed is shown or this prop changes, e.g. `Some(MenuKey::First)`
+    /// after opening it from the keyboard.
+    #[props(default)]
+    pub initial_focus: Option<MenuKey>,
+    /// Id of the feed, e.g. [`PopupProps::id`]. The bell's id is `"{id}-button"`. Generated if
+    /// `None`.
+    #[props(default)]
+    pub id: Option<String>,
+    /// Handles key presses on the bell in place of its built-in menu button keys, e.g.
+    /// [`TriggerProps::onkeydown`].
+    #[props(default)]
+    pub on_button_keydown: Option<EventHandler<KeyboardEvent>>,
+    /// Style for the wrapper around the bell.
+    #[props(default = "position: relative; display: inline-block; margin: 0 0 0 1rem;")]
+    pub wrapper_style: &'static str,
+    /// Style for the bell button.
+    #[props(
+        default = "position: relative; background: none; border: none; cursor: pointer; color: var(--navbar-fg, black); padding: 0.25rem;"
+    )]
+    pub button_style: &'static str,
+    /// Style for the unread count badge.
+    #[props(
+        default = "position: absolute; top: -0.25rem; right: -0.25rem; min-width: 1.25rem; padding: 0 0.25rem; border-radius: 9999px; background: var(--navbar-accent, #007bff); color: var(--navbar-accent-text, white); font-size: 0.75rem; line-height: 1.25rem; text-align: center;"
+    )]
+    pub badge_style: &'static str,
+    /// Style for the feed container.
+    #[props(
+        default = "position: absolute; top: 100%; right: 0; min-width: 18rem; margin: 0; padding: 0; list-style: none; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); z-index: 1000;"
+    )]
     pub dropdown_style: &'static str,
+    /// Style for each notification.
+    #[props(
+        default = "display: flex; flex-direction: column; width: 100%; padding: 0.5rem 1rem; background: none; border: none; text-align: left; cursor: pointer; color: inherit;"
+    )]
+    pub item_style: &'static str,
+    /// Style added to unread notifications.
+    #[props(default = "font-weight: bold; background: var(--navbar-highlight, #f0f0f0);")]
+    pub unread_style: &'static str,
+    /// Style for the time of each notification.
+    #[props(default = "font-size: 0.75rem; font-weight: normal; opacity: 0.7;")]
+    pub time_style: &'static str,
+    /// Style for the "Mark all read" button and the empty text.
+    #[props(
+        default = "width: 100%; padding: 0.5rem 1rem; background: none; border: none; border-top: 1px solid var(--navbar-border, #ccc); text-align: center; cursor: pointer; color: var(--navbar-accent, #007bff);"
+    )]
+    pub footer_style: &'static str,
+    /// Class name for the wrapper element.
+    #[props(default = String::new())]
+    pub wrapper_class: String,
+    /// Class name for the bell button.
+    #[props(default = String::new())]
+    pub button_class: String,
+    /// Class name for the badge.
+    #[props(default = String::new())]
+    pub badge_class: String,
+    /// Class name for the feed container.
+    #[props(default = String::new())]
+    pub dropdown_class: String,
+    /// Class name for each notification.
+    #[props(default = String::new())]
+    pub item_class: String,
+    /// Class added to unread notifications.
+    #[props(default = String::new())]
+    pub unread_class: String,
+    /// Class name for the "Mark all read" button and the empty text.
+    #[props(default = String::new())]
+    pub footer_class: String,
+}
 
-    /// Style for each dropdown menu item.
-    #[props(default = "padding: 0.5rem 1rem; white-space: nowrap;")]
+/// Renders a bell with an unread badge and a feed of notifications, following the WAI-ARIA
+/// menu button pattern like [`ProfileMenu`].
+///
+/// The badge counts unread notifications, capped at `badge_max` (`"99+"` by default).
+/// Clicking a notification calls `on_select` and closes the feed; while any is unread, the feed
+/// ends with a "Mark all read" item. The open state is controlled, e.g. by [`use_navbar`] with
+/// [`Popup::Notifications`](crate::core::Popup::Notifications).
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::core::{Notification, Popup};
+/// use navbar::dioxus::{NavbarOptions, NotificationMenu, use_navbar};
+///
+/// #[component]
+/// fn Bell() -> Element {
+///     let navbar = use_navbar(NavbarOptions::default());
+///     let bell = navbar.trigger_props(Popup::Notifications);
+///     let feed = navbar.popup_props(Popup::Notifications);
+///     rsx! {
+///         NotificationMenu {
+///             items: vec![Notification::new(1, "Build passed", "2 min ago")],
+///             id: feed.id,
+///             is_open: feed.is_open,
+///             initial_focus: feed.initial_focus,
+///             toggle: bell.onclick,
+///             on_button_keydown: bell.onkeydown,
+///             on_close: feed.on_close,
+///             on_select: |n: Notification| log::info!("{}", n.title),
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn NotificationMenu(props: NotificationMenuProps) -> Element {
+    let generated_id = use_element_id("notifications");
+    let id = props.id.clone().unwrap_or(generated_id);
+    use_click_outside(
+        id.clone(),
+        props.is_open && props.close_on_outside_click,
+        props.on_close,
+    );
+
+    let unread = unread_count(&props.items);
+    let len = props.items.len() + usize::from(unread > 0);
+    let (phase, on_transition_end) = use_transition(props.is_open, props.transition.clone());
+    let transition = props.transition.clone().unwrap_or_default();
+    let menu_button = use_menu_button(MenuButtonOptions {
+        id: id.clone(),
+        len,
+        is_open: props.is_open,
+        shown: props.is_open && phase.is_mounted(),
+        initial_focus: props.initial_focus,
+        on_open: props.on_open,
+        on_close: props.on_close,
+    });
+    let on_button_keydown = {
+        let custom = props.on_button_keydown;
+        let mut builtin = menu_button.on_button_keydown();
+        move |e: KeyboardEvent| match custom {
+            Some(custom) => custom.call(e),
+            None => builtin(e),
+        }
+    };
+
+    let on_select = props.on_select;
+    let on_close = props.on_close;
+    let on_item_click = move |item: Notification| {
+        move |_| {
+            on_select.call(item.clone());
+            on_close.call(());
+        }
+    };
+    let on_mark_all_read = props.on_mark_all_read;
+    rsx! {
+        div {
+            style: props.wrapper_style,
+            class: "{props.wrapper_class}",
+            button {
+                id: "{menu_button.button_id}",
+                r#type: "button",
+                onclick: move |e| props.toggle.call(e),
+                onkeydown: on_button_keydown,
+                aria_controls: "{id}",
+                aria_haspopup: "menu",
+                aria_expanded: props.is_open.to_string(),
+                style: props.button_style,
+                class: "{props.button_class}",
+                svg {
+                    width: "24",
+                    height: "24",
+                    view_box: "0 0 24 24",
+                    fill: "none",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                    "aria-hidden": "true",
+                    path { d: BELL_ICON_PATH }
+                }
+                span { style: VISUALLY_HIDDEN_STYLE, "{props.label}" }
+                {unread_badge(unread, props.badge_max, props.badge_style, &props.badge_class)}
+            }
+            if phase.is_mounted() {
+                ul {
+                    id: "{id}",
+                    role: "menu",
+                    aria_labelledby: "{menu_button.button_id}",
+                    style: with_phase(props.dropdown_style, transition.style(phase)),
+                    class: with_phase(&props.dropdown_class, transition.class(phase)),
+                    ontransitionend: move |_| on_transition_end.call(()),
+                    for (index, item) in props.items.iter().enumerate() {
+                        li { key: "{item.id}", role: "none",
+                            button {
+                                id: item_id(&id, index),
+                                r#type: "button",
+                                role: "menuitem",
+                                tabindex: "-1",
+                                onclick: on_item_click(item.clone()),
+                                onkeydown: menu_button.on_item_keydown(index),
+                                style: with_active(props.item_style, props.unread_style, !item.read),
+                                class: with_active(&props.item_class, &props.unread_class, !item.read),
+                                {unread_prefix(!item.read, &props.unread_text)}
+                                span { "{item.title}" }
+                                span { style: props.time_style, "{item.time}" }
+                            }
+                        }
+                    }
+                    if props.items.is_empty() {
+                        li {
+                            role: "none",
+                            style: props.footer_style,
+                            class: "{props.footer_class}",
+                            "{props.empty_text}"
+                        }
+                    }
+                    if unread > 0 {
+                        li { role: "none",
+                            button {
+                                id: item_id(&id, props.items.len()),
+                                r#type: "button",
+                                role: "menuitem",
+                                tabindex: "-1",
+                                onclick: move |_| on_mark_all_read.call(()),
+                                onkeydown: menu_button.on_item_keydown(props.items.len()),
+                                style: props.footer_style,
+                                class: "{props.footer_class}",
+                                "{props.mark_all_read_text}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Renders the unread count badge, shared by the bell and the mobile notification section.
+fn unread_badge(unread: usize, max: usize, style: &'static str, class: &str) -> Element {
+    match badge_text(unread, max) {
+        Some(badge) => rsx! { span { style, class: "{class}", "{badge}" } },
+        None => rsx! {},
+    }
+}
+
+/// Renders the text read out by screen readers before an unread notification.
+fn unread_prefix(unread: bool, text: &str) -> Element {
+    if !unread {
+        return rsx! {};
+    }
+    rsx! { span { style: VISUALLY_HIDDEN_STYLE, "{text}" } }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct MegaMenuProps {
+    pub items: Vec<MegaMenuItem>,
+    #[props(default = "position: absolute; top: 60px; left: 0; z-index: 999;")]
+    pub wrapper_style: &'static str,
+    #[props(
+        default = "background: var(--navbar-surface, white); display: flex; gap: 2rem; padding: 1rem; box-shadow: 0 2px 8px var(--navbar-shadow, rgba(0,0,0,0.1));"
+    )]
+    pub card_style: &'static str,
+    #[props(default = "text-decoration: none; color: var(--navbar-fg, black);")]
+    pub item_link_style: &'static str,
+    #[props(default = String::new())]
+    pub wrapper_class: String,
+    #[props(default = String::new())]
+    pub card_class: String,
+    #[props(default = String::new())]
+    pub item_class: String,
+    /// Whether the menu is open. It stays mounted while its exit `transition` runs.
+    #[props(default = true)]
+    pub is_open: bool,
+    /// Enter and exit transition of the menu.
+    #[props(default = None)]
+    pub transition: Option<Transition>,
+    /// Whether a click outside the open mega menu closes it.
+    #[props(default = true)]
+    pub close_on_outside_click: bool,
+    /// Callback to close the mega menu, e.g. after a click outside of it or `Escape`.
+    #[props(default)]
+    pub on_close: EventHandler<()>,
+    /// Item to focus when the menu is shown or this prop changes, e.g. `Some(MenuKey::First)`
+    /// after opening it from the keyboard.
+    #[props(default)]
+    pub initial_focus: Option<MenuKey>,
+    /// Id of the menu, e.g. for the "More" button's `aria-controls`. Generated if `None`.
+    #[props(default)]
+    pub id: Option<String>,
+}
+
+#[component]
+pub fn MegaMenu(props: MegaMenuProps) -> Element {
+    let generated_id = use_element_id("mega-menu");
+    let id = props.id.clone().unwrap_or(generated_id);
+    let router = use_link_router();
+    use_click_outside(
+        id.clone(),
+        props.is_open && props.close_on_outside_click,
+        props.on_close,
+    );
+    let (phase, on_transition_end) = use_transition(props.is_open, props.transition.clone());
+    let transition = props.transition.clone().unwrap_or_default();
+
+    let len = props.items.len();
+    {
+        let id = id.clone();
+        let shown = props.is_open && phase.is_mounted();
+        use_effect(use_reactive(
+            (&props.initial_focus, &shown, &len),
+            move |(focus, shown, len)| {
+                if let Some(key) = focus.filter(|_| shown) {
+                    focus_menu_item(&id, key, None, len);
+                }
+            },
+        ));
+    }
+
+    let on_item_keydown = {
+        let id = id.clone();
+        let on_close = props.on_close;
+        move |index: usize| {
+            let id = id.clone();
+            move |e: KeyboardEvent| match MenuKey::from_key(&e.key().to_string(), Orientation::Both)
+            {
+                Some(MenuKey::Close) => {
+                    e.prevent_default();
+                    on_close.call(());
+                }
+                Some(MenuKey::Leave) => on_close.call(()),
+                Some(key) => {
+                    let moved = focus_menu_item(&id, key, Some(index), len);
+                    if moved {
+                        e.prevent_default();
+                    }
+                }
+                None => {}
+            }
+        }
+    };
+
+    if !phase.is_mounted() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            id: "{id}",
+            style: with_phase(props.wrapper_style, transition.style(phase)),
+            class: with_phase(&props.wrapper_class, transition.class(phase)),
+            ontransitionend: move |_| on_transition_end.call(()),
+            div {
+                role: "menu",
+                style: props.card_style,
+                class: "{props.card_class}",
+                for (index, item) in props.items.iter().enumerate() {
+                    a {
+                        id: item_id(&id, index),
+                        href: router.href(&item.link),
+                        onclick: router.onclick(&item.link, ""),
+                        role: "menuitem",
+                        tabindex: "-1",
+                        onkeydown: on_item_keydown(index),
+                        style: props.item_link_style,
+                        class: "{props.item_class}",
+                        div {
+                            p { strong { "{item.title}" } }
+                            p { "{item.description}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct MobileMenuProps {
+    pub menus: Vec<Menu>,
+    #[props(default = "padding: 0.5rem 1rem;")]
+    pub menu_item_style: &'static str,
+    pub dropdown_items: Vec<DropdownItem>,
+    #[props(
+        default = "background: var(--navbar-surface-muted, #f9f9f9); padding: 1rem; border-radius: 4px;"
+    )]
+    pub dropdown_style: &'static str,
+    #[props(default = "padding: 0.5rem;")]
     pub dropdown_item_style: &'static str,
+    #[props(default = false)]
+    pub show_profile_menu: bool,
+    /// Whether to show the notification section.
+    #[props(default = false)]
+    pub show_notifications: bool,
+    /// Notifications listed in the notification section.
+    #[props(default)]
+    pub notifications: Vec<Notification>,
+    /// Called with the notification the user clicked.
+    #[props(default)]
+    pub on_notification_select: EventHandler<Notification>,
+    /// Called when the user clicks "Mark all read".
+    #[props(default)]
+    pub on_mark_all_read: EventHandler<()>,
+    /// Heading and accessible name of the notification section.
+    #[props(into, default = "Notifications".to_string())]
+    pub notifications_label: String,
+    /// Text of the button that marks every notification as read.
+    #[props(into, default = "Mark all read".to_string())]
+    pub mark_all_read_text: String,
+    /// Unread count above which the badge next to the heading shows `"{max}+"`.
+    #[props(default = BADGE_MAX)]
+    pub notifications_badge_max: usize,
+    /// Style for the unread count badge next to the heading.
+    #[props(
+        default = "display: inline-block; min-width: 1.25rem; margin-left: 0.5rem; padding: 0 0.25rem; border-radius: 9999px; background: var(--navbar-accent, #007bff); color: var(--navbar-accent-text, white); font-size: 0.75rem; line-height: 1.25rem; text-align: center;"
+    )]
+    pub notifications_badge_style: &'static str,
+    /// Optional class for the unread count badge next to the heading.
+    #[props(default = String::new())]
+    pub notifications_badge_class: String,
+    /// Text read out by screen readers before an unread notification.
+    #[props(into, default = "Unread:".to_string())]
+    pub unread_text: String,
+    /// Style for each notification button; unread ones are also bold.
+    #[props(
+        default = "display: flex; flex-direction: column; width: 100%; padding: 0; background: none; border: none; text-align: left; cursor: pointer; color: inherit;"
+    )]
+    pub notification_style: &'static str,
+    #[props(default = false)]
+    pub show_search: bool,
+    #[props(
+        default = "padding: 0.5rem; width: 100%; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
+    )]
+    pub search_input_style: &'static str,
+    #[props(into, default = "Search...".to_string())]
+    pub search_placeholder: String,
+    #[props(
+        default = "display: flex; flex-direction: column; gap: 1rem; padding: 1rem; position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); z-index: 999; width: max-content;"
+    )]
+    pub container_style: &'static str,
+    #[props(default = String::new())]
+    pub container_class: String,
+    #[props(default = String::new())]
+    pub menu_item_class: String,
+    #[props(default = String::new())]
+    pub dropdown_class: String,
+    #[props(default = String::new())]
+    pub dropdown_item_class: String,
+    #[props(default = String::new())]
+    pub search_input_class: String,
+    /// Current search text, e.g. the navbar's `search_state`. `None` leaves the input uncontrolled.
+    #[props(default = None)]
+    pub search_value: Option<String>,
+    /// Callback fired with the search text on every keystroke.
+    #[props(default)]
+    pub on_search_input: EventHandler<String>,
+    /// Callback fired with the search text on `Enter` or a click on the search button.
+    #[props(default)]
+    pub on_search_submit: EventHandler<String>,
+    /// Whether to show a submit button next to the search input.
+    #[props(default = false)]
+    pub show_search_button: bool,
+    /// Text of the search submit button.
+    #[props(into, default = "Search".to_string())]
+    pub search_button_text: String,
+    /// Style for the search submit button.
+    #[props(
+        default = "padding: 0.5rem 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;"
+    )]
+    pub search_button_style: &'static str,
+    /// Optional class for the search submit button.
+    #[props(default = String::new())]
+    pub search_button_class: String,
+    /// Provider of the suggestions listed under the search input as the user types.
+    #[props(default = None)]
+    pub suggestions: Option<SuggestionProvider>,
+    /// Callback fired with the chosen suggestion, before navigating to its `link` (if any).
+    #[props(default)]
+    pub on_suggestion_select: EventHandler<Suggestion>,
+    /// Delay (ms) after the last keystroke before an asynchronous suggestion provider is called.
+    #[props(default = 300)]
+    pub search_debounce_ms: u32,
+    /// Text shown while an asynchronous suggestion provider is loading.
+    #[props(into, default = "Loading...".to_string())]
+    pub suggestions_loading_text: String,
+    /// Text shown when the suggestion provider finds nothing.
+    #[props(into, default = "No results".to_string())]
+    pub suggestions_empty_text: String,
+    /// Style for the suggestions listbox.
+    #[props(default = SUGGESTIONS_STYLE)]
+    pub suggestions_style: &'static str,
+    /// Optional class for the suggestions listbox.
+    #[props(default = String::new())]
+    pub suggestions_class: String,
+    /// Style for each suggestion.
+    #[props(default = "padding: 0.5rem 1rem; cursor: pointer;")]
+    pub suggestion_style: &'static str,
+    /// Optional class for each suggestion.
+    #[props(default = String::new())]
+    pub suggestion_class: String,
+    /// Style added to the highlighted suggestion.
+    #[props(default = "background: var(--navbar-highlight, #f0f0f0);")]
+    pub suggestion_active_style: &'static str,
+    /// Optional class added to the highlighted suggestion.
+    #[props(default = String::new())]
+    pub suggestion_active_class: String,
+    /// Style for the expanded submenu (accordion) of an item with `children`.
+    #[props(default = "margin: 0; padding-left: 1rem; list-style: none;")]
+    pub submenu_style: &'static str,
+    /// Optional class for the expanded submenu of an item with `children`.
+    #[props(default = String::new())]
+    pub submenu_class: String,
+    /// Id of the menu container, e.g. for the toggle's `aria-controls`. Generated if `None`.
+    #[props(default = None)]
+    pub id: Option<String>,
+    /// Whether the menu is open. It stays mounted while its exit `transition` runs.
+    #[props(default = true)]
+    pub is_open: bool,
+    /// Where to move focus when the menu is shown or this prop changes: its first focusable
+    /// element, or its last one for `Some(MenuKey::Last)`.
+    #[props(default)]
+    pub initial_focus: Option<MenuKey>,
+    /// Key presses inside the menu, e.g. [`PopupProps::onkeydown`] to close it with `Escape`.
+    #[props(default)]
+    pub onkeydown: EventHandler<KeyboardEvent>,
+    /// Keeps the menu rendered while closed, for a surrounding `<details>` to show and hide it
+    /// before the app hydrates.
+    #[props(default = false)]
+    pub keep_mounted: bool,
+    /// Content rendered at the bottom of the menu.
+    #[props(default = None)]
+    pub extra: Option<Element>,
+    /// Enter and exit transition of the menu.
+    #[props(default = None)]
+    pub transition: Option<Transition>,
+    /// Layout of the menu. Drawers and the sheet are modal dialogs over a backdrop.
+    #[props(default)]
+    pub variant: MobileVariant,
+    /// Accessible name of the menu when shown as a modal dialog.
+    #[props(into, default = "Menu".to_string())]
+    pub label: String,
+    /// Style of the backdrop behind a drawer or sheet.
+    #[props(default = MOBILE_BACKDROP_STYLE)]
+    pub backdrop_style: &'static str,
+    /// Optional class for the backdrop behind a drawer or sheet.
+    #[props(into, default = String::new())]
+    pub backdrop_class: String,
+    /// Whether a click outside the open mobile menu closes it.
+    #[props(default = true)]
+    pub close_on_outside_click: bool,
+    /// Callback to close the mobile menu, e.g. after a click outside of it.
+    #[props(default)]
+    pub on_close: EventHandler<()>,
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[props(default = None)]
+    pub current_path: Option<String>,
+    /// How item links are matched against the current path.
+    #[props(default)]
+    pub active_match: ActiveMatch,
+    /// Style added to the active item's link, which also gets `aria-current="page"`.
+    #[props(default = "font-weight: bold;")]
+    pub active_style: &'static str,
+    /// Optional class added to the active item's link.
+    #[props(default = String::new())]
+    pub active_class: String,
+}
+
+#[component]
+pub fn MobileMenu(props: MobileMenuProps) -> Element {
+    let generated_id = use_element_id("mobile-menu");
+    let id = props.id.clone().unwrap_or(generated_id);
+    use_click_outside(
+        id.clone(),
+        props.is_open && props.close_on_outside_click,
+        props.on_close,
+    );
+    let router = use_link_router();
+    let location = use_location_path();
+    let path = props
+        .current_path
+        .clone()
+        .unwrap_or_else(|| location.cloned());
+    let active = |link: &str| props.active_match.is_active(link, &path);
+    let (phase, on_transition_end) = use_transition(props.is_open, props.transition.clone());
+    let modal = props.variant.is_modal();
+    let guard = use_hook(|| Rc::new(RefCell::new(None::<(FocusTrap, ScrollLock)>)));
+    {
+        let id = id.clone();
+        let on_close = props.on_close;
+        let trapped = modal && props.is_open && phase.is_mounted();
+        use_effect(use_reactive(&trapped, move |trapped| {
+            // Drops the previous trap first, so focus returns before a new one captures it.
+            guard.borrow_mut().take();
+            if trapped {
+                let trap = FocusTrap::new(id.clone(), move || on_close.call(()));
+                *guard.borrow_mut() = Some((trap, ScrollLock::new()));
+            }
+        }));
+    }
+    {
+        let id = id.clone();
+        let shown = props.is_open && phase.is_mounted();
+        use_effect(use_reactive(
+            (&props.initial_focus, &shown),
+            move |(focus, shown)| {
+                if let Some(key) = focus.filter(|_| shown) {
+                    focus_within(&id, key);
+                }
+            },
+        ));
+    }
+    if !phase.is_mounted() && !props.keep_mounted {
+        return rsx! {};
+    }
+    let transition = props.transition.clone().unwrap_or_default();
+    let style = with_phase(props.container_style, props.variant.panel_style());
+
+    rsx! {
+        if modal {
+            div {
+                style: props.backdrop_style,
+                class: "{props.backdrop_class}",
+                aria_hidden: "true",
+                onclick: move |_| props.on_close.call(()),
+            }
+        }
+        div {
+            id: "{id}",
+            role: modal.then_some("dialog"),
+            aria_modal: modal.then_some("true"),
+            aria_label: modal.then_some(props.label.as_str()),
+            style: with_phase(&style, transition.style(phase)),
+            class: with_phase(&props.container_class, transition.class(phase)),
+            onkeydown: move |e| props.onkeydown.call(e),
+            ontransitionend: move |_| on_transition_end.call(()),
+            NavbarMenu {
+                menus: props.menus.clone(),
+                menu_item_style: props.menu_item_style,
+                menu_item_class: props.menu_item_class.clone(),
+                list_style: "",
+                list_class: "",
+                orientation: Orientation::Vertical,
+                submenu_style: props.submenu_style,
+                submenu_class: props.submenu_class.clone(),
+                accordion: true,
+                current_path: path.clone(),
+                active_match: props.active_match.clone(),
+                active_style: props.active_style,
+                active_class: props.active_class.clone(),
+            }
+            if props.show_search {
+                NavbarSearch {
+                    value: props.search_value.clone(),
+                    placeholder: props.search_placeholder.clone(),
+                    input_style: props.search_input_style,
+                    input_class: props.search_input_class.clone(),
+                    on_input: props.on_search_input,
+                    on_submit: props.on_search_submit,
+                    show_button: props.show_search_button,
+                    button_text: props.search_button_text.clone(),
+                    button_style: props.search_button_style,
+                    button_class: props.search_button_class.clone(),
+                    suggestions: props.suggestions.clone(),
+                    on_suggestion_select: props.on_suggestion_select,
+                    debounce_ms: props.search_debounce_ms,
+                    suggestions_loading_text: props.suggestions_loading_text.clone(),
+                    suggestions_empty_text: props.suggestions_empty_text.clone(),
+                    suggestions_style: props.suggestions_style,
+                    suggestions_class: props.suggestions_class.clone(),
+                    suggestion_style: props.suggestion_style,
+                    suggestion_class: props.suggestion_class.clone(),
+                    suggestion_active_style: props.suggestion_active_style,
+                    suggestion_active_class: props.suggestion_active_class.clone(),
+                }
+            }
+            if props.show_profile_menu {
+                ul {
+                    style: props.dropdown_style,
+                    class: "{props.dropdown_class}",
+                    for item in props.dropdown_items.iter() {
+                        li {
+                            key: "{item.id}",
+                            style: props.dropdown_item_style,
+                            class: "{props.dropdown_item_class}",
+                            a {
+                                href: router.href(&item.link),
+                                onclick: router.onclick(&item.link, ""),
+                                aria_current: active(&item.link).then_some("page"),
+                                style: active(&item.link).then_some(props.active_style),
+                                class: active(&item.link).then_some(props.active_class.as_str()),
+                                "{item.label}"
+                            }
+                        }
+                    }
+                }
+            }
+            if props.show_notifications {
+                section { aria_label: "{props.notifications_label}",
+                    p { style: "margin: 0; padding: 0.5rem; font-weight: bold;",
+                        "{props.notifications_label}"
+                        {
+                            unread_badge(
+                                unread_count(&props.notifications),
+                                props.notifications_badge_max,
+                                props.notifications_badge_style,
+                                &props.notifications_badge_class,
+                            )
+                        }
+                    }
+                    ul {
+                        style: props.dropdown_style,
+                        class: "{props.dropdown_class}",
+                        for item in props.notifications.iter().cloned() {
+                            li {
+                                key: "{item.id}",
+                                style: props.dropdown_item_style,
+                                class: "{props.dropdown_item_class}",
+                                button {
+                                    r#type: "button",
+                                    style: with_active(props.notification_style, "font-weight: bold;", !item.read),
+                                    onclick: {
+                                        let item = item.clone();
+                                        move |_| props.on_notification_select.call(item.clone())
+                                    },
+                                    {unread_prefix(!item.read, &props.unread_text)}
+                                    span { "{item.title}" }
+                                    small { "{item.time}" }
+                                }
+                            }
+                        }
+                        if unread_count(&props.notifications) > 0 {
+                            li {
+                                style: props.dropdown_item_style,
+                                class: "{props.dropdown_item_class}",
+                                button {
+                                    r#type: "button",
+                                    style: props.notification_style,
+                                    onclick: move |_| props.on_mark_all_read.call(()),
+                                    "{props.mark_all_read_text}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            {props.extra.clone()}
+        }
+    }
+}
+
+/// Props for the `Navbar` component.
+///
+/// These props control the display, content, and styling of a responsive navigation bar.
+#[derive(Props, Clone, PartialEq)]
+pub struct NavbarProps {
+    /// Top-level menu items for the main navigation bar.
+    #[props(default)]
+    pub menus: Vec<Menu>,
+
+    /// Items for the profile dropdown menu.
+    #[props(default)]
+    pub dropdown_items: Vec<DropdownItem>,
+
+    /// Items shown in a mega menu panel.
+    #[props(default)]
+    pub mega_menu_items: Vec<MegaMenuItem>,
+
+    /// Whether to display the search input field.
+    #[props(default)]
+    pub show_search: bool,
+
+    /// Whether to show the mega menu.
+    #[props(default)]
+    pub show_mega_menu: bool,
+
+    /// Whether to show the profile menu (avatar or dropdown).
+    #[props(default)]
+    pub show_profile_menu: bool,
+
+    /// Text for the main call-to-action button.
+    #[props(into, default = "Sign up".to_string())]
+    pub button_text: String,
+
+    /// Href for the main call-to-action button.
+    #[props(into, default = "#".to_string())]
+    pub button_href: String,
+
+    /// Source path for the logo image.
+    #[props(into, default = "/assets/logo.webp".to_string())]
+    pub logo_src: String,
+
+    /// Alternative text for the logo image.
+    #[props(into, default = "logo".to_string())]
+    pub logo_alt: String,
+
+    /// Link the logo should redirect to when clicked.
+    #[props(into, default = "/".to_string())]
+    pub logo_link: String,
+
+    /// Target attribute for the CTA button link.
+    #[props(into, default = "_blank".to_string())]
+    pub button_target: String,
+
+    /// Placeholder text for the search input.
+    #[props(into, default = "Search...".to_string())]
+    pub search_placeholder: String,
+
+    /// Text label for the profile menu button/avatar.
+    #[props(into, default = "Profile".to_string())]
+    pub profile_button_text: String,
+
+    /// Accessible label (`aria-label`) of the hamburger toggle button.
+    #[props(into, default = "Toggle Menu".to_string())]
+    pub menu_toggle_label: String,
+
+    // Notification props
+    /// Whether to show the notification bell and its feed.
+    #[props(default = false)]
+    pub show_notifications: bool,
+
+    /// Notifications listed in the feed, newest first.
+    #[props(default)]
+    pub notifications: Vec<Notification>,
+
+    /// Called with the notification the user clicked.
+    #[props(default)]
+    pub on_notification_select: EventHandler<Notification>,
+
+    /// Called when the user clicks "Mark all read".
+    #[props(default)]
+    pub on_mark_all_read: EventHandler<()>,
+
+    /// Unread count above which the badge shows `"{notifications_badge_max}+"`.
+    #[props(default = BADGE_MAX)]
+    pub notifications_badge_max: usize,
+
+    /// Accessible name of the bell, and of the notification section of the mobile menu.
+    #[props(into, default = "Notifications".to_string())]
+    pub notifications_label: String,
+
+    /// Text of the button that marks every notification as read.
+    #[props(into, default = "Mark all read".to_string())]
+    pub mark_all_read_text: String,
+
+    // Layout props
+    /// Content rendered right after the logo, e.g. a workspace switcher.
+    #[props(default = None)]
+    pub start: Option<Element>,
+
+    /// Content rendered between the `start` slot and the built-in parts, e.g. a page title.
+    #[props(default = None)]
+    pub center: Option<Element>,
+
+    /// Content rendered after the built-in parts, on desktop and mobile, e.g. a cart icon.
+    #[props(default = None)]
+    pub end: Option<Element>,
+
+    /// Content rendered at the bottom of the mobile menu.
+    #[props(default = None)]
+    pub mobile_extra: Option<Element>,
+
+    /// Built-in parts of the desktop layout, in order. A part left out is hidden, on mobile too.
+    #[props(default = NavbarPart::DEFAULT_ORDER.to_vec())]
+    pub parts: Vec<NavbarPart>,
+
+    // Responsive props
+    /// Viewport width, in pixels, at or below which the mobile layout is used.
+    #[props(default = MOBILE_BREAKPOINT)]
+    pub mobile_breakpoint: f64,
+
+    /// Named breakpoint widths (`sm`, `md`, `lg`, `xl`) reported through `on_breakpoint_change`.
+    #[props(default)]
+    pub breakpoints: Breakpoints,
+
+    /// Called on mount and whenever the viewport crosses a named breakpoint or the mobile breakpoint.
+    #[props(default)]
+    pub on_breakpoint_change: EventHandler<Viewport>,
+
+    /// Layout of the first render, before the viewport is measured: the measured viewport (the
+    /// default), desktop, mobile, or a guess from the `User-Agent` header. Set it when server
+    /// rendering so the hydrating render matches the server markup.
+    #[props(default)]
+    pub initial_layout: InitialLayout,
+
+    /// Prefix of the element ids of the navbar and its menus, e.g. `navbar-mobile-menu`. Ids
+    /// don't depend on anything rendered before, so they match between a server render and
+    /// hydration; give each navbar on a page its own prefix.
+    #[props(into, default = "navbar".to_string())]
+    pub id_prefix: String,
+
+    /// Renders both the desktop and the mobile layout and shows one of them with media queries
+    /// (see [`responsive_css`]), so the right one is painted before the app hydrates, or without
+    /// JavaScript at all. The hamburger toggles a `<details>` until hydration takes over.
+    #[props(default = false)]
+    pub css_responsive: bool,
+
+    // Scroll props
+    /// How the navbar reacts to the page scrolling: static (the default), sticky, hidden while
+    /// scrolling down, or shrunk past a threshold.
+    #[props(default)]
+    pub scroll_behavior: ScrollBehavior,
+
+    /// Style added to the `<nav>` once the page is scrolled past the [`ScrollBehavior::Shrink`]
+    /// threshold.
+    #[props(
+        default = "padding-top: 0.5rem; padding-bottom: 0.5rem; box-shadow: 0 2px 4px var(--navbar-shadow, rgba(0,0,0,0.1));"
+    )]
+    pub scrolled_style: &'static str,
+
+    // Dismissal props
+    /// Whether a click outside the open mobile menu closes it.
+    #[props(default = true)]
+    pub mobile_menu_close_on_outside_click: bool,
+
+    /// Whether a click outside the open profile dropdown closes it.
+    #[props(default = true)]
+    pub dropdown_close_on_outside_click: bool,
+
+    /// Whether a click outside the open mega menu closes it.
+    #[props(default = true)]
+    pub mega_menu_close_on_outside_click: bool,
+
+    /// Whether a click outside the open notification feed closes it.
+    #[props(default = true)]
+    pub notifications_close_on_outside_click: bool,
+
+    // Transition props
+    /// Enter and exit transition of the mobile menu, e.g. [`Transition::slide_down`].
+    ///
+    /// Without one, the menu mounts and unmounts at once, except for the drawers and the sheet
+    /// of `mobile_variant`, which slide in.
+    #[props(default = None)]
+    pub mobile_menu_transition: Option<Transition>,
+
+    /// Enter and exit transition of the profile dropdown list.
+    #[props(default = None)]
+    pub dropdown_transition: Option<Transition>,
+
+    /// Enter and exit transition of the mega menu.
+    #[props(default = None)]
+    pub mega_menu_transition: Option<Transition>,
+
+    /// Enter and exit transition of the notification feed.
+    #[props(default = None)]
+    pub notifications_transition: Option<Transition>,
+
+    // Mobile menu props
+    /// Layout of the mobile menu: a dropdown under the toggle (the default), a left or right
+    /// drawer, or a full-screen sheet.
+    ///
+    /// Drawers and the sheet open over a backdrop, keep focus inside the menu and lock the page
+    /// scroll while open; `Escape` or a click on the backdrop closes them.
+    #[props(default)]
+    pub mobile_variant: MobileVariant,
+
+    /// Style of the backdrop behind a drawer or sheet.
+    #[props(default = MOBILE_BACKDROP_STYLE)]
+    pub mobile_backdrop_style: &'static str,
+
+    /// Class of the backdrop behind a drawer or sheet.
+    #[props(default)]
+    pub mobile_backdrop_class: &'static str,
+
+    // Theme props
+    /// Colors of the navbar, set as CSS custom properties on the `<nav>` and used by every
+    /// default style: [`NavbarTheme::light`] (the default), [`NavbarTheme::dark`] or a custom
+    /// theme.
+    #[props(default)]
+    pub theme: NavbarTheme,
+
+    /// Colors used instead of `theme` while the theme toggle applies the dark theme.
+    #[props(default = NavbarTheme::dark())]
+    pub dark_theme: NavbarTheme,
+
+    /// Shows a [`ThemeToggle`] (light, dark or system) at `NavbarPart::ThemeToggle` in `parts`,
+    /// last by default; the mobile layout shows it after `end`.
+    #[props(default = false)]
+    pub show_theme_toggle: bool,
+
+    /// `localStorage` key the toggle saves the picked mode under; empty to keep it in memory only.
+    #[props(into, default = "navbar-theme".to_string())]
+    pub theme_storage_key: String,
+
+    /// Callback fired with the toggle's preference on mount and whenever the applied theme may
+    /// change.
+    #[props(default)]
+    pub on_theme_change: EventHandler<ThemePreference>,
+
+    // Active route props
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[props(default = None)]
+    pub current_path: Option<String>,
+
+    /// How item links are matched against the current path.
+    #[props(default)]
+    pub active_match: ActiveMatch,
+
+    /// Style added to the link of the active menu, mobile menu and dropdown item.
+    #[props(default = "font-weight: bold;")]
+    pub active_style: &'static str,
+
+    /// CSS class added to the link of the active menu, mobile menu and dropdown item.
+    #[props(default = "")]
+    pub active_class: &'static str,
+
+    // Styles
+    /// Style for the main navbar container.
+    #[props(
+        default = "display: flex; align-items: center; justify-content: space-between; padding: 1rem; background-color: var(--navbar-bg, #fff); color: var(--navbar-fg, black);"
+    )]
+    pub navbar_style: &'static str,
+
+    /// Style for the inner container within the navbar.
+    #[props(default = "display: flex; align-items: center; width: 100%;")]
+    pub inner_style: &'static str,
+
+    /// Style for the maximum width container wrapping all navbar elements.
+    #[props(
+        default = "max-width: 1200px; margin: auto; display: flex; width: 100%; align-items: center; justify-content: space-between;"
+    )]
+    pub container_style: &'static str,
+
+    /// Style applied to the logo image.
+    #[props(default = "height: 40px;")]
+    pub logo_style: &'static str,
+
+    /// Style for the hamburger menu button.
+    #[props(
+        default = "display: flex; flex-direction: column; gap: 5px; cursor: pointer; background: none; border: none; padding: 0;"
+    )]
+    pub menu_toggle_style: &'static str,
+
+    /// How the hamburger icon animates when the mobile menu opens: not at all (the default),
+    /// morphing into an X, spinning into an X, or folding into an arrow.
+    #[props(default)]
+    pub menu_toggle_animation: ToggleAnimation,
+
+    /// Style for the lines in the hamburger icon.
+    #[props(default = "width: 25px; height: 2px; background: var(--navbar-fg, black);")]
+    pub line_style: &'static str,
+
+    /// Style applied to the `<ul>` navigation container.
+    #[props(default = "display: flex; gap: 1rem; list-style: none; margin: 0; padding: 0;")]
+    pub nav_style: &'static str,
+
+    /// Style for individual `<li>` items in the navbar.
+    #[props(
+        default = "text-decoration: none; color: var(--navbar-fg, black); padding: 0.5rem 1rem; transition: background 0.3s ease;"
+    )]
+    pub menu_item_style: &'static str,
+
+    /// Style for the dropdown menu panel.
+    #[props(
+        default = "position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); z-index: 1000;"
+    )]
+    pub dropdown_style: &'static str,
+
+    /// Style for the flyout of a menu item with `children`.
+    ///
+    /// Nested flyouts open beside their parent item instead of below it.
+    #[props(default = SUBMENU_STYLE)]
+    pub submenu_style: &'static str,
+
+    /// Style for each dropdown menu item.
+    #[props(default = "padding: 0.5rem 1rem; white-space: nowrap;")]
+    pub dropdown_item_style: &'static str,
+
+    /// Style applied to the search input field.
+    #[props(
+        default = "padding: 0.5rem; font-size: 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
+    )]
+    pub search_input_style: &'static str,
+
+    /// Style for the CTA button wrapper.
+    #[props(default = "margin-left: 1rem; white-space: nowrap;")]
+    pub button_style: &'static str,
+
+    /// Style for the `<a>` link inside the CTA button.
+    #[props(
+        default = "text-decoration: none; color: var(--navbar-accent-text, white); background: var(--navbar-accent, #007bff); padding: 0.5rem 1rem; border-radius: 4px;"
+    )]
+    pub button_link_style: &'static str,
+
+    /// Style for the mega menu dropdown.
+    #[props(
+        default = "position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); padding: 0; margin-top: 0.5rem; z-index: 1000;"
+    )]
+    pub mega_menu_style: &'static str,
+
+    /// Style for each card/item in the mega menu.
+    #[props(
+        default = "background: var(--navbar-surface, white); display: flex; gap: 2rem; padding: 1rem; box-shadow: 0 2px 8px var(--navbar-shadow, rgba(0,0,0,0.1));"
+    )]
+    pub mega_menu_card_style: &'static str,
+
+    /// Text for the "More" button shown in the navbar.
+    #[props(into, default = "More".to_string())]
+    pub more_button_text: String,
+
+    /// Style applied to the "More" button element.
+    #[props(
+        default = "background: transparent; border: none; cursor: pointer; font-weight: bold;"
+    )]
+    pub more_button_style: &'static str,
+
+    // State and image props
+    /// Optional external state for the search input value.
+    #[props(default)]
+    pub search_state: Option<Signal<String>>,
+
+    /// Callback fired with the search text on `Enter` or a click on the search button.
+    #[props(default)]
+    pub on_search_submit: EventHandler<String>,
+
+    /// Callback fired with the search text once typing pauses for `search_debounce_ms`.
+    #[props(default)]
+    pub on_search_change: EventHandler<String>,
+
+    /// Delay (ms) after the last keystroke before `on_search_change` fires.
+    #[props(default = 300)]
+    pub search_debounce_ms: u32,
+
+    /// Whether to show a submit button next to the search input.
+    #[props(default = false)]
+    pub show_search_button: bool,
+
+    /// Text of the search submit button.
+    #[props(into, default = "Search".to_string())]
+    pub search_button_text: String,
+
+    /// Style applied to the search submit button.
+    #[props(
+        default = "padding: 0.5rem 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;"
+    )]
+    pub search_button_style: &'static str,
+
+    /// Provider of the suggestions listed under the search input as the user types.
+    #[props(default = None)]
+    pub suggestions: Option<SuggestionProvider>,
+
+    /// Suggests the navbar's own items (`menus`, `dropdown_items` and `mega_menu_items`) as the
+    /// user types, fuzzy-matched on their names and descriptions, unless `suggestions` is set.
+    #[props(default = false)]
+    pub search_items: bool,
+
+    /// Callback fired with the chosen suggestion, before navigating to its `link` (if any).
+    #[props(default)]
+    pub on_suggestion_select: EventHandler<Suggestion>,
+
+    /// Text shown while an asynchronous suggestion provider is loading.
+    #[props(into, default = "Loading...".to_string())]
+    pub suggestions_loading_text: String,
+
+    /// Text shown when the suggestion provider finds nothing.
+    #[props(into, default = "No results".to_string())]
+    pub suggestions_empty_text: String,
+
+    /// Style applied to the suggestions listbox.
+    #[props(default = SUGGESTIONS_STYLE)]
+    pub suggestions_style: &'static str,
+
+    /// Style applied to each suggestion.
+    #[props(default = "padding: 0.5rem 1rem; cursor: pointer;")]
+    pub suggestion_style: &'static str,
+
+    /// Style added to the highlighted suggestion.
+    #[props(default = "background: var(--navbar-highlight, #f0f0f0);")]
+    pub suggestion_active_style: &'static str,
+
+    /// Whether to render a command palette over `menus`, `dropdown_items`, `mega_menu_items`
+    /// and `commands`, opened with `Ctrl` (or `Cmd`) and `command_palette_shortcut`.
+    #[props(default = false)]
+    pub show_command_palette: bool,
+
+    /// App commands (links or actions) listed in the command palette next to the navbar items.
+    #[props(default)]
+    pub commands: Vec<Command>,
+
+    /// Key that opens the command palette together with `Ctrl` (or `Cmd` on macOS).
+    #[props(into, default = "k".to_string())]
+    pub command_palette_shortcut: String,
+
+    /// Callback fired with the command chosen in the palette, before it navigates or runs.
+    #[props(default)]
+    pub on_command_select: EventHandler<Command>,
+
+    /// Optional profile image URL for the avatar in the navbar.
+    #[props(default)]
+    pub profile_image_url: Option<String>,
+    /// CSS class for the outer `<nav>` element of the navbar.
+    ///
+    /// Allows customization of the outermost wrapper of the navbar. Defaults to an empty string.
+    #[props(default)]
+    pub navbar_class: &'static str,
+
+    /// CSS class added to the `<nav>` once the page is scrolled past the
+    /// [`ScrollBehavior::Shrink`] threshold.
+    #[props(default)]
+    pub scrolled_class: &'static str,
+
+    /// CSS class for the inner wrapper of the navbar content.
+    ///
+    /// Used to apply styles to the inner layout container that holds all navbar elements.
+    #[props(default)]
+    pub inner_class: &'static str,
+
+    /// CSS class for the container that constrains navbar width.
+    ///
+    /// Useful for centering and limiting the maximum width of navbar contents.
+    #[props(default)]
+    pub container_class: &'static str,
+
+    /// CSS class for the logo image element.
+    ///
+    /// Use this to style the logo, such as sizing or spacing.
+    #[props(default)]
+    pub logo_class: &'static str,
+
+    /// CSS class for the menu toggle button (commonly the hamburger icon).
+    ///
+    /// Used in responsive design for toggling the navigation menu on smaller screens.
+    #[props(default)]
+    pub menu_toggle_class: &'static str,
+
+    /// CSS class for individual lines inside the hamburger toggle button.
+    ///
+    /// Typically used to style each bar in the toggle icon.
+    #[props(default)]
+    pub line_class: &'static str,
+
+    /// CSS class for the navigation list element.
+    ///
+    /// Styles the `<ul>` or equivalent container that holds menu items.
+    #[props(default)]
+    pub nav_class: &'static str,
+
+    /// CSS class for individual menu item links.
+    ///
+    /// Use this to style each navigation link (e.g., padding, hover effects).
+    #[props(default)]
+    pub menu_item_class: &'static str,
+
+    /// CSS class for the dropdown menu container.
+    ///
+    /// This class applies to the container holding dropdown items below a menu.
+    #[props(default)]
+    pub dropdown_class: &'static str,
+
+    /// CSS class for the submenu of a menu item with `children`.
+    ///
+    /// Applies to desktop flyouts and mobile accordions, at every level.
+    #[props(default)]
+    pub submenu_class: &'static str,
+
+    /// CSS class for individual dropdown menu items.
+    ///
+    /// Used to style each item within a dropdown menu.
+    #[props(default)]
+    pub dropdown_item_class: &'static str,
+
+    /// CSS class for the search input element.
+    ///
+    /// Applies styles to the optional search bar input field.
+    #[props(default)]
+    pub search_input_class: &'static str,
+
+    /// CSS class for the search submit button.
+    #[props(default)]
+    pub search_button_class: &'static str,
+
+    /// CSS class for the suggestions listbox.
+    #[props(default)]
+    pub suggestions_class: &'static str,
+
+    /// CSS class for each suggestion.
+    #[props(default)]
+    pub suggestion_class: &'static str,
+
+    /// CSS class added to the highlighted suggestion.
+    #[props(default)]
+    pub suggestion_active_class: &'static str,
+
+    /// CSS class for the container wrapping the button.
+    ///
+    /// Use this to style the wrapper that holds the sign-up/login button.
+    #[props(default)]
+    pub button_class: &'static str,
+
+    /// CSS class for the sign-up/login button link.
+    ///
+    /// Applies styles directly to the clickable `<a>` or button element.
+    #[props(default)]
+    pub button_link_class: &'static str,
+
+    /// CSS class for the mega menu container.
+    ///
+    /// Used when the mega menu is shown—applies to the entire dropdown card area.
+    #[props(default)]
+    pub mega_menu_class: &'static str,
+
+    /// CSS class for the cards within the mega menu.
+    ///
+    /// Applies to each item in the mega menu layout (e.g., a flex card).
+    #[props(default)]
+    pub mega_menu_card_class: &'static str,
+
+    /// CSS class for the "More" button in the navbar.
+    ///
+    /// This button typically toggles more navigation options or links.
+    #[props(default)]
+    pub more_button_class: &'static str,
+}
+
+/// Tracks the viewport against a mobile breakpoint and a set of named breakpoints.
+///
+/// The returned signal is `None` outside a browser. The viewport is observed with `matchMedia`
+/// change events (falling back to `resize`), coalesced to one update per animation frame, so the
+/// signal only changes when a breakpoint or the mobile layout is crossed; `width` is the viewport
+/// width at the last crossing. Listeners are removed on unmount and re-created when either
+/// argument changes.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::core::{Breakpoint, Breakpoints};
+/// use navbar::dioxus::use_viewport;
+///
+/// #[component]
+/// fn Sidebar() -> Element {
+///     let viewport = use_viewport(1024.0, Breakpoints::default());
+///     let wide = viewport().is_some_and(|v| v.breakpoint >= Breakpoint::Lg);
+///     rsx! { if wide { aside { "Filters" } } }
+/// }
+/// ```
+pub fn use_viewport(
+    mobile_breakpoint: f64,
+    breakpoints: Breakpoints,
+) -> ReadOnlySignal<Option<Viewport>> {
+    let mut viewport =
+        use_signal(|| viewport_width().map(|w| breakpoints.viewport(w, mobile_breakpoint)));
+    let observer = use_hook(|| Rc::new(RefCell::new(None::<ViewportObserver>)));
+
+    use_effect(use_reactive(
+        (&mobile_breakpoint, &breakpoints),
+        move |(mobile_breakpoint, breakpoints)| {
+            let mut tracker = BreakpointTracker::default();
+            let mut report = move |width: f64| {
+                let next = breakpoints.viewport(width, mobile_breakpoint);
+                if let Some(next) = tracker.observe(next) {
+                    if *viewport.peek() != Some(next) {
+                        viewport.set(Some(next));
+                    }
+                }
+            };
+            if let Some(width) = viewport_width() {
+                report(width);
+            }
+
+            let next = ViewportObserver::new(&breakpoints.boundaries(mobile_breakpoint), report);
+            observer.borrow_mut().replace(next);
+        },
+    ));
+
+    viewport.into()
+}
+
+/// Tracks the page scroll for a [`ScrollBehavior`].
+///
+/// The signal holds whether the navbar should be hidden ([`ScrollBehavior::HideOnScroll`]) or
+/// scrolled ([`ScrollBehavior::Shrink`]); it stays at its default for untracked behaviors.
+/// Scrolling is observed with a passive listener, coalesced to one update per animation frame,
+/// and the signal only changes with the state. The listener is removed on unmount and re-created
+/// when `behavior` changes.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::core::ScrollBehavior;
+/// use navbar::dioxus::use_scroll_state;
+///
+/// #[component]
+/// fn BackToTop() -> Element {
+///     let scroll = use_scroll_state(ScrollBehavior::Shrink(400.0));
+///     rsx! { if scroll().scrolled { a { href: "#top", "Back to top" } } }
+/// }
+/// ```
+pub fn use_scroll_state(behavior: ScrollBehavior) -> ReadOnlySignal<ScrollState> {
+    let mut state = use_signal(ScrollState::default);
+    let observer = use_hook(|| Rc::new(RefCell::new(None::<ScrollObserver>)));
+
+    use_effect(use_reactive(&behavior, move |behavior| {
+        let mut report = move |next: ScrollState| {
+            if *state.peek() != next {
+                state.set(next);
+            }
+        };
+        report(ScrollState::default());
+        let next = behavior.is_tracked().then(|| {
+            let mut tracker = ScrollTracker::new(behavior);
+            ScrollObserver::new(move |y| report(tracker.observe(y)))
+        });
+        *observer.borrow_mut() = next;
+    }));
+
+    state.into()
+}
+
+/// Drives the enter and exit transitions of an element shown while `show` is `true`.
+///
+/// Returns the element's [`TransitionPhase`], to render it while [`TransitionPhase::is_mounted`]
+/// with [`Transition::style`]/[`Transition::class`] of that phase, and a handler to call on its
+/// `transitionend`. Exiting elements unmount on `transitionend`, or after
+/// [`Transition::timeout_ms`] at the latest. Without a transition, or when the user prefers
+/// reduced motion, the element mounts and unmounts with `show`.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::core::Transition;
+/// use navbar::dioxus::use_transition;
+///
+/// #[component]
+/// fn Toast(show: bool) -> Element {
+///     let fade = Transition::fade(200);
+///     let (phase, on_end) = use_transition(show, Some(fade.clone()));
+///     rsx! {
+///         if phase.is_mounted() {
+///             div { style: fade.style(phase), ontransitionend: move |_| on_end.call(()), "Saved" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_transition(
+    show: bool,
+    transition: Option<Transition>,
+) -> (TransitionPhase, EventHandler<()>) {
+    let mut phase = use_signal(|| TransitionPhase::initial(show));
+    let timer = use_hook(|| Rc::new(RefCell::new(None::<PhaseTimer>)));
+    let animate = transition.is_some() && !prefers_reduced_motion();
+    let timeout_ms = transition.map_or(0, |transition| transition.timeout_ms);
+
+    use_effect(use_reactive((&show, &animate), move |(show, animate)| {
+        let current = *phase.peek();
+        let next = if show {
+            current.show(animate)
+        } else {
+            current.hide(animate)
+        };
+        if next != current {
+            phase.set(next);
+        }
+    }));
+    use_effect(use_reactive(&timeout_ms, move |timeout_ms| {
+        let current = phase();
+        let next = PhaseTimer::new(current, timeout_ms, move || phase.set(current.advance()));
+        *timer.borrow_mut() = Some(next);
+    }));
+
+    let on_end = EventHandler::new(move |_| {
+        if *phase.peek() == TransitionPhase::Exit {
+            phase.set(TransitionPhase::Exited);
+        }
+    });
+    if animate {
+        (phase(), on_end)
+    } else {
+        (TransitionPhase::initial(show), on_end)
+    }
+}
+
+/// Calls `on_outside` whenever a click lands outside the element with the given `id`.
+///
+/// This is the dismissal mechanism behind every navbar popup. The document listener is only
+/// attached while `enabled` is `true`, and is removed when it turns `false` or the component
+/// unmounts.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::dioxus::use_click_outside;
+///
+/// #[component]
+/// fn Popover() -> Element {
+///     let mut open = use_signal(|| true);
+///     use_click_outside("popover".to_string(), open(), EventHandler::new(move |_| open.set(false)));
+///     rsx! { if open() { div { id: "popover", "Hello" } } }
+/// }
+/// ```
+pub fn use_click_outside(id: String, enabled: bool, on_outside: EventHandler<()>) {
+    let listener = use_hook(|| Rc::new(RefCell::new(None::<OutsideClickListener>)));
+
+    use_effect(use_reactive((&id, &enabled), move |(id, enabled)| {
+        let next = enabled.then(|| OutsideClickListener::new(id, move || on_outside.call(())));
+        *listener.borrow_mut() = next;
+    }));
+}
+
+/// Returns the current `window.location.pathname`, updated on history navigation (`popstate`).
+///
+/// With the `dioxus-router` feature, inside a router this is the router's current route, so it
+/// also follows client-side navigation. The signal holds an empty path outside a browser. Other
+/// routers that navigate with `pushState` do not fire `popstate`; pass their current path
+/// through the `current_path` props instead.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::dioxus::use_location_path;
+///
+/// #[component]
+/// fn Breadcrumb() -> Element {
+///     let path = use_location_path();
+///     rsx! { span { "{path}" } }
+/// }
+/// ```
+pub fn use_location_path() -> ReadOnlySignal<String> {
+    let mut path = use_signal(|| location_path().unwrap_or_default());
+    use_hook(|| {
+        Rc::new(watch_location(move |next| {
+            if *path.peek() != next {
+                path.set(next);
+            }
+        }))
+    });
+    #[cfg(feature = "dioxus-router")]
+    return router::use_router_path(path).into();
+    #[cfg(not(feature = "dioxus-router"))]
+    path.into()
+}
+
+/// Returns the suggestions of `provider` for `query`.
+///
+/// A synchronous provider answers right away. An asynchronous one is called once `query` has
+/// not changed for `debounce_ms`, reporting [`Suggestions::Loading`] meanwhile; the request for
+/// an outdated query is cancelled. An empty query (or no provider) gives [`Suggestions::Idle`].
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::core::{Suggestion, SuggestionProvider};
+/// use navbar::dioxus::use_suggestions;
+///
+/// #[component]
+/// fn Lookup() -> Element {
+///     let provider = use_hook(|| {
+///         SuggestionProvider::new(|query| vec![Suggestion::new(0, query.to_uppercase())])
+///     });
+///     let suggestions = use_suggestions(Some(provider), "docs".to_string(), 300);
+///     rsx! { span { "{suggestions.read().items().len()}" } }
+/// }
+/// ```
+pub fn use_suggestions(
+    provider: Option<SuggestionProvider>,
+    query: String,
+    debounce_ms: u32,
+) -> ReadOnlySignal<Suggestions> {
+    let mut suggestions = use_signal(Suggestions::default);
+    let pending = use_hook(|| Rc::new(Cell::new(None::<Task>)));
+
+    use_effect(use_reactive(
+        (&provider, &query, &debounce_ms),
+        move |(provider, query, debounce_ms)| {
+            if let Some(task) = pending.take() {
+                task.cancel();
+            }
+            let next = match provider {
+                Some(_) if query.trim().is_empty() => Suggestions::Idle,
+                None => Suggestions::Idle,
+                Some(SuggestionProvider::Sync(provider)) => Suggestions::Ready(provider(&query)),
+                Some(SuggestionProvider::Async(provider)) => {
+                    pending.set(Some(spawn(async move {
+                        TimeoutFuture::new(debounce_ms).await;
+                        let items = provider(query).await;
+                        suggestions.set(Suggestions::Ready(items));
+                    })));
+                    Suggestions::Loading
+                }
+            };
+            if *suggestions.peek() != next {
+                suggestions.set(next);
+            }
+        },
+    ));
+
+    suggestions.into()
+}
+
+/// Returns an element id that is unique to this component instance.
+///
+/// Under a [`Navbar`], the id comes from its `id_prefix` and stays the same between a server
+/// render and hydration.
+fn use_element_id(name: &'static str) -> String {
+    use_hook(|| element_id(try_consume_context::<IdScope>().as_ref(), name))
+}
+
+/// Options of [`use_menu_button`].
+struct MenuButtonOptions {
+    /// Id of the menu; the button's id is `{id}-button`.
+    id: String,
+    /// Number of items in the menu.
+    len: usize,
+    is_open: bool,
+    /// Whether the open menu is mounted and can take focus.
+    shown: bool,
+    initial_focus: Option<MenuKey>,
+    on_open: EventHandler<()>,
+    on_close: EventHandler<()>,
+}
+
+/// Keyboard handling of a WAI-ARIA menu button and its vertical menu, returned by
+/// [`use_menu_button`].
+struct MenuButton {
+    id: String,
+    button_id: String,
+    len: usize,
+    is_open: bool,
+    /// Item to focus once the menu opens after a key press on the button.
+    pending_focus: Rc<Cell<Option<MenuKey>>>,
+    on_open: EventHandler<()>,
+    on_close: EventHandler<()>,
+}
+
+impl MenuButton {
+    /// Returns the key handler of the button: arrows open the menu onto an item, `Escape` closes
+    /// it.
+    fn on_button_keydown(&self) -> impl FnMut(KeyboardEvent) + use<> {
+        let id = self.id.clone();
+        let len = self.len;
+        let is_open = self.is_open;
+        let pending_focus = self.pending_focus.clone();
+        let on_open = self.on_open;
+        let on_close = self.on_close;
+        move |e: KeyboardEvent| {
+            let key = e.key().to_string();
+            match MenuKey::from_trigger_key(&key) {
+                Some(MenuKey::Close) => on_close.call(()),
+                Some(focus) => {
+                    // Enter and Space also click the button, which toggles the menu.
+                    let clicks = matches!(key.as_str(), "Enter" | " ");
+                    if !clicks {
+                        e.prevent_default();
+                    }
+                    if !is_open {
+                        pending_focus.set(Some(focus));
+                        if !clicks {
+                            on_open.call(());
+                        }
+                    } else if !clicks {
+                        focus_menu_item(&id, focus, None, len);
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+
+    /// Returns the key handler of the item at `index`, which moves focus between the items and
+    /// closes the menu on `Escape` (returning focus to the button) or `Tab`.
+    fn on_item_keydown(&self, index: usize) -> impl FnMut(KeyboardEvent) + use<> {
+        let id = self.id.clone();
+        let button_id = self.button_id.clone();
+        let len = self.len;
+        let on_close = self.on_close;
+        move |e: KeyboardEvent| match MenuKey::from_key(&e.key().to_string(), Orientation::Vertical)
+        {
+            Some(MenuKey::Close) => {
+                e.prevent_default();
+                on_close.call(());
+                focus_element(&button_id);
+            }
+            Some(MenuKey::Leave) => on_close.call(()),
+            Some(key) => {
+                let moved = focus_menu_item(&id, key, Some(index), len);
+                if moved {
+                    e.prevent_default();
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+/// Wires the keyboard of a menu button to the items of its menu, as used by [`ProfileMenu`] and
+/// [`NotificationMenu`].
+///
+/// Once the menu is shown, the item picked by the key that opened it, or else `initial_focus`,
+/// is focused.
+fn use_menu_button(options: MenuButtonOptions) -> MenuButton {
+    let MenuButtonOptions {
+        id,
+        len,
+        is_open,
+        shown,
+        initial_focus,
+        on_open,
+        on_close,
+    } = options;
+    let pending_focus = use_hook(|| Rc::new(Cell::new(None::<MenuKey>)));
+    {
+        let pending_focus = pending_focus.clone();
+        let id = id.clone();
+        use_effect(use_reactive(
+            (&initial_focus, &shown, &len),
+            move |(focus, shown, len)| {
+                if !shown {
+                    return;
+                }
+                if let Some(key) = pending_focus.take().or(focus) {
+                    focus_menu_item(&id, key, None, len);
+                }
+            },
+        ));
+    }
 
-    /// Style applied to the search input field.
-    #[props(default = "padding: 0.5rem; font-size: 1rem; border: 1px solid #ccc;")]
-    pub search_input_style: &'static str,
+    MenuButton {
+        button_id: format!("{id}-button"),
+        id,
+        len,
+        is_open,
+        pending_focus,
+        on_open,
+        on_close,
+    }
+}
 
-    /// Style for the CTA button wrapper.
-    #[props(default = "margin-left: 1rem; white-space: nowrap;")]
-    pub button_style: &'static str,
+/// Returns `base` followed by the style or class of a transition phase, if any.
+fn with_phase(base: &str, extra: &str) -> String {
+    with_active(base, extra, !extra.is_empty())
+}
 
-    /// Style for the `<a>` link inside the CTA button.
-    #[props(
-        default = "text-decoration: none; color: white; background: #007bff; padding: 0.5rem 1rem; border-radius: 4px;"
-    )]
-    pub button_link_style: &'static str,
+/// Options of [`use_navbar`].
+///
+/// The fields mirror the matching [`NavbarProps`].
+#[derive(Clone, PartialEq)]
+pub struct NavbarOptions {
+    /// Viewport width, in pixels, at or below which the mobile layout is used.
+    pub mobile_breakpoint: f64,
+    /// Named breakpoint widths (`sm`, `md`, `lg`, `xl`) reported through `on_breakpoint_change`.
+    pub breakpoints: Breakpoints,
+    /// Called on mount and whenever the viewport crosses a named breakpoint or the mobile breakpoint.
+    pub on_breakpoint_change: EventHandler<Viewport>,
+    /// Layout of the first render, before the viewport is measured.
+    pub initial_layout: InitialLayout,
+    /// How the navbar reacts to page scroll, reported through [`UseNavbar::scroll`].
+    pub scroll_behavior: ScrollBehavior,
+    /// Path whose changes close every popup. Defaults to [`use_location_path`].
+    pub current_path: Option<String>,
+    /// Popups that close on a click outside them, i.e. outside the element carrying their
+    /// [`PopupProps::id`]. All of them by default.
+    pub close_on_outside_click: Vec<Popup>,
+    /// Prefix of the popup ids, e.g. `navbar` for `navbar-mobile-menu`. Give each navbar on a
+    /// page its own prefix.
+    pub id_prefix: String,
+}
 
-    /// Style for the mega menu dropdown.
-    #[props(
-        default = "position: absolute; top: 100%; left: 0; background: white; padding: 0; margin-top: 0.5rem; z-index: 1000;"
-    )]
-    pub mega_menu_style: &'static str,
+impl Default for NavbarOptions {
+    fn default() -> Self {
+        Self {
+            mobile_breakpoint: MOBILE_BREAKPOINT,
+            breakpoints: Breakpoints::default(),
+            on_breakpoint_change: EventHandler::default(),
+            initial_layout: InitialLayout::default(),
+            scroll_behavior: ScrollBehavior::default(),
+            current_path: None,
+            close_on_outside_click: Popup::ALL.to_vec(),
+            id_prefix: "navbar".to_string(),
+        }
+    }
+}
 
-    /// Style for each card/item in the mega menu.
-    #[props(
-        default = "background: white; display: flex; gap: 2rem; padding: 1rem; box-shadow: 0 2px 8px rgba(0,0,0,0.1);"
-    )]
-    pub mega_menu_card_style: &'static str,
+/// Attributes and handlers of the button that opens a popup, from [`UseNavbar::trigger_props`].
+#[derive(Clone, PartialEq)]
+pub struct TriggerProps {
+    /// Id of the button, which gets focus back when the popup is closed with `Escape`.
+    pub id: String,
+    /// `aria-controls`: the id of the popup.
+    pub aria_controls: String,
+    /// `aria-expanded`: `"true"` while the popup is open.
+    pub aria_expanded: &'static str,
+    /// `aria-haspopup`: `"menu"` for every popup but the mobile menu.
+    pub aria_haspopup: Option<&'static str>,
+    /// Toggles the popup.
+    pub onclick: EventHandler<MouseEvent>,
+    /// Opens the popup with `Enter`, `Space` or the arrow keys, and closes it with `Escape`.
+    pub onkeydown: EventHandler<KeyboardEvent>,
+}
 
-    /// Text for the "More" button shown in the navbar.
-    #[props(default = "More")]
-    pub more_button_text: &'static str,
+/// Attributes and handlers of a popup, from [`UseNavbar::popup_props`].
+#[derive(Clone, PartialEq)]
+pub struct PopupProps {
+    /// Id of the popup element, which outside clicks are checked against.
+    pub id: String,
+    /// Whether the popup is open.
+    pub is_open: bool,
+    /// Item to focus once the popup opens, after a key press on its trigger.
+    pub initial_focus: Option<MenuKey>,
+    /// Closes the popup with `Escape` and returns focus to its trigger.
+    pub onkeydown: EventHandler<KeyboardEvent>,
+    /// Closes the popup.
+    pub on_close: EventHandler<()>,
+}
 
-    /// Style applied to the "More" button element.
-    #[props(
-        default = "background: transparent; border: none; cursor: pointer; font-weight: bold;"
-    )]
-    pub more_button_style: &'static str,
+/// State and prop-getters of a navbar, returned by [`use_navbar`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseNavbar {
+    state: Signal<NavbarState>,
+    viewport: ReadOnlySignal<Option<Viewport>>,
+    scroll: ReadOnlySignal<ScrollState>,
+    id_prefix: CopyValue<String>,
+    focus: Signal<Option<(Popup, MenuKey)>>,
+}
 
-    // State and image props
-    /// Optional external state for the search input value.
-    #[props(default)]
-    pub search_state: Option<Signal<String>>,
+impl UseNavbar {
+    /// Returns the open/close state.
+    pub fn state(&self) -> NavbarState {
+        *self.state.read()
+    }
 
-    /// Optional profile image URL for the avatar in the navbar.
-    #[props(default)]
-    pub profile_image_url: Option<String>,
-    /// CSS class for the outer `<nav>` element of the navbar.
-    ///
-    /// Allows customization of the outermost wrapper of the navbar. Defaults to an empty string.
-    #[props(default)]
-    pub navbar_class: &'static str,
+    /// Returns the measured viewport, or `None` before it is measured or outside a browser.
+    pub fn viewport(&self) -> Option<Viewport> {
+        *self.viewport.read()
+    }
 
-    /// CSS class for the inner wrapper of the navbar content.
-    ///
-    /// Used to apply styles to the inner layout container that holds all navbar elements.
-    #[props(default)]
-    pub inner_class: &'static str,
+    /// Returns the scroll state. The navbar is never `hidden` while a popup is open.
+    pub fn scroll(&self) -> ScrollState {
+        let scroll = *self.scroll.read();
+        ScrollState {
+            hidden: scroll.hidden && !self.state.read().any_open(),
+            ..scroll
+        }
+    }
 
-    /// CSS class for the container that constrains navbar width.
-    ///
-    /// Useful for centering and limiting the maximum width of navbar contents.
-    #[props(default)]
-    pub container_class: &'static str,
+    /// Returns `true` if the mobile layout applies.
+    pub fn is_mobile(&self) -> bool {
+        self.state.read().is_mobile
+    }
 
-    /// CSS class for the logo image element.
-    ///
-    /// Use this to style the logo, such as sizing or spacing.
-    #[props(default)]
-    pub logo_class: &'static str,
+    /// Returns `true` if the given popup is open.
+    pub fn is_open(&self, popup: Popup) -> bool {
+        self.state.read().is_open(popup)
+    }
 
-    /// CSS class for the menu toggle button (commonly the hamburger icon).
-    ///
-    /// Used in responsive design for toggling the navigation menu on smaller screens.
-    #[props(default)]
-    pub menu_toggle_class: &'static str,
+    /// Applies an action to the state.
+    pub fn dispatch(&self, action: NavbarAction) {
+        let mut state = self.state;
+        let next = state.peek().apply(action);
+        if next != *state.peek() {
+            state.set(next);
+        }
+    }
 
-    /// CSS class for individual lines inside the hamburger toggle button.
-    ///
-    /// Typically used to style each bar in the toggle icon.
-    #[props(default)]
-    pub line_class: &'static str,
+    /// Returns a handler that opens a popup, e.g. on hover.
+    pub fn open<T: 'static>(&self, popup: Popup) -> EventHandler<T> {
+        let navbar = *self;
+        let mut focus = self.focus;
+        EventHandler::new(move |_| {
+            focus.set(None);
+            navbar.dispatch(NavbarAction::Open(popup));
+        })
+    }
 
-    /// CSS class for the navigation list element.
-    ///
-    /// Styles the `<ul>` or equivalent container that holds menu items.
-    #[props(default)]
-    pub nav_class: &'static str,
+    /// Returns a handler that closes a popup.
+    pub fn close<T: 'static>(&self, popup: Popup) -> EventHandler<T> {
+        let navbar = *self;
+        EventHandler::new(move |_| navbar.dispatch(NavbarAction::Close(popup)))
+    }
 
-    /// CSS class for individual menu item links.
-    ///
-    /// Use this to style each navigation link (e.g., padding, hover effects).
-    #[props(default)]
-    pub menu_item_class: &'static str,
+    /// Returns a handler that opens a closed popup or closes an open one.
+    pub fn toggle<T: 'static>(&self, popup: Popup) -> EventHandler<T> {
+        let navbar = *self;
+        let mut focus = self.focus;
+        EventHandler::new(move |_| {
+            focus.set(None);
+            navbar.dispatch(NavbarAction::Toggle(popup));
+        })
+    }
 
-    /// CSS class for the dropdown menu container.
-    ///
-    /// This class applies to the container holding dropdown items below a menu.
-    #[props(default)]
-    pub dropdown_class: &'static str,
+    fn id(&self, popup: Popup) -> String {
+        popup_id(&self.id_prefix.read(), popup)
+    }
 
-    /// CSS class for individual dropdown menu items.
-    ///
-    /// Used to style each item within a dropdown menu.
-    #[props(default)]
-    pub dropdown_item_class: &'static str,
+    fn trigger_id(&self, popup: Popup) -> String {
+        format!("{}-button", self.id(popup))
+    }
 
-    /// CSS class for the search input element.
-    ///
-    /// Applies styles to the optional search bar input field.
-    #[props(default)]
-    pub search_input_class: &'static str,
+    /// Returns the attributes and handlers of the button that opens a popup.
+    pub fn trigger_props(&self, popup: Popup) -> TriggerProps {
+        let navbar = *self;
+        let mut focus = self.focus;
+        let onkeydown = EventHandler::new(move |e: KeyboardEvent| {
+            let key = e.key().to_string();
+            match MenuKey::from_trigger_key(&key) {
+                Some(MenuKey::Close) => navbar.dispatch(NavbarAction::Close(popup)),
+                // Like a click, `Enter` and `Space` close an open popup.
+                Some(_) if navbar.is_open(popup) && matches!(key.as_str(), "Enter" | " ") => {
+                    e.prevent_default();
+                    navbar.dispatch(NavbarAction::Close(popup));
+                }
+                Some(focus_key) => {
+                    e.prevent_default();
+                    focus.set(Some((popup, focus_key)));
+                    navbar.dispatch(NavbarAction::Open(popup));
+                }
+                None => {}
+            }
+        });
+        TriggerProps {
+            id: self.trigger_id(popup),
+            aria_controls: self.id(popup),
+            aria_expanded: if self.is_open(popup) { "true" } else { "false" },
+            aria_haspopup: (popup != Popup::MobileMenu).then_some("menu"),
+            onclick: self.toggle(popup),
+            onkeydown,
+        }
+    }
 
-    /// CSS class for the container wrapping the button.
-    ///
-    /// Use this to style the wrapper that holds the sign-up/login button.
-    #[props(default)]
-    pub button_class: &'static str,
+    /// Returns the attributes and handlers of a popup.
+    pub fn popup_props(&self, popup: Popup) -> PopupProps {
+        let navbar = *self;
+        let trigger_id = self.trigger_id(popup);
+        let onkeydown = EventHandler::new(move |e: KeyboardEvent| {
+            if e.key() == Key::Escape {
+                navbar.dispatch(NavbarAction::Close(popup));
+                focus_element(&trigger_id);
+            }
+        });
+        PopupProps {
+            id: self.id(popup),
+            is_open: self.is_open(popup),
+            initial_focus: self
+                .focus
+                .read()
+                .and_then(|(focused, key)| (focused == popup).then_some(key)),
+            onkeydown,
+            on_close: self.close(popup),
+        }
+    }
+}
 
-    /// CSS class for the sign-up/login button link.
-    ///
-    /// Applies styles directly to the clickable `<a>` or button element.
-    #[props(default)]
-    pub button_link_class: &'static str,
+/// Runs the behavior of a navbar without its markup.
+///
+/// The hook tracks the viewport against the breakpoints, holds the [`NavbarState`] of the
+/// popups, follows the page scroll, closes every popup when the path changes and, for those
+/// listed in `close_on_outside_click`, on a click outside the open one. The returned handle
+/// reads the state and hands out the attributes and handlers of each popup and of the button
+/// that opens it: [`UseNavbar::trigger_props`] and [`UseNavbar::popup_props`]. [`Navbar`] is
+/// the default rendering built on this hook.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::core::Popup;
+/// use navbar::dioxus::{NavbarOptions, use_navbar};
+///
+/// #[component]
+/// fn Header() -> Element {
+///     let navbar = use_navbar(NavbarOptions::default());
+///     let trigger = navbar.trigger_props(Popup::MobileMenu);
+///     let menu = navbar.popup_props(Popup::MobileMenu);
+///     rsx! {
+///         header {
+///             button {
+///                 id: trigger.id,
+///                 aria_controls: trigger.aria_controls,
+///                 aria_expanded: trigger.aria_expanded,
+///                 onclick: move |e| trigger.onclick.call(e),
+///                 onkeydown: move |e| trigger.onkeydown.call(e),
+///                 "Menu"
+///             }
+///             if menu.is_open {
+///                 ul {
+///                     id: menu.id,
+///                     onkeydown: move |e| menu.onkeydown.call(e),
+///                     li { a { href: "/", "Home" } }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_navbar(options: NavbarOptions) -> UseNavbar {
+    let viewport = use_viewport(options.mobile_breakpoint, options.breakpoints);
+    let state = use_signal(|| NavbarState::new(options.initial_layout.is_mobile(*viewport.peek())));
+    let navbar = UseNavbar {
+        state,
+        viewport,
+        scroll: use_scroll_state(options.scroll_behavior),
+        id_prefix: use_hook(|| CopyValue::new(options.id_prefix.clone())),
+        focus: use_signal(|| None),
+    };
 
-    /// CSS class for the mega menu container.
-    ///
-    /// Used when the mega menu is shown—applies to the entire dropdown card area.
-    #[props(default)]
-    pub mega_menu_class: &'static str,
+    let on_breakpoint_change = options.on_breakpoint_change;
+    use_effect(move || {
+        if let Some(viewport) = viewport() {
+            navbar.dispatch(NavbarAction::SetMobile(viewport.is_mobile));
+            on_breakpoint_change.call(viewport);
+        }
+    });
 
-    /// CSS class for the cards within the mega menu.
-    ///
-    /// Applies to each item in the mega menu layout (e.g., a flex card).
-    #[props(default)]
-    pub mega_menu_card_class: &'static str,
+    // Client-side navigation keeps the navbar mounted; close whatever led to the new page.
+    let location = use_location_path();
+    let path = options
+        .current_path
+        .clone()
+        .unwrap_or_else(|| location.cloned());
+    use_effect(use_reactive((&path,), move |_| {
+        navbar.dispatch(NavbarAction::CloseAll)
+    }));
 
-    /// CSS class for the "More" button in the navbar.
-    ///
-    /// This button typically toggles more navigation options or links.
-    #[props(default)]
-    pub more_button_class: &'static str,
+    let outside =
+        |popup: Popup| options.close_on_outside_click.contains(&popup) && navbar.is_open(popup);
+    use_click_outside(
+        navbar.id(Popup::MobileMenu),
+        outside(Popup::MobileMenu),
+        navbar.close(Popup::MobileMenu),
+    );
+    use_click_outside(
+        navbar.id(Popup::Dropdown),
+        outside(Popup::Dropdown),
+        navbar.close(Popup::Dropdown),
+    );
+    use_click_outside(
+        navbar.id(Popup::MegaMenu),
+        outside(Popup::MegaMenu),
+        navbar.close(Popup::MegaMenu),
+    );
+    use_click_outside(
+        navbar.id(Popup::Notifications),
+        outside(Popup::Notifications),
+        navbar.close(Popup::Notifications),
+    );
+    navbar
+}
+
+/// Returns `base` with `extra` appended when `active` is `true`.
+fn with_active(base: &str, extra: &str, active: bool) -> String {
+    if active {
+        format!("{base} {extra}")
+    } else {
+        base.to_string()
+    }
 }
 
 /// Navbar Component
@@ -660,24 +3648,85 @@ pub struct NavbarProps {
 ///
 /// # Features
 /// - **Responsive Behavior**:
-///   Adapts based on screen width using a signal driven by `window.innerWidth()` with a resize listener.
-///   - Mobile breakpoint: `<= 768px`
+///   Adapts based on screen width through [`use_viewport`], which uses `matchMedia` change events
+///   throttled to animation frames and removes its listeners on unmount.
+///   - Mobile breakpoint: `<= mobile_breakpoint` (768px by default)
+///   - `on_breakpoint_change` reports named breakpoint (`sm`/`md`/`lg`/`xl`) and layout changes
+///   - `initial_layout` sets the layout rendered on the server and on the hydrating render,
+///     before the viewport is measured, and element ids start with `id_prefix`, so both renders
+///     produce the same markup
+///   - `css_responsive` renders both layouts and switches between them with media queries, with
+///     a `<details>` disclosure for the mobile menu until the app hydrates
+///
+/// - **Scroll Behavior**:
+///   `scroll_behavior` keeps the navbar static (default) or sticky, hides it while scrolling down
+///   and reveals it on the way up, or adds `scrolled_style`/`scrolled_class` past a threshold.
+///   Scrolling is tracked through [`use_scroll_state`], with a passive listener throttled to
+///   animation frames that is removed on unmount
+///
+/// - **Theming**:
+///   `theme` sets the navbar colors as CSS custom properties on the `<nav>`, which every default
+///   style refers to; see [`NavbarTheme`]. `show_theme_toggle` adds a light/dark/system
+///   [`ThemeToggle`] that switches the navbar to `dark_theme`, marks `<html>` with `data-theme`
+///   and remembers the choice
+///
+/// - **Transitions**:
+///   `mobile_menu_transition`, `dropdown_transition`, `mega_menu_transition` and
+///   `notifications_transition` animate the menus in and out, e.g. with [`Transition::fade`] or
+///   [`Transition::slide_down`]. Closed menus stay mounted until their exit transition ends;
+///   motion is skipped when the user prefers reduced motion. See [`use_transition`]
 ///
 /// - **Dropdown & Mega Menu**:
 ///   - Profile dropdown toggled on click
-///   - Mega menu shown on hover with mouse enter/leave detection
+///   - Mega menu shown on hover with mouse enter/leave detection, or from the keyboard on "More"
+///
+/// - **Keyboard Navigation** (WAI-ARIA menubar pattern):
+///   - Arrow keys, `Home` and `End` move between top-level items, with a roving `tabindex`
+///   - `Enter`, `Space` or `ArrowDown` on "More" or the profile button open the menu and focus its
+///     first item (`ArrowUp` focuses the last); arrow keys then move within the menu
+///   - `Escape` closes the menu and returns focus to its trigger; `Tab` closes it
+///
+/// - **Active Route**:
+///   The item whose `link` matches the current path (`active_match`: exact, prefix or custom) gets
+///   `aria-current="page"` and `active_style`/`active_class`. The path defaults to
+///   `window.location.pathname`; pass `current_path` when using a router.
 ///
 /// - **Search Support**:
-///   A customizable search input field with shared state handling
+///   A customizable search input field with shared state handling, used by both the desktop and
+///   mobile layouts. `on_search_submit` fires on `Enter` (or the optional search button), and
+///   `on_search_change` fires once typing pauses for `search_debounce_ms`. With a `suggestions`
+///   provider (sync or async), a listbox of suggestions opens under the input (a WAI-ARIA
+///   combobox), with the matched text highlighted and loading/empty states. With
+///   `search_items`, the navbar suggests its own items instead, fuzzy-matched and ranked, and
+///   choosing one navigates to its link
+///
+/// - **Command Palette**:
+///   With `show_command_palette`, `Ctrl+K` / `Cmd+K` opens a [`CommandPalette`] listing every
+///   menu, dropdown and mega menu item plus the app's `commands`, fuzzy-filtered and grouped by
+///   section, with recently used commands first
 ///
 /// - **Mobile Toggle Menu**:
-///   Displays a slide-in or dropdown menu when the hamburger is clicked on smaller screens
+///   Displays a dropdown menu when the hamburger is clicked on smaller screens, or with
+///   `mobile_variant` a left or right drawer or a full-screen sheet over a backdrop, with focus
+///   trapped inside and the page scroll locked while it is open. The hamburger is a button with
+///   `aria-expanded` and `aria-controls` bound to the menu; `menu_toggle_animation` morphs its
+///   icon into an X or an arrow
 ///
 /// - **CTA Button**:
 ///   Optional call-to-action button next to the menu
 ///
+/// - **Notifications**:
+///   With `show_notifications`, a bell with an unread badge (capped at `"99+"`) opens a
+///   [`NotificationMenu`] listing `notifications`, with "Mark all read" and per-item callbacks.
+///   It also shows as a section of the mobile menu
+///
+/// - **Custom Layout**:
+///   `start`, `center` and `end` slots take arbitrary markup around the built-in parts, and
+///   `mobile_extra` goes at the bottom of the mobile menu. `parts` orders the built-in parts
+///   ([`NavbarPart`]); a part left out is hidden
+///
 /// - **Event Management**:
-///   Global document click listener closes open mobile menu automatically
+///   Clicking outside the mobile menu, profile dropdown or mega menu closes it (see [`use_click_outside`])
 ///
 /// # Examples
 ///
@@ -691,7 +3740,7 @@ pub struct NavbarProps {
 ///     rsx! {
 ///         Navbar {
 ///             menus: vec![
-///                 Menu { id: 1, link: "/", name: "Home", icon_start: None, icon_end: None },
+///                 Menu::new(1, "Home", "/"),
 ///             ],
 ///         }
 ///     }
@@ -712,11 +3761,11 @@ pub struct NavbarProps {
 ///             show_profile_menu: true,
 ///             profile_button_text: "User",
 ///             dropdown_items: vec![
-///                 DropdownItem { id: 1, link: "/account", label: "Account", icon: None },
-///                 DropdownItem { id: 2, link: "/logout", label: "Logout", icon: None },
+///                 DropdownItem::new(1, "Account", "/account"),
+///                 DropdownItem::new(2, "Logout", "/logout"),
 ///             ],
 ///             menus: vec![
-///                 Menu { id: 1, link: "/", name: "Dashboard", icon_start: None, icon_end: None },
+///                 Menu::new(1, "Dashboard", "/"),
 ///             ],
 ///         }
 ///     }
@@ -735,18 +3784,58 @@ pub struct NavbarProps {
 ///             show_mega_menu: true,
 ///             more_button_text: "Explore",
 ///             mega_menu_items: vec![
-///                 MegaMenuItem { title: "Docs", description: "Technical docs", link: "/docs" },
-///                 MegaMenuItem { title: "Blog", description: "Latest updates", link: "/blog" },
+///                 MegaMenuItem::new("Docs", "Technical docs", "/docs"),
+///                 MegaMenuItem::new("Blog", "Latest updates", "/blog"),
 ///             ],
 ///             menus: vec![
-///                 Menu { id: 1, link: "/", name: "Platform", icon_start: None, icon_end: None },
+///                 Menu::new(1, "Platform", "/"),
 ///             ],
 ///         }
 ///     }
 /// }
 /// ```
 ///
+/// ## Server-Side Rendering
+/// The navbar never touches `window` while rendering, so it renders under `dioxus-ssr` (or in
+/// host-side tests). `initial_layout` picks the layout of the server markup; the hydrated navbar
+/// switches to the measured viewport once mounted. Element ids start with `id_prefix`, so every
+/// render of the same navbar produces the same markup.
+/// ```rust
+/// use dioxus::prelude::*;
+/// use navbar::core::InitialLayout;
+/// use navbar::dioxus::{Menu, Navbar};
+///
+/// #[component]
+/// fn App(user_agent: String) -> Element {
+///     rsx! {
+///         Navbar {
+///             initial_layout: InitialLayout::UserAgent(user_agent.into()),
+///             menus: vec![Menu::new(1, "Home", "/home")],
+///         }
+///     }
+/// }
+///
+/// let render = |user_agent: &str| {
+///     let mut dom = VirtualDom::new_with_props(App, AppProps { user_agent: user_agent.into() });
+///     dom.rebuild_in_place();
+///     dioxus_ssr::render(&dom)
+/// };
+///
+/// let desktop = render("Mozilla/5.0 (X11; Linux x86_64) Chrome/124.0");
+/// assert!(desktop.contains("Home"));
+/// assert!(!desktop.contains("aria-expanded=\"false\""));
+///
+/// let mobile = render("Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) Mobile/15E148");
+/// assert!(mobile.contains("aria-expanded=\"false\""));
+/// assert!(!mobile.contains("Home"));
+/// assert!(mobile.contains("aria-controls=\"navbar-mobile-menu\""));
+/// assert!(mobile.contains("id=\"navbar-mobile-menu-button\""));
+/// assert_eq!(mobile, render("Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) Mobile/15E148"));
+/// ```
+///
 /// # Notes
+/// - Open/close behavior is driven by the shared [`NavbarState`] machine held by [`use_navbar`],
+///   the same hook that headless navbars render from.
 /// - Search uses `use_signal` for reactive state, or accepts an external state via `search_state`.
 /// - The component is composed of subcomponents: `NavbarMenu`, `MegaMenu`, `ProfileMenu`, etc.
 /// - All sub-styles and class props allow fine-grained CSS control.
@@ -755,70 +3844,340 @@ pub struct NavbarProps {
 /// - [MDN `<nav>` Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/nav)
 #[component]
 pub fn Navbar(props: NavbarProps) -> Element {
-    let mut is_mobile = use_signal(|| {
-        window()
-            .and_then(|w| w.inner_width().ok())
-            .and_then(|w| w.as_f64())
-            .map(|w| w <= 768.0)
-            .unwrap_or(false)
+    let navbar = use_navbar(NavbarOptions {
+        mobile_breakpoint: props.mobile_breakpoint,
+        breakpoints: props.breakpoints,
+        on_breakpoint_change: props.on_breakpoint_change,
+        initial_layout: props.initial_layout.clone(),
+        scroll_behavior: props.scroll_behavior,
+        current_path: props.current_path.clone(),
+        close_on_outside_click: [
+            (Popup::MobileMenu, props.mobile_menu_close_on_outside_click),
+            (Popup::Dropdown, props.dropdown_close_on_outside_click),
+            (Popup::MegaMenu, props.mega_menu_close_on_outside_click),
+            (
+                Popup::Notifications,
+                props.notifications_close_on_outside_click,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(popup, closes)| closes.then_some(popup))
+        .collect(),
+        id_prefix: props.id_prefix.clone(),
     });
+    use_context_provider(|| IdScope::new(props.id_prefix.clone()));
+    let state = move || navbar.state();
+    let scroll = navbar.scroll();
+    let dispatch = move |action: NavbarAction| navbar.dispatch(action);
 
-    let mut is_mobile_menu_open = use_signal(|| false);
-    let mut is_dropdown_open = use_signal(|| false);
-    let mut is_mega_menu_open = use_signal(|| false);
+    let hamburger = navbar.trigger_props(Popup::MobileMenu);
+    let mobile_menu = navbar.popup_props(Popup::MobileMenu);
+    let profile = navbar.trigger_props(Popup::Dropdown);
+    let dropdown = navbar.popup_props(Popup::Dropdown);
+    let more = navbar.trigger_props(Popup::MegaMenu);
+    let mega_menu = navbar.popup_props(Popup::MegaMenu);
+    let bell = navbar.trigger_props(Popup::Notifications);
+    let feed = navbar.popup_props(Popup::Notifications);
+    let on_mouse_enter = navbar.open(Popup::MegaMenu);
+    let on_mouse_leave = navbar.close(Popup::MegaMenu);
+    let disclosure_id = format!("{}-disclosure", props.id_prefix);
 
-    use_effect(move || {
-        let closure = Closure::<dyn FnMut()>::wrap(Box::new(move || {
-            let width = window()
-                .and_then(|w| w.inner_width().ok())
-                .and_then(|w| w.as_f64())
-                .unwrap_or(1024.0);
-            is_mobile.set(width <= 768.0);
+    // In the CSS responsive mode, the `<details>` opens natively until the navbar is mounted;
+    // from then on, the state drives the menu, starting from whatever the user left open.
+    let mut hydrated = use_signal(|| false);
+    {
+        let disclosure_id = disclosure_id.clone();
+        use_effect(use_reactive(&props.css_responsive, move |css_responsive| {
+            if css_responsive && is_details_open(&disclosure_id) {
+                dispatch(NavbarAction::Open(Popup::MobileMenu));
+            }
+            hydrated.set(true);
         }));
+    }
+    let show_desktop = props.css_responsive || !state().is_mobile;
+    let show_mobile = props.css_responsive || state().is_mobile;
 
-        if let Some(w) = window() {
-            w.add_event_listener_with_callback("resize", closure.as_ref().unchecked_ref())
-                .unwrap();
-        }
-
-        closure.forget();
-    });
-
-    use_effect(move || {
-        let document = window().unwrap().document().unwrap();
-        let listener = EventListener::new(&document, "click", move |_| {
-            is_mobile_menu_open.set(false);
-        });
-        drop(listener)
+    let item_suggestions = use_memo(use_reactive(
+        (
+            &props.search_items,
+            &props.menus,
+            &props.dropdown_items,
+            &props.mega_menu_items,
+        ),
+        |(enabled, menus, dropdown_items, mega_menu_items)| {
+            enabled.then(|| {
+                let index = SearchIndex::new()
+                    .with_menus(&menus)
+                    .with_dropdown_items(&dropdown_items)
+                    .with_mega_menu_items(&mega_menu_items);
+                SuggestionProvider::new(move |query| index.search(query))
+            })
+        },
+    ));
+    let suggestions = props
+        .suggestions
+        .clone()
+        .or_else(|| item_suggestions.cloned());
+    let mut search_state = props.search_state.unwrap_or(use_signal(|| "".to_string()));
+    let mut dark = use_signal(|| false);
+    let on_theme_change = {
+        let on_theme_change = props.on_theme_change;
+        EventHandler::new(move |preference: ThemePreference| {
+            dark.set(preference.is_dark());
+            on_theme_change.call(preference);
+        })
+    };
+    let theme = if props.show_theme_toggle && dark() {
+        &props.dark_theme
+    } else {
+        &props.theme
+    };
+    let debounce = use_hook(|| Rc::new(Debounce::default()));
+    let on_search_change = props.on_search_change;
+    let delay = props.search_debounce_ms;
+    let on_search_input = EventHandler::new(move |value: String| {
+        search_state.set(value.clone());
+        debounce.call(delay, move || on_search_change.call(value));
     });
 
-    let toggle_mobile_menu = {
-        move |e: Event<MouseData>| {
-            e.stop_propagation();
-            is_mobile_menu_open.set(!is_mobile_menu_open());
-        }
+    let show_part = |part| props.parts.contains(&part);
+    let render_part = |part: NavbarPart| match part {
+        NavbarPart::Menu => rsx! {
+            NavbarMenu {
+                menus: props.menus.clone(),
+                menu_item_style: props.menu_item_style,
+                menu_item_class: props.menu_item_class,
+                submenu_style: props.submenu_style,
+                submenu_class: props.submenu_class,
+                current_path: props.current_path.clone(),
+                active_match: props.active_match.clone(),
+                active_style: props.active_style,
+                active_class: props.active_class,
+            }
+        },
+        NavbarPart::MegaMenu if props.show_mega_menu => rsx! {
+            div {
+                // Taps also fire pointer events; only a mouse opens the menu on hover, so a tap
+                // is left to the click that toggles it.
+                onpointerenter: move |e: PointerEvent| {
+                    if e.pointer_type() == "mouse" {
+                        on_mouse_enter.call(());
+                    }
+                },
+                onkeydown: move |e| mega_menu.onkeydown.call(e),
+                style: "position: relative; display: inline-block;",
+                button {
+                    id: more.id.clone(),
+                    r#type: "button",
+                    onclick: move |e| more.onclick.call(e),
+                    onkeydown: move |e| more.onkeydown.call(e),
+                    aria_controls: more.aria_controls.clone(),
+                    aria_haspopup: more.aria_haspopup,
+                    aria_expanded: more.aria_expanded,
+                    style: "{props.more_button_style}",
+                    class: "{props.more_button_class}",
+                    "{props.more_button_text}"
+                }
+                div {
+                    onpointerleave: move |e: PointerEvent| {
+                        if e.pointer_type() == "mouse" {
+                            on_mouse_leave.call(());
+                        }
+                    },
+                    MegaMenu {
+                        id: mega_menu.id.clone(),
+                        is_open: mega_menu.is_open,
+                        transition: props.mega_menu_transition.clone(),
+                        items: props.mega_menu_items.clone(),
+                        wrapper_style: props.mega_menu_style,
+                        wrapper_class: props.mega_menu_class,
+                        card_style: props.mega_menu_card_style,
+                        card_class: props.mega_menu_card_class,
+                        close_on_outside_click: false,
+                        on_close: mega_menu.on_close,
+                        initial_focus: mega_menu.initial_focus,
+                    }
+                }
+            }
+        },
+        NavbarPart::Search if props.show_search => rsx! {
+            NavbarSearch {
+                value: search_state(),
+                placeholder: props.search_placeholder.clone(),
+                input_style: props.search_input_style,
+                input_class: props.search_input_class,
+                on_input: on_search_input,
+                on_submit: props.on_search_submit,
+                show_button: props.show_search_button,
+                button_text: props.search_button_text.clone(),
+                button_style: props.search_button_style,
+                button_class: props.search_button_class,
+                suggestions: suggestions.clone(),
+                on_suggestion_select: props.on_suggestion_select,
+                debounce_ms: props.search_debounce_ms,
+                suggestions_loading_text: props.suggestions_loading_text.clone(),
+                suggestions_empty_text: props.suggestions_empty_text.clone(),
+                suggestions_style: props.suggestions_style,
+                suggestions_class: props.suggestions_class,
+                suggestion_style: props.suggestion_style,
+                suggestion_class: props.suggestion_class,
+                suggestion_active_style: props.suggestion_active_style,
+                suggestion_active_class: props.suggestion_active_class,
+            }
+        },
+        NavbarPart::Button if !props.button_text.is_empty() => rsx! {
+            NavbarButton {
+                href: props.button_href.clone(),
+                text: props.button_text.clone(),
+                button_style: props.button_style,
+                button_class: props.button_class,
+                link_style: props.button_link_style,
+                link_class: props.button_link_class,
+                target: props.button_target.clone(),
+            }
+        },
+        NavbarPart::Notifications if props.show_notifications => rsx! {
+            NotificationMenu {
+                items: props.notifications.clone(),
+                id: feed.id.clone(),
+                is_open: feed.is_open,
+                initial_focus: feed.initial_focus,
+                toggle: bell.onclick,
+                on_button_keydown: bell.onkeydown,
+                on_close: feed.on_close,
+                on_select: props.on_notification_select,
+                on_mark_all_read: props.on_mark_all_read,
+                badge_max: props.notifications_badge_max,
+                label: props.notifications_label.clone(),
+                mark_all_read_text: props.mark_all_read_text.clone(),
+                transition: props.notifications_transition.clone(),
+                close_on_outside_click: false,
+            }
+        },
+        NavbarPart::Profile if props.show_profile_menu => rsx! {
+            ProfileMenu {
+                profile_image_url: props.profile_image_url.clone(),
+                items: props.dropdown_items.clone(),
+                dropdown_style: props.dropdown_style,
+                dropdown_class: props.dropdown_class,
+                item_style: props.dropdown_item_style,
+                item_class: props.dropdown_item_class,
+                id: dropdown.id.clone(),
+                is_open: dropdown.is_open,
+                initial_focus: dropdown.initial_focus,
+                transition: props.dropdown_transition.clone(),
+                toggle: profile.onclick,
+                on_button_keydown: profile.onkeydown,
+                profile_text: props.profile_button_text.clone(),
+                close_on_outside_click: false,
+                on_close: dropdown.on_close,
+                current_path: props.current_path.clone(),
+                active_match: props.active_match.clone(),
+                active_style: props.active_style,
+                active_class: props.active_class,
+            }
+        },
+        NavbarPart::ThemeToggle if props.show_theme_toggle => rsx! {
+            ThemeToggle {
+                storage_key: props.theme_storage_key.clone(),
+                on_theme_change,
+            }
+        },
+        _ => rsx! {},
     };
 
-    let toggle_dropdown = {
-        move |e: Event<MouseData>| {
-            e.stop_propagation();
-            is_dropdown_open.set(!is_dropdown_open());
+    let mobile_block = rsx! {
+        if show_mobile {
+            NavbarToggle {
+                id: hamburger.id.clone(),
+                onclick: hamburger.onclick,
+                onkeydown: hamburger.onkeydown,
+                open: mobile_menu.is_open,
+                disclosure: props.css_responsive,
+                controls: hamburger.aria_controls.clone(),
+                label: props.menu_toggle_label.clone(),
+                animation: props.menu_toggle_animation,
+                toggle_style: props.menu_toggle_style,
+                toggle_class: props.menu_toggle_class,
+                line_style: props.line_style,
+                line_class: props.line_class,
+            }
+        }
+        if show_mobile {
+            MobileMenu {
+                id: mobile_menu.id.clone(),
+                is_open: mobile_menu.is_open,
+                initial_focus: mobile_menu.initial_focus,
+                onkeydown: mobile_menu.onkeydown,
+                keep_mounted: props.css_responsive && !hydrated(),
+                transition: props
+                    .mobile_menu_transition
+                    .clone()
+                    .or_else(|| props.mobile_variant.transition(200)),
+                variant: props.mobile_variant,
+                backdrop_style: props.mobile_backdrop_style,
+                backdrop_class: props.mobile_backdrop_class,
+                menus: if show_part(NavbarPart::Menu) { props.menus.clone() } else { Vec::new() },
+                menu_item_style: props.menu_item_style,
+                menu_item_class: props.menu_item_class,
+                submenu_class: props.submenu_class,
+                dropdown_items: props.dropdown_items.clone(),
+                dropdown_style: props.dropdown_style,
+                dropdown_class: props.dropdown_class,
+                dropdown_item_style: props.dropdown_item_style,
+                dropdown_item_class: props.dropdown_item_class,
+                show_profile_menu: props.show_profile_menu && show_part(NavbarPart::Profile),
+                show_search: props.show_search && show_part(NavbarPart::Search),
+                show_notifications: props.show_notifications && show_part(NavbarPart::Notifications),
+                notifications: props.notifications.clone(),
+                on_notification_select: props.on_notification_select,
+                on_mark_all_read: props.on_mark_all_read,
+                notifications_label: props.notifications_label.clone(),
+                notifications_badge_max: props.notifications_badge_max,
+                mark_all_read_text: props.mark_all_read_text.clone(),
+                search_input_style: props.search_input_style,
+                search_input_class: props.search_input_class,
+                search_placeholder: props.search_placeholder.clone(),
+                search_value: search_state(),
+                on_search_input: on_search_input,
+                on_search_submit: props.on_search_submit,
+                show_search_button: props.show_search_button,
+                search_button_text: props.search_button_text.clone(),
+                search_button_style: props.search_button_style,
+                search_button_class: props.search_button_class,
+                suggestions: suggestions.clone(),
+                on_suggestion_select: props.on_suggestion_select,
+                search_debounce_ms: props.search_debounce_ms,
+                suggestions_loading_text: props.suggestions_loading_text.clone(),
+                suggestions_empty_text: props.suggestions_empty_text.clone(),
+                suggestions_style: props.suggestions_style,
+                suggestions_class: props.suggestions_class,
+                suggestion_style: props.suggestion_style,
+                suggestion_class: props.suggestion_class,
+                suggestion_active_style: props.suggestion_active_style,
+                suggestion_active_class: props.suggestion_active_class,
+                close_on_outside_click: false,
+                on_close: mobile_menu.on_close,
+                current_path: props.current_path.clone(),
+                active_match: props.active_match.clone(),
+                active_style: props.active_style,
+                active_class: props.active_class,
+                extra: props.mobile_extra.clone(),
+            }
         }
-    };
-
-    let on_mouse_enter = { move |_| is_mega_menu_open.set(true) };
-
-    let on_mouse_leave = { move |_| is_mega_menu_open.set(false) };
-
-    let mut search_state = props.search_state.unwrap_or(use_signal(|| "".to_string()));
-    let on_input = move |e: Event<FormData>| {
-        search_state.set(e.value());
     };
 
     rsx! {
         nav {
-            style: "{props.navbar_style}",
-            class: "{props.navbar_class}",
+            style: with_phase(
+                &theme.css_variables(),
+                &scroll.nav_style(props.scroll_behavior, props.navbar_style, props.scrolled_style),
+            ),
+            class: with_active(props.navbar_class, props.scrolled_class, scroll.scrolled),
+            "data-navbar-breakpoint": props.css_responsive.then(|| props.mobile_breakpoint.to_string()),
+            if props.css_responsive {
+                style { {responsive_css(props.mobile_breakpoint)} }
+            }
             div {
                 style: "{props.container_style}",
                 class: "{props.container_class}",
@@ -829,102 +4188,49 @@ pub fn Navbar(props: NavbarProps) -> Element {
                     logo_style: props.logo_style,
                     logo_class: props.logo_class,
                 }
+                {props.start.clone()}
+                {props.center.clone()}
                 div {
                     style: "{props.inner_style}",
-                    class: "{props.inner_class}",
-                    if !is_mobile() {
-                        NavbarMenu {
-                            menus: props.menus.clone(),
-                            menu_item_style: props.menu_item_style,
-                            menu_item_class: props.menu_item_class,
-                        }
-                        if props.show_mega_menu {
-                            div {
-                                onmouseenter: on_mouse_enter,
-                                style: "position: relative; display: inline-block;",
-                                button {
-                                    style: "{props.more_button_style}",
-                                    class: "{props.more_button_class}",
-                                    "{props.more_button_text}"
-                                }
-                                if is_mega_menu_open() {
-                                    div {
-                                        onmouseleave: on_mouse_leave,
-                                        MegaMenu {
-                                            items: props.mega_menu_items.clone(),
-                                            wrapper_style: props.mega_menu_style,
-                                            wrapper_class: props.mega_menu_class,
-                                            card_style: props.mega_menu_card_style,
-                                            card_class: props.mega_menu_card_class,
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        if props.show_search {
-                            input {
-                                r#type: "text",
-                                placeholder: "{props.search_placeholder}",
-                                style: "{props.search_input_style}",
-                                class: "{props.search_input_class}",
-                                value: "{search_state()}",
-                                oninput: on_input,
-                            }
-                        }
-                        if !props.button_text.is_empty() {
-                            NavbarButton {
-                                href: props.button_href,
-                                text: props.button_text,
-                                button_style: props.button_style,
-                                button_class: props.button_class,
-                                link_style: props.button_link_style,
-                                link_class: props.button_link_class,
-                                target: props.button_target,
-                            }
-                        }
-                        if props.show_profile_menu {
-                            ProfileMenu {
-                                profile_image_url: props.profile_image_url.clone(),
-                                items: props.dropdown_items.clone(),
-                                dropdown_style: props.dropdown_style,
-                                dropdown_class: props.dropdown_class,
-                                item_style: props.dropdown_item_style,
-                                item_class: props.dropdown_item_class,
-                                is_open: is_dropdown_open(),
-                                toggle: toggle_dropdown,
-                                profile_text: props.profile_button_text,
-                            }
+                    class: with_active(props.inner_class, DESKTOP_CLASS, props.css_responsive),
+                    if show_desktop {
+                        for part in props.parts.iter().copied() {
+                            {render_part(part)}
                         }
                     }
                 }
-            }
-            div {
-                style: "position: relative; display: inline-block;",
-                if is_mobile() {
-                    NavbarToggle {
-                        onclick: toggle_mobile_menu,
-                        toggle_style: props.menu_toggle_style,
-                        toggle_class: props.menu_toggle_class,
-                        line_style: props.line_style,
-                        line_class: props.line_class,
+                {props.end.clone()}
+                // The mobile layout has no parts row, so the toggle follows `end` there.
+                if show_mobile && props.show_theme_toggle && show_part(NavbarPart::ThemeToggle) {
+                    ThemeToggle {
+                        storage_key: props.theme_storage_key.clone(),
+                        on_theme_change,
+                        class: if props.css_responsive { MOBILE_CLASS } else { "" },
                     }
                 }
-                if is_mobile() && is_mobile_menu_open() {
-                    MobileMenu {
-                        menus: props.menus.clone(),
-                        menu_item_style: props.menu_item_style,
-                        menu_item_class: props.menu_item_class,
-                        dropdown_items: props.dropdown_items.clone(),
-                        dropdown_style: props.dropdown_style,
-                        dropdown_class: props.dropdown_class,
-                        dropdown_item_style: props.dropdown_item_style,
-                        dropdown_item_class: props.dropdown_item_class,
-                        show_profile_menu: props.show_profile_menu,
-                        show_search: props.show_search,
-                        search_input_style: props.search_input_style,
-                        search_input_class: props.search_input_class,
-                        search_placeholder: props.search_placeholder,
-                    }
+            }
+            if props.css_responsive {
+                details {
+                    id: disclosure_id.clone(),
+                    open: hydrated(),
+                    style: "position: relative; display: inline-block;",
+                    class: MOBILE_CLASS,
+                    {mobile_block}
+                }
+            } else {
+                div {
+                    style: "position: relative; display: inline-block;",
+                    {mobile_block}
+                }
+            }
+            if props.show_command_palette {
+                CommandPalette {
+                    menus: props.menus.clone(),
+                    dropdown_items: props.dropdown_items.clone(),
+                    mega_menu_items: props.mega_menu_items.clone(),
+                    commands: props.commands.clone(),
+                    shortcut: props.command_palette_shortcut.clone(),
+                    on_select: props.on_command_select,
                 }
             }
         }
@@ -0,0 +1,125 @@
+//! Client-side navigation for item links, through `dioxus-router` when the feature is enabled.
+
+#[cfg(feature = "dioxus-router")]
+use crate::core::is_route_link;
+use crate::dom::assign_location;
+use dioxus::prelude::*;
+#[cfg(feature = "dioxus-router")]
+use dioxus::router::prelude::{RouterContext, try_router};
+
+/// Renders item links, routing app-internal ones through the enclosing router.
+///
+/// Outside a `dioxus-router` router (or without the feature), links are plain `href`s.
+#[derive(Clone, Copy)]
+pub(super) struct LinkRouter {
+    #[cfg(feature = "dioxus-router")]
+    router: Option<RouterContext>,
+}
+
+impl LinkRouter {
+    /// Returns the `href` for a link, including the router's prefix.
+    pub(super) fn href(self, link: &str) -> String {
+        #[cfg(feature = "dioxus-router")]
+        if let Some(router) = self.router.filter(|_| is_route_link(link)) {
+            return router.prefix().unwrap_or_default() + link;
+        }
+        link.to_string()
+    }
+
+    /// Returns a click handler that navigates to `link` without reloading the page.
+    ///
+    /// Clicks with a modifier key (to open a new tab or window) are left to the browser, as are
+    /// links that open in another browsing context (`target` other than `_self`) and links
+    /// outside the app.
+    pub(super) fn onclick(self, link: &str, target: &str) -> impl FnMut(MouseEvent) + use<> {
+        #[cfg(feature = "dioxus-router")]
+        let route = self
+            .router
+            .filter(|_| is_route_link(link) && matches!(target, "" | "_self"))
+            .map(|router| (router, link.to_string()));
+        #[cfg(not(feature = "dioxus-router"))]
+        let _ = (self, link, target);
+
+        move |event: MouseEvent| {
+            #[cfg(feature = "dioxus-router")]
+            if let Some((router, link)) = &route {
+                let primary = event.trigger_button()
+                    == Some(dioxus_elements::input_data::MouseButton::Primary);
+                if primary && event.modifiers().is_empty() {
+                    event.prevent_default();
+                    router.push(link.clone());
+                }
+            }
+            #[cfg(not(feature = "dioxus-router"))]
+            let _ = event;
+        }
+    }
+
+    /// Navigates to `link`, through the router for app-internal links.
+    pub(super) fn navigate(self, link: &str) {
+        #[cfg(feature = "dioxus-router")]
+        if let Some(router) = self.router.filter(|_| is_route_link(link)) {
+            router.push(link.to_string());
+            return;
+        }
+        assign_location(&self.href(link));
+    }
+}
+
+/// Returns the link router of the enclosing `dioxus-router` router, if any.
+pub(super) fn use_link_router() -> LinkRouter {
+    LinkRouter {
+        #[cfg(feature = "dioxus-router")]
+        router: try_router(),
+    }
+}
+
+/// Returns the current route of the enclosing router, or `fallback` outside one.
+#[cfg(feature = "dioxus-router")]
+pub(super) fn use_router_path(fallback: Signal<String>) -> Memo<String> {
+    let router = try_router();
+    use_memo(move || match router {
+        Some(router) => router.full_route_string(),
+        None => fallback(),
+    })
+}
+
+/// Points navbar items at a typed `dioxus-router` route instead of a string path.
+///
+/// The item's `link` becomes the route's `Display` output, so it navigates through the enclosing
+/// `Router` like `Link` and is highlighted while that route is current:
+///
+/// ```rust,ignore
+/// use navbar::dioxus::{Menu, WithRoute};
+///
+/// Menu::new(1, "Ada", "").with_route(Route::User { id: 7 }) // link: "/users/7"
+/// ```
+#[cfg(feature = "dioxus-router")]
+pub trait WithRoute: Sized {
+    /// Sets the item's link to the path of `route`.
+    fn with_route<R: Routable>(self, route: R) -> Self;
+}
+
+#[cfg(feature = "dioxus-router")]
+impl<I> WithRoute for crate::core::Menu<I> {
+    fn with_route<R: Routable>(mut self, route: R) -> Self {
+        self.link = route.to_string().into();
+        self
+    }
+}
+
+#[cfg(feature = "dioxus-router")]
+impl<I> WithRoute for crate::core::DropdownItem<I> {
+    fn with_route<R: Routable>(mut self, route: R) -> Self {
+        self.link = route.to_string().into();
+        self
+    }
+}
+
+#[cfg(feature = "dioxus-router")]
+impl WithRoute for crate::core::MegaMenuItem {
+    fn with_route<R: Routable>(mut self, route: R) -> Self {
+        self.link = route.to_string().into();
+        self
+    }
+}
@@ -0,0 +1,709 @@
+//! Browser helpers shared by the framework adapters.
+
+#[cfg(any(feature = "yew", feature = "dio"))]
+use crate::core::{MenuKey, is_palette_shortcut};
+use crate::core::{Popup, ThemeMode, TransitionPhase, focus_trap_target};
+use gloo::events::EventListener;
+use gloo::events::EventListenerOptions;
+use gloo::render::{AnimationFrame, request_animation_frame};
+use gloo::timers::callback::Timeout;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use web_sys::wasm_bindgen::JsCast;
+
+/// Returns the browser window, or `None` outside a browser.
+///
+/// On targets other than `wasm32`, such as a server rendering the navbar or host-side tests,
+/// calling into JavaScript panics, so the window is never looked up there.
+pub(crate) fn window() -> Option<web_sys::Window> {
+    if cfg!(target_arch = "wasm32") {
+        web_sys::window()
+    } else {
+        None
+    }
+}
+
+/// Returns the current `window.innerWidth`, or `None` outside a browser.
+pub(crate) fn viewport_width() -> Option<f64> {
+    window()?.inner_width().ok()?.as_f64()
+}
+
+/// Returns the current `window.location.pathname`, or `None` outside a browser.
+pub(crate) fn location_path() -> Option<String> {
+    window()?.location().pathname().ok()
+}
+
+/// Loads `href` in the current window, like following a link.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn assign_location(href: &str) {
+    if let Some(window) = window() {
+        let _ = window.location().set_href(href);
+    }
+}
+
+/// Calls `on_change` with the new path whenever the history entry changes (`popstate`).
+///
+/// Returns `None` outside a browser. Dropping the listener stops watching.
+pub(crate) fn watch_location(mut on_change: impl FnMut(String) + 'static) -> Option<EventListener> {
+    let window = window()?;
+    Some(EventListener::new(&window, "popstate", move |_| {
+        if let Some(path) = location_path() {
+            on_change(path);
+        }
+    }))
+}
+
+/// Returns a document-unique element id starting with `prefix`.
+///
+/// The counter is shared by the whole process, so the ids differ between a server render and
+/// the client hydrating it. Components under a navbar take their ids from its [`IdScope`].
+pub(crate) fn unique_id(prefix: &str) -> String {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    format!("{prefix}-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Returns the id of a popup of the navbar whose ids start with `prefix`, e.g.
+/// `navbar-mobile-menu`.
+pub(crate) fn popup_id(prefix: &str, popup: Popup) -> String {
+    let name = match popup {
+        Popup::MobileMenu => "mobile-menu",
+        Popup::Dropdown => "dropdown",
+        Popup::MegaMenu => "mega-menu",
+        Popup::Notifications => "notifications",
+    };
+    format!("{prefix}-{name}")
+}
+
+/// Source of the element ids of the components under one navbar, shared through context.
+///
+/// Ids combine the navbar's `id_prefix` with a counter that only this navbar advances, in the
+/// order its components first render. They don't depend on what else the process rendered, so
+/// a server rendering many requests and the client hydrating one of them agree on every id.
+#[derive(Clone, Debug)]
+pub(crate) struct IdScope {
+    prefix: String,
+    next: Arc<AtomicUsize>,
+}
+
+impl IdScope {
+    /// Creates a scope whose ids start with `prefix`.
+    pub(crate) fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            next: Arc::default(),
+        }
+    }
+
+    /// Returns the next id of an element called `name`, e.g. `navbar-search-0`.
+    pub(crate) fn next_id(&self, name: &str) -> String {
+        let index = self.next.fetch_add(1, Ordering::Relaxed);
+        format!("{}-{name}-{index}", self.prefix)
+    }
+}
+
+impl PartialEq for IdScope {
+    fn eq(&self, other: &Self) -> bool {
+        self.prefix == other.prefix && Arc::ptr_eq(&self.next, &other.next)
+    }
+}
+
+/// Returns an id for an element called `name`: from the surrounding navbar's `scope` if any,
+/// and otherwise unique in the document.
+pub(crate) fn element_id(scope: Option<&IdScope>, name: &str) -> String {
+    match scope {
+        Some(scope) => scope.next_id(name),
+        None => unique_id(&format!("navbar-{name}")),
+    }
+}
+
+/// Returns the element id of the item at `index` in the menu with the given id.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn item_id(menu_id: &str, index: usize) -> String {
+    format!("{menu_id}-item-{index}")
+}
+
+/// Returns `true` if the element with the given id carries the `open` attribute, like an expanded
+/// `<details>`.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn is_details_open(id: &str) -> bool {
+    window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(id))
+        .is_some_and(|element| element.has_attribute("open"))
+}
+
+/// Moves keyboard focus to the element with the given id, if it is rendered.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn focus_element(id: &str) {
+    let element = window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(id))
+        .and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok());
+    if let Some(element) = element {
+        let _ = element.focus();
+    }
+}
+
+/// Scrolls the element with the given id into view within its scrolling container, if needed.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn scroll_into_view(id: &str) {
+    let element = window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(id));
+    if let Some(element) = element {
+        let options = web_sys::ScrollIntoViewOptions::new();
+        options.set_block(web_sys::ScrollLogicalPosition::Nearest);
+        element.scroll_into_view_with_scroll_into_view_options(&options);
+    }
+}
+
+/// Focuses the menu item that `key` moves to from `current`, returning `true` if focus moved.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn focus_menu_item(
+    menu_id: &str,
+    key: MenuKey,
+    current: Option<usize>,
+    len: usize,
+) -> bool {
+    match key.target(current, len) {
+        Some(index) => {
+            focus_element(&item_id(menu_id, index));
+            true
+        }
+        None => false,
+    }
+}
+
+/// Moves keyboard focus to the first focusable element inside the element with the given id, or
+/// to the last one for [`MenuKey::Last`].
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn focus_within(id: &str, key: MenuKey) {
+    let elements = focusable_within(id);
+    let target = match key {
+        MenuKey::Last => elements.last(),
+        _ => elements.first(),
+    };
+    if let Some(element) = target {
+        let _ = element.focus();
+    }
+}
+
+/// Calls `on_shortcut` whenever `key` is pressed with `Ctrl` (or `Cmd`) anywhere in the document.
+///
+/// The browser's own binding for the shortcut is suppressed. Returns `None` outside a browser;
+/// dropping the listener detaches it.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn listen_shortcut(
+    key: String,
+    mut on_shortcut: impl FnMut() + 'static,
+) -> Option<EventListener> {
+    let document = window()?.document()?;
+    Some(EventListener::new_with_options(
+        &document,
+        "keydown",
+        EventListenerOptions::enable_prevent_default(),
+        move |event| {
+            let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>() else {
+                return;
+            };
+            if is_palette_shortcut(&event.key(), &key, event.ctrl_key(), event.meta_key()) {
+                event.prevent_default();
+                on_shortcut();
+            }
+        },
+    ))
+}
+
+/// Remembers the focused element so focus can go back to it once a dialog closes.
+#[derive(Default)]
+pub(crate) struct FocusReturn(RefCell<Option<web_sys::HtmlElement>>);
+
+impl FocusReturn {
+    /// Remembers the currently focused element.
+    pub(crate) fn capture(&self) {
+        let active = window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.active_element())
+            .and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok());
+        self.0.replace(active);
+    }
+
+    /// Focuses the remembered element again, if any.
+    pub(crate) fn restore(&self) {
+        if let Some(element) = self.0.take() {
+            let _ = element.focus();
+        }
+    }
+}
+
+/// Returns `true` if `window.localStorage` can be used: in a browser, with storage allowed.
+fn local_storage_available() -> bool {
+    window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .is_some()
+}
+
+/// Loads the ids of recently used palette commands saved under `key`.
+///
+/// Returns an empty list when nothing was saved, `key` is empty or storage is unavailable.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn load_recent_commands(key: &str) -> Vec<String> {
+    use gloo::storage::{LocalStorage, Storage};
+
+    if key.is_empty() || !local_storage_available() {
+        return Vec::new();
+    }
+    LocalStorage::get(key).unwrap_or_default()
+}
+
+/// Saves the ids of recently used palette commands under `key`, unless `key` is empty.
+#[cfg(any(feature = "yew", feature = "dio"))]
+pub(crate) fn save_recent_commands(key: &str, ids: &[String]) {
+    use gloo::storage::{LocalStorage, Storage};
+
+    if !key.is_empty() && local_storage_available() {
+        let _ = LocalStorage::set(key, ids);
+    }
+}
+
+/// Runs only the last of a burst of calls, once no further call arrives within its delay.
+///
+/// Each call cancels the pending one. Dropping the debouncer cancels a pending call.
+#[derive(Default)]
+pub(crate) struct Debounce(RefCell<Option<Timeout>>);
+
+impl Debounce {
+    /// Schedules `f` to run after `delay_ms`, replacing any pending call.
+    pub(crate) fn call(&self, delay_ms: u32, f: impl FnOnce() + 'static) {
+        self.0.replace(Some(Timeout::new(delay_ms, f)));
+    }
+}
+
+/// Returns `true` if `event` was dispatched on the element with the given id or a descendant.
+fn event_within(event: &web_sys::Event, id: &str) -> bool {
+    let target = event
+        .target()
+        .and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+    let root = window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(id));
+    match (root, target) {
+        (Some(root), Some(target)) => root.contains(Some(&target)),
+        _ => false,
+    }
+}
+
+/// Calls a callback for every document `click` that lands outside an element.
+///
+/// The element is looked up by id on each click, so it may be re-rendered freely. The document
+/// listener is attached on the next tick, which keeps the click that opened a popup from
+/// immediately closing it again. Dropping the listener detaches it (or cancels the pending attach).
+pub(crate) struct OutsideClickListener {
+    _attach: Timeout,
+    _listener: Rc<RefCell<Option<EventListener>>>,
+}
+
+impl OutsideClickListener {
+    /// Starts listening for clicks outside the element with the given id.
+    pub(crate) fn new(id: String, on_outside: impl Fn() + 'static) -> Self {
+        let listener = Rc::new(RefCell::new(None));
+        let slot = listener.clone();
+        let attach = Timeout::new(0, move || {
+            let Some(document) = window().and_then(|w| w.document()) else {
+                return;
+            };
+            *slot.borrow_mut() = Some(EventListener::new(&document, "click", move |event| {
+                if !event_within(event, &id) {
+                    on_outside();
+                }
+            }));
+        });
+
+        Self {
+            _attach: attach,
+            _listener: listener,
+        }
+    }
+}
+
+/// Returns a function that runs `f` on the next animation frame, at most once per frame.
+///
+/// The scheduled frame is kept in `pending`; clearing it cancels the call.
+fn throttle_to_frame(
+    pending: Rc<RefCell<Option<AnimationFrame>>>,
+    f: impl FnMut() + 'static,
+) -> impl Fn() + Clone + 'static {
+    let f = Rc::new(RefCell::new(f));
+    move || {
+        if pending.borrow().is_some() {
+            return;
+        }
+        let f = f.clone();
+        let done = pending.clone();
+        let frame = request_animation_frame(move |_| {
+            done.borrow_mut().take();
+            (f.borrow_mut())();
+        });
+        *pending.borrow_mut() = Some(frame);
+    }
+}
+
+/// Watches the viewport width and reports it when it crosses one of a set of widths.
+///
+/// Each width is observed with a `(max-width: <width>px)` media query, so the callback only runs
+/// when a boundary is crossed rather than on every `resize`. Browsers without `matchMedia` fall
+/// back to a `resize` listener. Either way, reports are coalesced to one per animation frame.
+///
+/// All listeners, and any pending frame, are removed when the observer is dropped.
+pub(crate) struct ViewportObserver {
+    _listeners: Vec<EventListener>,
+    pending: Rc<RefCell<Option<AnimationFrame>>>,
+}
+
+impl ViewportObserver {
+    /// Starts observing the viewport, calling `on_change` with the new width after a crossing.
+    pub(crate) fn new(widths: &[f64], mut on_change: impl FnMut(f64) + 'static) -> Self {
+        let pending = Rc::new(RefCell::new(None));
+        let schedule = throttle_to_frame(pending.clone(), move || {
+            if let Some(width) = viewport_width() {
+                on_change(width);
+            }
+        });
+
+        let Some(window) = window() else {
+            return Self {
+                _listeners: Vec::new(),
+                pending,
+            };
+        };
+
+        let queries: Option<Vec<_>> = widths
+            .iter()
+            .map(|width| {
+                window
+                    .match_media(&format!("(max-width: {width}px)"))
+                    .ok()
+                    .flatten()
+            })
+            .collect();
+
+        let listeners = match queries {
+            Some(queries) if !queries.is_empty() => queries
+                .iter()
+                .map(|query| {
+                    let schedule = schedule.clone();
+                    EventListener::new(query, "change", move |_| schedule())
+                })
+                .collect(),
+            _ => vec![EventListener::new(&window, "resize", move |_| schedule())],
+        };
+
+        Self {
+            _listeners: listeners,
+            pending,
+        }
+    }
+}
+
+impl Drop for ViewportObserver {
+    fn drop(&mut self) {
+        // Cancels a scheduled frame and breaks the frame <-> handle cycle.
+        self.pending.borrow_mut().take();
+    }
+}
+
+/// Returns the current `window.scrollY`, or `None` outside a browser.
+pub(crate) fn scroll_y() -> Option<f64> {
+    window()?.scroll_y().ok()
+}
+
+/// Reports the vertical scroll position of the page, at most once per animation frame.
+///
+/// The `scroll` listener is passive, so it never delays scrolling. The current position is
+/// reported once on creation. The listener, and any pending frame, are removed when the observer
+/// is dropped.
+pub(crate) struct ScrollObserver {
+    _listener: Option<EventListener>,
+    pending: Rc<RefCell<Option<AnimationFrame>>>,
+}
+
+impl ScrollObserver {
+    /// Starts observing the page scroll, calling `on_scroll` with `window.scrollY`.
+    pub(crate) fn new(mut on_scroll: impl FnMut(f64) + 'static) -> Self {
+        let pending = Rc::new(RefCell::new(None));
+        let schedule = throttle_to_frame(pending.clone(), move || {
+            if let Some(y) = scroll_y() {
+                on_scroll(y);
+            }
+        });
+
+        let listener = window().map(|window| {
+            schedule();
+            EventListener::new(&window, "scroll", move |_| schedule())
+        });
+
+        Self {
+            _listener: listener,
+            pending,
+        }
+    }
+}
+
+impl Drop for ScrollObserver {
+    fn drop(&mut self) {
+        // Cancels a scheduled frame and breaks the frame <-> handle cycle.
+        self.pending.borrow_mut().take();
+    }
+}
+
+/// Returns `true` if the user asked for reduced motion (`prefers-reduced-motion: reduce`).
+pub(crate) fn prefers_reduced_motion() -> bool {
+    window()
+        .and_then(|w| {
+            w.match_media("(prefers-reduced-motion: reduce)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|query| query.matches())
+}
+
+/// Media query matched when the system prefers a dark color scheme.
+const DARK_SCHEME_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Returns `true` if the system prefers a dark color scheme (`prefers-color-scheme: dark`).
+pub(crate) fn prefers_dark_scheme() -> bool {
+    window()
+        .and_then(|w| w.match_media(DARK_SCHEME_QUERY).ok().flatten())
+        .is_some_and(|query| query.matches())
+}
+
+/// Watches `prefers-color-scheme` and reports whether the system now prefers dark.
+///
+/// Returns `None` outside a browser or without `matchMedia`. Dropping the listener stops the
+/// reports.
+pub(crate) fn watch_color_scheme(
+    mut on_change: impl FnMut(bool) + 'static,
+) -> Option<EventListener> {
+    let query = window()?.match_media(DARK_SCHEME_QUERY).ok()??;
+    Some(EventListener::new(&query.clone(), "change", move |_| {
+        on_change(query.matches())
+    }))
+}
+
+/// Loads the theme mode saved under `key`, if any.
+///
+/// The mode is stored as its plain name (`"light"`, `"dark"` or `"system"`), so an inline script
+/// in the page head can read it to set the theme before the app loads.
+pub(crate) fn load_theme_mode(key: &str) -> Option<ThemeMode> {
+    use gloo::storage::{LocalStorage, Storage};
+
+    if key.is_empty() || !local_storage_available() {
+        return None;
+    }
+    let name = LocalStorage::raw().get_item(key).ok()??;
+    ThemeMode::parse(&name)
+}
+
+/// Saves the theme mode under `key`, unless `key` is empty.
+pub(crate) fn save_theme_mode(key: &str, mode: ThemeMode) {
+    use gloo::storage::{LocalStorage, Storage};
+
+    if !key.is_empty() && local_storage_available() {
+        let _ = LocalStorage::raw().set_item(key, mode.as_str());
+    }
+}
+
+/// Sets `attribute` to `theme` on the document element (`<html>`), unless `attribute` is empty.
+pub(crate) fn set_document_theme(attribute: &str, theme: &str) {
+    if attribute.is_empty() {
+        return;
+    }
+    if let Some(root) = window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.document_element())
+    {
+        let _ = root.set_attribute(attribute, theme);
+    }
+}
+
+/// Runs a callback once the current DOM changes have been painted (two animation frames).
+///
+/// Dropping it cancels the callback.
+struct AfterPaint {
+    _first: AnimationFrame,
+    second: Rc<RefCell<Option<AnimationFrame>>>,
+}
+
+impl AfterPaint {
+    fn new(f: impl FnOnce() + 'static) -> Self {
+        let second = Rc::new(RefCell::new(None));
+        let slot = second.clone();
+        let first = request_animation_frame(move |_| {
+            *slot.borrow_mut() = Some(request_animation_frame(move |_| f()));
+        });
+        Self {
+            _first: first,
+            second,
+        }
+    }
+}
+
+impl Drop for AfterPaint {
+    fn drop(&mut self) {
+        self.second.borrow_mut().take();
+    }
+}
+
+/// Ends the timed phases of a transition: [`TransitionPhase::Enter`] once its styles have been
+/// painted, and [`TransitionPhase::Exit`] after the timeout, in case no `transitionend` arrives.
+///
+/// Dropping the timer, e.g. when the phase changes first, cancels it.
+pub(crate) struct PhaseTimer {
+    _painted: Option<AfterPaint>,
+    _timeout: Option<Timeout>,
+}
+
+impl PhaseTimer {
+    /// Calls `advance` when `phase` has run its course, if it is a timed phase.
+    pub(crate) fn new(
+        phase: TransitionPhase,
+        timeout_ms: u32,
+        advance: impl FnOnce() + 'static,
+    ) -> Self {
+        match phase {
+            TransitionPhase::Enter => Self {
+                _painted: Some(AfterPaint::new(advance)),
+                _timeout: None,
+            },
+            TransitionPhase::Exit => Self {
+                _painted: None,
+                _timeout: Some(Timeout::new(timeout_ms, advance)),
+            },
+            TransitionPhase::Entering | TransitionPhase::Exited => Self {
+                _painted: None,
+                _timeout: None,
+            },
+        }
+    }
+}
+
+/// Elements that can receive keyboard focus, for [`FocusTrap`].
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+/// Returns the focusable elements inside the element with the given id, in document order.
+fn focusable_within(id: &str) -> Vec<web_sys::HtmlElement> {
+    let nodes = window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(id))
+        .and_then(|root| root.query_selector_all(FOCUSABLE).ok());
+    let Some(nodes) = nodes else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+/// Keeps keyboard focus inside a modal panel while it is open.
+///
+/// Creating the trap moves focus to the first focusable element of the panel with the given id;
+/// `Tab` and `Shift+Tab` then wrap around within it, and `Escape` calls `on_escape`. Dropping
+/// the trap detaches its listener and returns focus to the element focused before.
+pub(crate) struct FocusTrap {
+    _keydown: Option<EventListener>,
+    focus_return: FocusReturn,
+}
+
+impl FocusTrap {
+    /// Traps focus inside the element with the given id.
+    pub(crate) fn new(id: String, on_escape: impl Fn() + 'static) -> Self {
+        let focus_return = FocusReturn::default();
+        focus_return.capture();
+        if let Some(first) = focusable_within(&id).first() {
+            let _ = first.focus();
+        }
+
+        let document = window().and_then(|w| w.document());
+        let keydown = document.map(|document| {
+            EventListener::new_with_options(
+                &document,
+                "keydown",
+                EventListenerOptions::enable_prevent_default(),
+                move |event| {
+                    let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>() else {
+                        return;
+                    };
+                    match event.key().as_str() {
+                        // Escape already handled inside the panel, e.g. by a listbox, stays there.
+                        "Escape" if !event.default_prevented() => on_escape(),
+                        "Tab" => {
+                            let elements = focusable_within(&id);
+                            let active = window()
+                                .and_then(|w| w.document())
+                                .and_then(|d| d.active_element());
+                            let current = active.and_then(|active| {
+                                elements
+                                    .iter()
+                                    .position(|element| **element == *active.unchecked_ref())
+                            });
+                            let target =
+                                focus_trap_target(current, elements.len(), event.shift_key());
+                            if let Some(element) = target.and_then(|index| elements.get(index)) {
+                                event.prevent_default();
+                                let _ = element.focus();
+                            } else if elements.is_empty() {
+                                event.prevent_default();
+                            }
+                        }
+                        _ => {}
+                    }
+                },
+            )
+        });
+        Self {
+            _keydown: keydown,
+            focus_return,
+        }
+    }
+}
+
+impl Drop for FocusTrap {
+    fn drop(&mut self) {
+        self.focus_return.restore();
+    }
+}
+
+/// Stops the page behind a modal panel from scrolling while it is open.
+///
+/// Sets `overflow: hidden` on `<body>`; dropping the lock restores the previous value.
+pub(crate) struct ScrollLock {
+    previous: Option<String>,
+}
+
+impl ScrollLock {
+    /// Locks the page scroll.
+    pub(crate) fn new() -> Self {
+        let previous = body_style().map(|style| {
+            let previous = style.get_property_value("overflow").unwrap_or_default();
+            let _ = style.set_property("overflow", "hidden");
+            previous
+        });
+        Self { previous }
+    }
+}
+
+impl Drop for ScrollLock {
+    fn drop(&mut self) {
+        if let (Some(style), Some(previous)) = (body_style(), self.previous.take()) {
+            let _ = style.set_property("overflow", &previous);
+        }
+    }
+}
+
+/// Returns the inline style of `<body>`, or `None` outside a browser.
+fn body_style() -> Option<web_sys::CssStyleDeclaration> {
+    Some(window()?.document()?.body()?.style())
+}
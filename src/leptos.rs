@@ -1 +1,2767 @@
+#![doc = include_str!("../LEPTOS.md")]
 
+use crate::core::{
+    ActiveMatch, BADGE_MAX, BELL_ICON_PATH, BreakpointTracker, Breakpoints, DESKTOP_CLASS,
+    InitialLayout, MOBILE_BREAKPOINT, MOBILE_CLASS, MobileVariant, NavbarAction, NavbarPart,
+    NavbarState, NavbarTheme, Notification, Popup, ScrollBehavior, ScrollState, ScrollTracker,
+    ThemeMode, ThemePreference, ToggleAnimation, Transition, TransitionPhase,
+    VISUALLY_HIDDEN_STYLE, Viewport, badge_text, responsive_css, unread_count,
+};
+use crate::dom::{
+    Debounce, FocusTrap, IdScope, OutsideClickListener, PhaseTimer, ScrollLock, ScrollObserver,
+    ViewportObserver, load_theme_mode, location_path, popup_id, prefers_dark_scheme,
+    prefers_reduced_motion, save_theme_mode, set_document_theme, viewport_width,
+    watch_color_scheme, watch_location,
+};
+use leptos::ev::{MouseEvent, PointerEvent};
+use leptos::html::{Details, Input};
+use leptos::prelude::*;
+
+pub use crate::core::MegaMenuItem;
+
+/// Represents a standard menu item in the navigation bar.
+///
+/// This is the shared [`crate::core::Menu`] model with Leptos `ViewFn` icons.
+pub type Menu = crate::core::Menu<ViewFn>;
+
+/// Represents an item in a dropdown menu.
+///
+/// This is the shared [`crate::core::DropdownItem`] model with a Leptos `ViewFn` icon.
+pub type DropdownItem = crate::core::DropdownItem<ViewFn>;
+
+/// Properties for rendering a logo inside a navigation bar.
+#[component]
+pub fn NavbarLogo(
+    /// Path to the logo image.
+    #[prop(into, default = "/logo.png".into())]
+    logo_src: Signal<String>,
+    /// Alternative text for the logo image.
+    #[prop(into, default = "Logo".into())]
+    logo_alt: Signal<String>,
+    /// URL to redirect when the logo is clicked.
+    #[prop(into, default = "/".into())]
+    logo_link: Signal<String>,
+    /// Inline CSS style for the logo image.
+    #[prop(default = "height: 40px;")]
+    logo_style: &'static str,
+    /// Inline CSS style for the link wrapping the logo.
+    #[prop(default = "display: inline-block;")]
+    link_style: &'static str,
+    /// Optional class name for the logo image.
+    #[prop(optional, into)]
+    logo_class: String,
+    /// Optional class name for the anchor tag wrapping the logo.
+    #[prop(optional, into)]
+    link_class: String,
+) -> impl IntoView {
+    view! {
+        <a href=logo_link style=link_style class=link_class>
+            <img src=logo_src alt=logo_alt style=logo_style class=logo_class />
+        </a>
+    }
+}
+
+/// Properties for rendering a hamburger toggle icon (used in responsive menus).
+#[component]
+pub fn NavbarToggle(
+    /// Callback triggered when the toggle is clicked.
+    #[prop(into)]
+    onclick: Callback<MouseEvent>,
+    /// Whether the menu the toggle controls is open, exposed as `aria-expanded`.
+    #[prop(into, default = false.into())]
+    open: Signal<bool>,
+    /// Id of the menu the toggle controls, exposed as `aria-controls`.
+    #[prop(optional, into)]
+    controls: Option<String>,
+    /// Accessible label (`aria-label`) of the toggle button.
+    #[prop(into, default = "Toggle Menu".into())]
+    label: Signal<String>,
+    /// How the icon animates when the menu opens.
+    #[prop(optional)]
+    animation: ToggleAnimation,
+    /// Renders the toggle as the `<summary>` of a surrounding `<details>`, which opens and closes
+    /// without JavaScript until `onclick` takes over (its default action is then prevented).
+    #[prop(optional)]
+    disclosure: bool,
+    /// Inline style for the toggle button.
+    #[prop(
+        default = "display: flex; flex-direction: column; gap: 5px; cursor: pointer; background: none; border: none; padding: 0;"
+    )]
+    toggle_style: &'static str,
+    /// Inline style for each line/bar in the hamburger icon.
+    #[prop(default = "width: 25px; height: 3px; background: var(--navbar-fg, black);")]
+    line_style: &'static str,
+    /// Optional class for the toggle container.
+    #[prop(optional, into)]
+    toggle_class: String,
+    /// Optional class for each line in the icon.
+    #[prop(optional, into)]
+    line_class: String,
+) -> impl IntoView {
+    let line = move |index: usize| {
+        view! {
+            <span
+                aria-hidden="true"
+                style=move || with_phase(line_style, animation.line_style(index, open.get()))
+                class=line_class.clone()
+            />
+        }
+    };
+
+    if disclosure {
+        return view! {
+            <summary
+                aria-label=label
+                aria-controls=controls
+                aria-expanded=move || open.get().to_string()
+                style=move || with_phase(toggle_style, animation.toggle_style(open.get()))
+                class=toggle_class
+                on:click=move |e| {
+                    e.prevent_default();
+                    onclick.run(e);
+                }
+            >
+                {line(0)}
+                {line(1)}
+                {line(2)}
+            </summary>
+        }
+        .into_any();
+    }
+    view! {
+        <button
+            type="button"
+            aria-label=label
+            aria-controls=controls
+            aria-expanded=move || open.get().to_string()
+            style=move || with_phase(toggle_style, animation.toggle_style(open.get()))
+            class=toggle_class
+            on:click=move |e| onclick.run(e)
+        >
+            {line(0)}
+            {line(1)}
+            {line(2)}
+        </button>
+    }
+    .into_any()
+}
+
+/// Properties for rendering a horizontal navigation menu.
+#[component]
+pub fn NavbarMenu(
+    /// List of menu items to display.
+    menus: Vec<Menu>,
+    /// Inline style for each menu item anchor tag.
+    #[prop(
+        default = "padding: 0.5rem 1rem; text-decoration: none; color: var(--navbar-fg, black);"
+    )]
+    menu_item_style: &'static str,
+    /// Inline style for the entire list container.
+    #[prop(default = "display: flex; gap: 1rem; list-style: none; margin: 0; padding: 0;")]
+    list_style: &'static str,
+    /// Optional class for each menu item.
+    #[prop(optional, into)]
+    menu_item_class: String,
+    /// Optional class for the list container.
+    #[prop(optional, into)]
+    list_class: String,
+    /// Style for the submenu of an item with `children`.
+    #[prop(default = SUBMENU_STYLE)]
+    submenu_style: &'static str,
+    /// Optional class for the submenu of an item with `children`.
+    #[prop(optional, into)]
+    submenu_class: String,
+    /// Expands submenus inline below their item (an accordion) instead of as flyouts.
+    #[prop(default = false)]
+    accordion: bool,
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[prop(optional, into)]
+    current_path: Option<Signal<String>>,
+    /// How item links are matched against the current path.
+    #[prop(optional)]
+    active_match: ActiveMatch,
+    /// Style added to the active item's link, which also gets `aria-current="page"`.
+    #[prop(default = "font-weight: bold;")]
+    active_style: &'static str,
+    /// Optional class added to the active item's link.
+    #[prop(optional, into)]
+    active_class: String,
+) -> impl IntoView {
+    let location = use_location_path();
+    let path =
+        Signal::derive(move || current_path.map_or_else(|| location.get(), |path| path.get()));
+    let active_match = StoredValue::new(active_match);
+    let active =
+        move |link: &str| active_match.with_value(|m| path.with(|path| m.is_active(link, path)));
+
+    view! {
+        <ul style=list_style class=list_class>
+            {menus
+                .into_iter()
+                .map(|menu| {
+                    if menu.has_submenu() {
+                        return view! {
+                            <NavbarSubmenu
+                                item=menu
+                                accordion=accordion
+                                nested=false
+                                item_style=menu_item_style
+                                item_class=menu_item_class.clone()
+                                submenu_style=submenu_style
+                                submenu_class=submenu_class.clone()
+                                path=path
+                                active_match=active_match
+                                active_style=active_style
+                                active_class=active_class.clone()
+                            />
+                        }
+                            .into_any();
+                    }
+                    let is_active = Signal::derive({
+                        let link = menu.link.clone();
+                        move || active(&link)
+                    });
+                    let link = menu.link.to_string();
+                    let menu_item_class = menu_item_class.clone();
+                    let active_class = active_class.clone();
+                    view! {
+                        <li>
+                            <a
+                                href=link
+                                aria-current=move || is_active.get().then_some("page")
+                                style=move || with_active(menu_item_style, active_style, is_active.get())
+                                class=move || with_active(&menu_item_class, &active_class, is_active.get())
+                            >
+                                {menu.icon_start.map(|icon| icon.run())}
+                                {menu.name.to_string()}
+                                {menu.icon_end.map(|icon| icon.run())}
+                            </a>
+                        </li>
+                    }
+                        .into_any()
+                })
+                .collect_view()}
+        </ul>
+    }
+}
+
+/// Default style for a desktop submenu flyout.
+const SUBMENU_STYLE: &str = "position: absolute; top: 100%; left: 0; z-index: 1000; min-width: 12rem; margin: 0; padding: 0.5rem 0; list-style: none; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1));";
+
+/// Renders a menu item with `children` as a menu button followed by its submenu.
+///
+/// As a flyout, the submenu opens on hover or click and closes on mouse leave, a click outside
+/// or after following one of its links. As an accordion it stays open until its item is clicked
+/// again.
+#[component]
+fn NavbarSubmenu(
+    /// The item whose children are shown.
+    item: Menu,
+    /// Expands the submenu inline below the item instead of as a flyout.
+    accordion: bool,
+    /// Opens the flyout beside the item rather than below it.
+    nested: bool,
+    item_style: &'static str,
+    item_class: String,
+    submenu_style: &'static str,
+    submenu_class: String,
+    path: Signal<String>,
+    active_match: StoredValue<ActiveMatch>,
+    active_style: &'static str,
+    active_class: String,
+    /// Called after a link in the submenu, at any depth, is followed.
+    #[prop(optional)]
+    on_navigate: Option<Callback<()>>,
+) -> impl IntoView {
+    let id = element_id("submenu");
+    let button_id = format!("{id}-button");
+    let menu_id = format!("{id}-menu");
+    let open = RwSignal::new(false);
+    use_click_outside(
+        id.clone(),
+        Signal::derive(move || open.get() && !accordion),
+        Callback::new(move |_| open.set(false)),
+    );
+
+    // Following a link closes every flyout up to the menubar.
+    let navigate = Callback::new(move |_| {
+        if !accordion {
+            open.set(false);
+        }
+        if let Some(on_navigate) = on_navigate {
+            on_navigate.run(());
+        }
+    });
+
+    let (wrapper_style, list_style) = match (accordion, nested) {
+        (true, _) => (None, submenu_style.to_string()),
+        (false, true) => (
+            Some("position: relative;"),
+            format!("{submenu_style} top: 0; left: 100%;"),
+        ),
+        (false, false) => (Some("position: relative;"), submenu_style.to_string()),
+    };
+    let link = item.link.to_string();
+    let children = StoredValue::new(item.children.clone());
+    let item = StoredValue::new(item);
+    let item_active = move || {
+        item.with_value(|item| {
+            active_match.with_value(|m| path.with(|path| item.contains_active(m, path)))
+        })
+    };
+    let active =
+        move |link: &str| active_match.with_value(|m| path.with(|path| m.is_active(link, path)));
+    let trigger_class = item_class.clone();
+    let trigger_active_class = active_class.clone();
+
+    view! {
+        <li
+            id=id
+            role="none"
+            style=wrapper_style
+            // Only a mouse opens the flyout on hover; a tap is left to the click that toggles it.
+            on:pointerenter=move |e: PointerEvent| {
+                if !accordion && e.pointer_type() == "mouse" {
+                    open.set(true);
+                }
+            }
+            on:pointerleave=move |e: PointerEvent| {
+                if !accordion && e.pointer_type() == "mouse" {
+                    open.set(false);
+                }
+            }
+        >
+            <a
+                id=button_id.clone()
+                href=link
+                role="menuitem"
+                aria-haspopup="menu"
+                aria-expanded=move || open.get().to_string()
+                aria-controls=menu_id.clone()
+                on:click=move |e: MouseEvent| {
+                    e.prevent_default();
+                    open.update(|open| *open = !*open);
+                }
+                style=move || with_active(item_style, active_style, item_active())
+                class=move || with_active(&trigger_class, &trigger_active_class, item_active())
+            >
+                {item.with_value(|item| item.icon_start.clone()).map(|icon| icon.run())}
+                {item.with_value(|item| item.name.to_string())}
+                {item.with_value(|item| item.icon_end.clone()).map(|icon| icon.run())}
+            </a>
+            <Show when=move || open.get()>
+                <ul
+                    id=menu_id.clone()
+                    role="menu"
+                    aria-labelledby=button_id.clone()
+                    style=list_style.clone()
+                    class=submenu_class.clone()
+                >
+                    {children
+                        .get_value()
+                        .into_iter()
+                        .map(|child| {
+                            if child.has_submenu() {
+                                return view! {
+                                    <NavbarSubmenu
+                                        item=child
+                                        accordion=accordion
+                                        nested=true
+                                        item_style=item_style
+                                        item_class=item_class.clone()
+                                        submenu_style=submenu_style
+                                        submenu_class=submenu_class.clone()
+                                        path=path
+                                        active_match=active_match
+                                        active_style=active_style
+                                        active_class=active_class.clone()
+                                        on_navigate=navigate
+                                    />
+                                }
+                                    .into_any();
+                            }
+                            let is_active = Signal::derive({
+                                let link = child.link.clone();
+                                move || active(&link)
+                            });
+                            let link = child.link.to_string();
+                            let item_class = item_class.clone();
+                            let active_class = active_class.clone();
+                            view! {
+                                <li role="none">
+                                    <a
+                                        href=link
+                                        role="menuitem"
+                                        on:click=move |_| navigate.run(())
+                                        aria-current=move || is_active.get().then_some("page")
+                                        style=move || with_active(item_style, active_style, is_active.get())
+                                        class=move || with_active(&item_class, &active_class, is_active.get())
+                                    >
+                                        {child.icon_start.map(|icon| icon.run())}
+                                        {child.name.to_string()}
+                                        {child.icon_end.map(|icon| icon.run())}
+                                    </a>
+                                </li>
+                            }
+                                .into_any()
+                        })
+                        .collect_view()}
+                </ul>
+            </Show>
+        </li>
+    }
+}
+
+/// Properties for rendering a search form.
+///
+/// The input sits inside a `role="search"` form, so `Enter` submits it.
+#[component]
+pub fn NavbarSearch(
+    /// Current search text, written to the input whenever it changes.
+    #[prop(into, default = "".into())]
+    value: Signal<String>,
+    /// Placeholder text for the search input.
+    #[prop(into, default = "Search...".into())]
+    placeholder: Signal<String>,
+    /// Style for the search input field.
+    #[prop(
+        default = "padding: 0.5rem; font-size: 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
+    )]
+    input_style: &'static str,
+    /// Optional class for the search input.
+    #[prop(optional, into)]
+    input_class: String,
+    /// Callback fired with the text on every keystroke.
+    #[prop(optional, into)]
+    on_input: Option<Callback<String>>,
+    /// Callback fired with the text on `Enter` or a click on the search button.
+    #[prop(optional, into)]
+    on_submit: Option<Callback<String>>,
+    /// Whether to show a submit button next to the input.
+    #[prop(default = false)]
+    show_button: bool,
+    /// Text of the submit button.
+    #[prop(into, default = "Search".into())]
+    button_text: Signal<String>,
+    /// Style for the submit button.
+    #[prop(
+        default = "padding: 0.5rem 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;"
+    )]
+    button_style: &'static str,
+    /// Optional class for the submit button.
+    #[prop(optional, into)]
+    button_class: String,
+) -> impl IntoView {
+    let input_ref = NodeRef::<Input>::new();
+
+    view! {
+        <form
+            role="search"
+            style="display: flex; gap: 0.5rem; margin: 0;"
+            on:submit=move |e| {
+                e.prevent_default();
+                if let (Some(input), Some(on_submit)) = (input_ref.get(), on_submit) {
+                    on_submit.run(input.value());
+                }
+            }
+        >
+            <input
+                node_ref=input_ref
+                type="search"
+                aria-label=placeholder
+                placeholder=placeholder
+                style=input_style
+                class=input_class
+                prop:value=value
+                on:input=move |e| {
+                    if let Some(on_input) = on_input {
+                        on_input.run(event_target_value(&e));
+                    }
+                }
+            />
+            <Show when=move || show_button>
+                <button type="submit" style=button_style class=button_class.clone()>
+                    {button_text}
+                </button>
+            </Show>
+        </form>
+    }
+}
+
+/// Properties for rendering a styled button with a link.
+#[component]
+pub fn NavbarButton(
+    /// The URL the button should navigate to.
+    #[prop(into, default = "#".into())]
+    href: Signal<String>,
+    /// Text displayed inside the button.
+    #[prop(into, default = "Click Me".into())]
+    text: Signal<String>,
+    /// Style for the outer div wrapping the button.
+    #[prop(
+        default = "padding: 0.5rem 1rem; background-color: var(--navbar-accent, #007bff); border-radius: 4px;"
+    )]
+    button_style: &'static str,
+    /// Style applied to the anchor inside the button.
+    #[prop(default = "color: var(--navbar-accent-text, white); text-decoration: none;")]
+    link_style: &'static str,
+    /// Target attribute for the anchor link (e.g. `_blank`, `_self`).
+    #[prop(into, default = "_self".into())]
+    target: Signal<String>,
+    /// Optional class for the outer button div.
+    #[prop(optional, into)]
+    button_class: String,
+    /// Optional class for the inner anchor tag.
+    #[prop(optional, into)]
+    link_class: String,
+) -> impl IntoView {
+    view! {
+        <div style=button_style class=button_class>
+            <a href=href target=target style=link_style class=link_class>
+                {text}
+            </a>
+        </div>
+    }
+}
+
+/// Default style of the theme toggle's group of buttons.
+const THEME_TOGGLE_STYLE: &str = "display: inline-flex; gap: 0.25rem; margin-left: 1rem;";
+
+/// Default style of each theme toggle button.
+const THEME_BUTTON_STYLE: &str = "padding: 0.25rem 0.5rem; border: 1px solid var(--navbar-border, #ccc); border-radius: 4px; background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;";
+
+/// A light/dark/system switch that themes the document.
+///
+/// The picked mode is saved to `localStorage` and restored once mounted: the first render, on
+/// the server and when hydrating, shows `default_mode`, so both agree. In system mode, the theme
+/// follows `prefers-color-scheme`, live. The applied theme is set on `<html>` as
+/// `data-theme="light"` or `data-theme="dark"`, for the app's own styles, and reported through
+/// `on_theme_change`. The buttons form a group with `aria-pressed` on the current mode.
+///
+/// The [`Navbar`] renders one with `show_theme_toggle`, switching to its `dark_theme`.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use navbar::leptos::ThemeToggle;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! { <ThemeToggle storage_key="app-theme" /> }
+/// }
+/// ```
+#[component]
+pub fn ThemeToggle(
+    /// Mode used until the user picks one (or when nothing was saved).
+    #[prop(optional)]
+    default_mode: ThemeMode,
+    /// `localStorage` key the picked mode is saved under; empty to keep it in memory only.
+    #[prop(into, default = "navbar-theme".into())]
+    storage_key: String,
+    /// Attribute of `<html>` set to the applied theme (`"light"` or `"dark"`); empty to leave
+    /// the document alone.
+    #[prop(into, default = "data-theme".into())]
+    attribute: String,
+    /// Callback fired with the preference on mount and whenever the mode or, in system mode,
+    /// the system preference changes.
+    #[prop(optional, into)]
+    on_theme_change: Option<Callback<ThemePreference>>,
+    /// Accessible name of the group of buttons.
+    #[prop(into, default = "Theme".into())]
+    label: String,
+    /// Text of the light mode button.
+    #[prop(into, default = "Light".into())]
+    light_text: String,
+    /// Text of the dark mode button.
+    #[prop(into, default = "Dark".into())]
+    dark_text: String,
+    /// Text of the system mode button.
+    #[prop(into, default = "System".into())]
+    system_text: String,
+    /// Style for the group of buttons.
+    #[prop(default = THEME_TOGGLE_STYLE)]
+    style: &'static str,
+    /// Optional class for the group of buttons.
+    #[prop(optional, into)]
+    class: String,
+    /// Style for each button.
+    #[prop(default = THEME_BUTTON_STYLE)]
+    button_style: &'static str,
+    /// Optional class for each button.
+    #[prop(optional, into)]
+    button_class: String,
+    /// Style added to the button of the current mode.
+    #[prop(default = "background: var(--navbar-highlight, #f0f0f0); font-weight: bold;")]
+    active_style: &'static str,
+    /// Optional class added to the button of the current mode.
+    #[prop(optional, into)]
+    active_class: String,
+) -> impl IntoView {
+    let mode = RwSignal::new(default_mode);
+    let (system_dark, set_system_dark) = signal(false);
+    let listener =
+        StoredValue::new_local(watch_color_scheme(move |dark| set_system_dark.set(dark)));
+    on_cleanup(move || listener.dispose());
+
+    // The server can't read the saved mode, so the first render shows `default_mode` on both
+    // sides and the saved mode and the system preference are read once mounted.
+    let restored = RwSignal::new(false);
+    {
+        let storage_key = storage_key.clone();
+        Effect::new(move |_| {
+            if let Some(saved) = load_theme_mode(&storage_key) {
+                mode.set(saved);
+            }
+            set_system_dark.set(prefers_dark_scheme());
+            restored.set(true);
+        });
+    }
+
+    Effect::new(move |_| {
+        if !restored.get() {
+            return;
+        }
+        let preference = ThemePreference {
+            mode: mode.get(),
+            system_dark: system_dark.get(),
+        };
+        set_document_theme(&attribute, preference.theme());
+        if let Some(on_theme_change) = on_theme_change {
+            on_theme_change.run(preference);
+        }
+    });
+
+    let storage_key = StoredValue::new(storage_key);
+    let button_class = StoredValue::new(button_class);
+    let active_class = StoredValue::new(active_class);
+    let buttons = ThemeMode::ALL
+        .into_iter()
+        .map(|option| {
+            let text = match option {
+                ThemeMode::Light => light_text.clone(),
+                ThemeMode::Dark => dark_text.clone(),
+                ThemeMode::System => system_text.clone(),
+            };
+            let pressed = move || mode.get() == option;
+            view! {
+                <button
+                    type="button"
+                    aria-pressed=move || pressed().to_string()
+                    on:click=move |_| {
+                        storage_key.with_value(|key| save_theme_mode(key, option));
+                        mode.set(option);
+                    }
+                    style=move || with_active(button_style, active_style, pressed())
+                    class=move || {
+                        with_active(&button_class.get_value(), &active_class.get_value(), pressed())
+                    }
+                >
+                    {text}
+                </button>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div role="group" aria-label=label style=style class=class>
+            {buttons}
+        </div>
+    }
+}
+
+/// Properties for rendering a user profile menu dropdown.
+#[component]
+pub fn ProfileMenu(
+    /// List of dropdown items in the profile menu.
+    items: Vec<DropdownItem>,
+    /// Style for the dropdown container.
+    #[prop(
+        default = "position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); z-index: 1000;"
+    )]
+    dropdown_style: &'static str,
+    /// Style for each item in the dropdown.
+    #[prop(default = "padding: 0.5rem 1rem; white-space: nowrap;")]
+    item_style: &'static str,
+    /// Style for the wrapper around the profile button.
+    #[prop(default = "position: relative; display: inline-block; margin: 0 0 0 1rem;")]
+    wrapper_style: &'static str,
+    /// Style for the clickable profile button.
+    #[prop(default = "background: none; border: none; cursor: pointer;")]
+    button_style: &'static str,
+    /// Style for the avatar image.
+    #[prop(default = "width: 40px; height: 40px; border-radius: 50%; object-fit: cover;")]
+    avatar_img_style: &'static str,
+    /// Style for fallback avatar (e.g., initials).
+    #[prop(
+        default = "width: 40px; height: 40px; border-radius: 50%; background-color: var(--navbar-border, #ccc); color: var(--navbar-accent-text, #fff); display: flex; align-items: center; justify-content: center; font-weight: bold;"
+    )]
+    avatar_fallback_style: &'static str,
+    /// Displayed if avatar image is not available.
+    #[prop(into, default = "Profile".into())]
+    profile_text: Signal<String>,
+    /// Optional URL for the avatar image.
+    #[prop(default = None)]
+    profile_image_url: Option<String>,
+    /// Indicates if the dropdown is currently open.
+    #[prop(into)]
+    is_open: Signal<bool>,
+    /// Callback to toggle the dropdown menu.
+    #[prop(into)]
+    toggle: Callback<MouseEvent>,
+    /// Class name for the dropdown container.
+    #[prop(optional, into)]
+    dropdown_class: String,
+    /// Class name for dropdown items.
+    #[prop(optional, into)]
+    item_class: String,
+    /// Class name for wrapper element.
+    #[prop(optional, into)]
+    wrapper_class: String,
+    /// Class name for avatar image/fallback.
+    #[prop(optional, into)]
+    avatar_class: String,
+    /// Class name for the button.
+    #[prop(optional, into)]
+    button_class: String,
+    /// Enter and exit transition of the dropdown list.
+    #[prop(default = None)]
+    transition: Option<Transition>,
+    /// Whether a click outside the open dropdown closes it.
+    #[prop(default = true)]
+    close_on_outside_click: bool,
+    /// Callback to close the dropdown, e.g. after a click outside of it.
+    #[prop(optional, into)]
+    on_close: Option<Callback<()>>,
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[prop(optional, into)]
+    current_path: Option<Signal<String>>,
+    /// How item links are matched against the current path.
+    #[prop(optional)]
+    active_match: ActiveMatch,
+    /// Style added to the active item's link, which also gets `aria-current="page"`.
+    #[prop(default = "font-weight: bold;")]
+    active_style: &'static str,
+    /// Optional class added to the active item's link.
+    #[prop(optional, into)]
+    active_class: String,
+) -> impl IntoView {
+    let id = element_id("dropdown");
+    use_click_outside(
+        id.clone(),
+        Signal::derive(move || is_open.get() && close_on_outside_click),
+        on_close.unwrap_or_else(|| Callback::new(|_| ())),
+    );
+
+    let location = use_location_path();
+    let path =
+        Signal::derive(move || current_path.map_or_else(|| location.get(), |path| path.get()));
+    let active_match = StoredValue::new(active_match);
+    let active =
+        move |link: &str| active_match.with_value(|m| path.with(|path| m.is_active(link, path)));
+
+    let (phase, on_transition_end) = use_transition(is_open, transition.clone());
+    let transition = StoredValue::new(transition.unwrap_or_default());
+
+    let fallback = move || profile_text.with(|text| text.chars().next().unwrap_or('P'));
+    let avatar = match profile_image_url {
+        Some(url) => view! {
+            <img src=url alt="Profile" style=avatar_img_style class=avatar_class />
+        }
+        .into_any(),
+        None => view! {
+            <div style=avatar_fallback_style class=avatar_class>
+                {fallback}
+            </div>
+        }
+        .into_any(),
+    };
+
+    view! {
+        <div id=id style=wrapper_style class=wrapper_class>
+            <button
+                on:click=move |e| toggle.run(e)
+                aria-haspopup="true"
+                aria-expanded=move || is_open.get().to_string()
+                style=button_style
+                class=button_class
+            >
+                {avatar}
+            </button>
+            <Show when=move || phase.get().is_mounted()>
+                <ul
+                    style=move || {
+                        transition.with_value(|t| with_phase(dropdown_style, t.style(phase.get())))
+                    }
+                    class={
+                        let dropdown_class = dropdown_class.clone();
+                        move || {
+                            transition
+                                .with_value(|t| with_phase(&dropdown_class, t.class(phase.get())))
+                        }
+                    }
+                    on:transitionend=move |_| on_transition_end.run(())
+                >
+                    {items
+                        .iter()
+                        .map(|item| {
+                            let is_active = Signal::derive({
+                                let link = item.link.clone();
+                                move || active(&link)
+                            });
+                            let link = item.link.to_string();
+                            let active_class = active_class.clone();
+                            view! {
+                                <li style=item_style class=item_class.clone()>
+                                    <a
+                                        href=link
+                                        aria-current=move || is_active.get().then_some("page")
+                                        style=move || is_active.get().then_some(active_style)
+                                        class=move || is_active.get().then(|| active_class.clone())
+                                    >
+                                        {item.icon.as_ref().map(|icon| icon.run())}
+                                        {item.label.to_string()}
+                                    </a>
+                                </li>
+                            }
+                        })
+                        .collect_view()}
+                </ul>
+            </Show>
+        </div>
+    }
+}
+
+/// Renders a bell with an unread badge and a feed of notifications.
+///
+/// The badge counts unread notifications, capped at `badge_max` (`"99+"` by default).
+/// Clicking a notification calls `on_select` and closes the feed; while any is unread, the feed
+/// ends with a "Mark all read" button. Like [`ProfileMenu`], the open state is controlled.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use navbar::core::Notification;
+/// use navbar::leptos::NotificationMenu;
+///
+/// #[component]
+/// fn Bell() -> impl IntoView {
+///     let open = RwSignal::new(false);
+///     let feed = RwSignal::new(vec![Notification::new(1, "Build passed", "2 min ago")]);
+///     view! {
+///         <NotificationMenu
+///             items=feed
+///             is_open=open
+///             toggle=Callback::new(move |_| open.update(|open| *open = !*open))
+///             on_close=Callback::new(move |_| open.set(false))
+///             on_mark_all_read=Callback::new(move |_| {
+///                 feed.update(|feed| feed.iter_mut().for_each(|n| n.read = true))
+///             })
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn NotificationMenu(
+    /// Notifications listed in the feed, newest first.
+    #[prop(into)]
+    items: Signal<Vec<Notification>>,
+    /// Indicates if the feed is currently open.
+    #[prop(into)]
+    is_open: Signal<bool>,
+    /// Callback to toggle the feed.
+    #[prop(into)]
+    toggle: Callback<MouseEvent>,
+    /// Callback to close the feed, e.g. after a click outside of it or a selection.
+    #[prop(optional, into)]
+    on_close: Option<Callback<()>>,
+    /// Called with the notification the user clicked.
+    #[prop(optional, into)]
+    on_select: Option<Callback<Notification>>,
+    /// Called when the user clicks "Mark all read". Shown while a notification is unread.
+    #[prop(optional, into)]
+    on_mark_all_read: Option<Callback<()>>,
+    /// Unread count above which the badge shows `"{badge_max}+"`.
+    #[prop(default = BADGE_MAX)]
+    badge_max: usize,
+    /// Accessible name of the bell button.
+    #[prop(into, default = "Notifications".into())]
+    label: Signal<String>,
+    /// Text of the button that marks every notification as read.
+    #[prop(into, default = "Mark all read".into())]
+    mark_all_read_text: Signal<String>,
+    /// Text shown when the feed is empty.
+    #[prop(into, default = "No notifications".into())]
+    empty_text: Signal<String>,
+    /// Text read out by screen readers before an unread notification.
+    #[prop(into, default = "Unread:".into())]
+    unread_text: Signal<String>,
+    /// Enter and exit transition of the feed.
+    #[prop(default = None)]
+    transition: Option<Transition>,
+    /// Whether a click outside the open feed closes it.
+    #[prop(default = true)]
+    close_on_outside_click: bool,
+    /// Id of the feed, e.g. for the bell's `aria-controls`. Generated if `None`.
+    #[prop(optional, into)]
+    id: Option<String>,
+    /// Style for the wrapper around the bell.
+    #[prop(default = "position: relative; display: inline-block; margin: 0 0 0 1rem;")]
+    wrapper_style: &'static str,
+    /// Style for the bell button.
+    #[prop(
+        default = "position: relative; background: none; border: none; cursor: pointer; color: var(--navbar-fg, black); padding: 0.25rem;"
+    )]
+    button_style: &'static str,
+    /// Style for the unread count badge.
+    #[prop(
+        default = "position: absolute; top: -0.25rem; right: -0.25rem; min-width: 1.25rem; padding: 0 0.25rem; border-radius: 9999px; background: var(--navbar-accent, #007bff); color: var(--navbar-accent-text, white); font-size: 0.75rem; line-height: 1.25rem; text-align: center;"
+    )]
+    badge_style: &'static str,
+    /// Style for the feed container.
+    #[prop(
+        default = "position: absolute; top: 100%; right: 0; min-width: 18rem; margin: 0; padding: 0; list-style: none; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); z-index: 1000;"
+    )]
+    dropdown_style: &'static str,
+    /// Style for each notification.
+    #[prop(
+        default = "display: flex; flex-direction: column; width: 100%; padding: 0.5rem 1rem; background: none; border: none; text-align: left; cursor: pointer; color: inherit;"
+    )]
+    item_style: &'static str,
+    /// Style added to unread notifications.
+    #[prop(default = "font-weight: bold; background: var(--navbar-highlight, #f0f0f0);")]
+    unread_style: &'static str,
+    /// Style for the time of each notification.
+    #[prop(default = "font-size: 0.75rem; font-weight: normal; opacity: 0.7;")]
+    time_style: &'static str,
+    /// Style for the "Mark all read" button and the empty text.
+    #[prop(
+        default = "width: 100%; padding: 0.5rem 1rem; background: none; border: none; border-top: 1px solid var(--navbar-border, #ccc); text-align: center; cursor: pointer; color: var(--navbar-accent, #007bff);"
+    )]
+    footer_style: &'static str,
+    /// Class name for the wrapper element.
+    #[prop(optional, into)]
+    wrapper_class: String,
+    /// Class name for the bell button.
+    #[prop(optional, into)]
+    button_class: String,
+    /// Class name for the badge.
+    #[prop(optional, into)]
+    badge_class: String,
+    /// Class name for the feed container.
+    #[prop(optional, into)]
+    dropdown_class: String,
+    /// Class name for each notification.
+    #[prop(optional, into)]
+    item_class: String,
+    /// Class added to unread notifications.
+    #[prop(optional, into)]
+    unread_class: String,
+    /// Class name for the "Mark all read" button and the empty text.
+    #[prop(optional, into)]
+    footer_class: String,
+) -> impl IntoView {
+    let id = id.unwrap_or_else(|| element_id("notifications"));
+    let on_close = on_close.unwrap_or_else(|| Callback::new(|_| ()));
+    use_click_outside(
+        id.clone(),
+        Signal::derive(move || is_open.get() && close_on_outside_click),
+        on_close,
+    );
+
+    let unread = Memo::new(move |_| items.with(|items| unread_count(items)));
+    let (phase, on_transition_end) = use_transition(is_open, transition.clone());
+    let transition = StoredValue::new(transition.unwrap_or_default());
+    let item_class = StoredValue::new(item_class);
+    let unread_class = StoredValue::new(unread_class);
+    let footer_class = StoredValue::new(footer_class);
+
+    view! {
+        <div style=wrapper_style class=wrapper_class>
+            <button
+                type="button"
+                on:click=move |e| toggle.run(e)
+                aria-controls=id.clone()
+                aria-haspopup="true"
+                aria-expanded=move || is_open.get().to_string()
+                style=button_style
+                class=button_class
+            >
+                <svg
+                    width="24"
+                    height="24"
+                    viewBox="0 0 24 24"
+                    fill="none"
+                    stroke="currentColor"
+                    stroke-width="2"
+                    stroke-linecap="round"
+                    stroke-linejoin="round"
+                    aria-hidden="true"
+                >
+                    <path d=BELL_ICON_PATH />
+                </svg>
+                <span style=VISUALLY_HIDDEN_STYLE>{label}</span>
+                {move || unread_badge(unread.get(), badge_max, badge_style, badge_class.clone())}
+            </button>
+            <Show when=move || phase.get().is_mounted()>
+                <ul
+                    id=id.clone()
+                    style=move || {
+                        transition.with_value(|t| with_phase(dropdown_style, t.style(phase.get())))
+                    }
+                    class={
+                        let dropdown_class = dropdown_class.clone();
+                        move || {
+                            transition
+                                .with_value(|t| with_phase(&dropdown_class, t.class(phase.get())))
+                        }
+                    }
+                    on:transitionend=move |_| on_transition_end.run(())
+                >
+                    <For each=move || items.get() key=|item| (item.id, item.read) let:item>
+                        <li>
+                            <button
+                                type="button"
+                                on:click={
+                                    let item = item.clone();
+                                    move |_| {
+                                        if let Some(on_select) = on_select {
+                                            on_select.run(item.clone());
+                                        }
+                                        on_close.run(());
+                                    }
+                                }
+                                style=with_active(item_style, unread_style, !item.read)
+                                class=with_active(&item_class.get_value(), &unread_class.get_value(), !item.read)
+                            >
+                                {unread_prefix(!item.read, unread_text)}
+                                <span>{item.title.to_string()}</span>
+                                <span style=time_style>{item.time.to_string()}</span>
+                            </button>
+                        </li>
+                    </For>
+                    <Show when=move || items.with(Vec::is_empty)>
+                        <li style=footer_style class=footer_class.get_value()>
+                            {empty_text}
+                        </li>
+                    </Show>
+                    <Show when=move || { unread.get() > 0 }>
+                        <li>
+                            <button
+                                type="button"
+                                on:click=move |_| {
+                                    if let Some(on_mark_all_read) = on_mark_all_read {
+                                        on_mark_all_read.run(());
+                                    }
+                                }
+                                style=footer_style
+                                class=footer_class.get_value()
+                            >
+                                {mark_all_read_text}
+                            </button>
+                        </li>
+                    </Show>
+                </ul>
+            </Show>
+        </div>
+    }
+}
+
+/// Renders the unread count badge, shared by the bell and the mobile notification section.
+fn unread_badge(
+    unread: usize,
+    max: usize,
+    style: &'static str,
+    class: String,
+) -> Option<impl IntoView> {
+    badge_text(unread, max).map(|badge| view! { <span style=style class=class>{badge}</span> })
+}
+
+/// Renders the text read out by screen readers before an unread notification.
+fn unread_prefix(unread: bool, text: Signal<String>) -> Option<impl IntoView> {
+    unread.then(|| view! { <span style=VISUALLY_HIDDEN_STYLE>{text}</span> })
+}
+
+/// Properties for rendering a complex mega menu layout.
+#[component]
+pub fn MegaMenu(
+    /// Items displayed in the mega menu.
+    items: Vec<MegaMenuItem>,
+    /// Style for the mega menu wrapper.
+    #[prop(default = "position: absolute; top: 60px; left: 0; z-index: 999;")]
+    wrapper_style: &'static str,
+    /// Style for the inner card/container element.
+    #[prop(
+        default = "background: var(--navbar-surface, white); display: flex; gap: 2rem; padding: 1rem; box-shadow: 0 2px 8px var(--navbar-shadow, rgba(0,0,0,0.1));"
+    )]
+    card_style: &'static str,
+    /// Style for each link in the mega menu.
+    #[prop(default = "text-decoration: none; color: var(--navbar-fg, black);")]
+    item_link_style: &'static str,
+    /// Optional class for the wrapper.
+    #[prop(optional, into)]
+    wrapper_class: String,
+    /// Optional class for the card.
+    #[prop(optional, into)]
+    card_class: String,
+    /// Optional class for each menu item.
+    #[prop(optional, into)]
+    item_class: String,
+    /// Whether the menu is open. It stays mounted while its exit `transition` runs.
+    #[prop(into, default = true.into())]
+    is_open: Signal<bool>,
+    /// Enter and exit transition of the menu.
+    #[prop(default = None)]
+    transition: Option<Transition>,
+    /// Whether a click outside the open mega menu closes it.
+    #[prop(default = true)]
+    close_on_outside_click: bool,
+    /// Callback to close the mega menu, e.g. after a click outside of it.
+    #[prop(optional, into)]
+    on_close: Option<Callback<()>>,
+    /// Id of the menu, e.g. for the "More" button's `aria-controls`. Generated if `None`.
+    #[prop(optional, into)]
+    id: Option<String>,
+) -> impl IntoView {
+    let id = id.unwrap_or_else(|| element_id("mega-menu"));
+    use_click_outside(
+        id.clone(),
+        Signal::derive(move || is_open.get() && close_on_outside_click),
+        on_close.unwrap_or_else(|| Callback::new(|_| ())),
+    );
+    let (phase, on_transition_end) = use_transition(is_open, transition.clone());
+    let transition = StoredValue::new(transition.unwrap_or_default());
+    let items = StoredValue::new(items);
+
+    view! {
+        <Show when=move || phase.get().is_mounted()>
+            <div
+                id=id.clone()
+                style=move || {
+                    transition.with_value(|t| with_phase(wrapper_style, t.style(phase.get())))
+                }
+                class={
+                    let wrapper_class = wrapper_class.clone();
+                    move || transition.with_value(|t| with_phase(&wrapper_class, t.class(phase.get())))
+                }
+                on:transitionend=move |_| on_transition_end.run(())
+            >
+                <div style=card_style class=card_class.clone()>
+                    {items
+                        .get_value()
+                        .into_iter()
+                        .map(|item| {
+                            view! {
+                                <a href=item.link.to_string() style=item_link_style class=item_class.clone()>
+                                    <div>
+                                        <p>
+                                            <strong>{item.title.to_string()}</strong>
+                                        </p>
+                                        <p>{item.description.to_string()}</p>
+                                    </div>
+                                </a>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+/// Properties for rendering a responsive mobile navigation menu.
+#[component]
+pub fn MobileMenu(
+    /// Menu items to render.
+    menus: Vec<Menu>,
+    /// Style for individual menu items.
+    #[prop(default = "padding: 0.5rem 1rem;")]
+    menu_item_style: &'static str,
+    /// Dropdown items (usually profile-related).
+    dropdown_items: Vec<DropdownItem>,
+    /// Style for the dropdown container.
+    #[prop(
+        default = "background: var(--navbar-surface-muted, #f9f9f9); padding: 1rem; border-radius: 4px;"
+    )]
+    dropdown_style: &'static str,
+    /// Style for each dropdown item.
+    #[prop(default = "padding: 0.5rem;")]
+    dropdown_item_style: &'static str,
+    /// Whether to show profile menu.
+    #[prop(default = false)]
+    show_profile_menu: bool,
+    /// Whether to show the notification section.
+    #[prop(default = false)]
+    show_notifications: bool,
+    /// Notifications listed in the notification section.
+    #[prop(into, default = Vec::new().into())]
+    notifications: Signal<Vec<Notification>>,
+    /// Called with the notification the user clicked.
+    #[prop(default = None)]
+    on_notification_select: Option<Callback<Notification>>,
+    /// Called when the user clicks "Mark all read".
+    #[prop(default = None)]
+    on_mark_all_read: Option<Callback<()>>,
+    /// Heading and accessible name of the notification section.
+    #[prop(into, default = "Notifications".into())]
+    notifications_label: Signal<String>,
+    /// Text of the button that marks every notification as read.
+    #[prop(into, default = "Mark all read".into())]
+    mark_all_read_text: Signal<String>,
+    /// Unread count above which the badge next to the heading shows `"{max}+"`.
+    #[prop(default = BADGE_MAX)]
+    notifications_badge_max: usize,
+    /// Style for the unread count badge next to the heading.
+    #[prop(
+        default = "display: inline-block; min-width: 1.25rem; margin-left: 0.5rem; padding: 0 0.25rem; border-radius: 9999px; background: var(--navbar-accent, #007bff); color: var(--navbar-accent-text, white); font-size: 0.75rem; line-height: 1.25rem; text-align: center;"
+    )]
+    notifications_badge_style: &'static str,
+    /// Optional class for the unread count badge next to the heading.
+    #[prop(optional, into)]
+    notifications_badge_class: String,
+    /// Text read out by screen readers before an unread notification.
+    #[prop(into, default = "Unread:".into())]
+    unread_text: Signal<String>,
+    /// Style for each notification button; unread ones are also bold.
+    #[prop(
+        default = "display: flex; flex-direction: column; width: 100%; padding: 0; background: none; border: none; text-align: left; cursor: pointer; color: inherit;"
+    )]
+    notification_style: &'static str,
+    /// Whether to show search input.
+    #[prop(default = false)]
+    show_search: bool,
+    /// Style for the search input field.
+    #[prop(
+        default = "padding: 0.5rem; width: 100%; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
+    )]
+    search_input_style: &'static str,
+    /// Placeholder text for the search input.
+    #[prop(into, default = "Search...".into())]
+    search_placeholder: Signal<String>,
+    /// Style for the mobile menu container.
+    #[prop(
+        default = "display: flex; flex-direction: column; gap: 1rem; padding: 1rem; position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); z-index: 999; width: max-content;"
+    )]
+    container_style: &'static str,
+    /// Optional class for container.
+    #[prop(optional, into)]
+    container_class: String,
+    /// Optional class for menu items.
+    #[prop(optional, into)]
+    menu_item_class: String,
+    /// Optional class for dropdown.
+    #[prop(optional, into)]
+    dropdown_class: String,
+    /// Optional class for dropdown items.
+    #[prop(optional, into)]
+    dropdown_item_class: String,
+    /// Optional class for search input.
+    #[prop(optional, into)]
+    search_input_class: String,
+    /// Current search text, e.g. the navbar's `search_state`.
+    #[prop(into, default = "".into())]
+    search_value: Signal<String>,
+    /// Callback fired with the search text on every keystroke.
+    #[prop(optional, into)]
+    on_search_input: Option<Callback<String>>,
+    /// Callback fired with the search text on `Enter` or a click on the search button.
+    #[prop(optional, into)]
+    on_search_submit: Option<Callback<String>>,
+    /// Whether to show a submit button next to the search input.
+    #[prop(default = false)]
+    show_search_button: bool,
+    /// Text of the search submit button.
+    #[prop(into, default = "Search".into())]
+    search_button_text: Signal<String>,
+    /// Style for the search submit button.
+    #[prop(
+        default = "padding: 0.5rem 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;"
+    )]
+    search_button_style: &'static str,
+    /// Optional class for the search submit button.
+    #[prop(optional, into)]
+    search_button_class: String,
+    /// Style for the expanded submenu (accordion) of an item with `children`.
+    #[prop(default = "margin: 0; padding-left: 1rem; list-style: none;")]
+    submenu_style: &'static str,
+    /// Optional class for the expanded submenu of an item with `children`.
+    #[prop(optional, into)]
+    submenu_class: String,
+    /// Whether the menu is open. It stays mounted while its exit `transition` runs.
+    #[prop(into, default = true.into())]
+    is_open: Signal<bool>,
+    /// Keeps the menu rendered while closed, for a surrounding `<details>` to show and hide it
+    /// before the app hydrates.
+    #[prop(into, default = false.into())]
+    keep_mounted: Signal<bool>,
+    /// Content rendered at the bottom of the menu.
+    #[prop(default = None)]
+    extra: Option<ViewFn>,
+    /// Id of the menu container, e.g. for the toggle's `aria-controls`. Generated if `None`.
+    #[prop(optional, into)]
+    id: Option<String>,
+    /// Enter and exit transition of the menu.
+    #[prop(default = None)]
+    transition: Option<Transition>,
+    /// Layout of the menu. Drawers and the sheet are modal dialogs over a backdrop.
+    #[prop(optional)]
+    variant: MobileVariant,
+    /// Accessible name of the menu when shown as a modal dialog.
+    #[prop(into, default = "Menu".into())]
+    label: Signal<String>,
+    /// Style of the backdrop behind a drawer or sheet.
+    #[prop(default = MOBILE_BACKDROP_STYLE)]
+    backdrop_style: &'static str,
+    /// Optional class for the backdrop behind a drawer or sheet.
+    #[prop(optional, into)]
+    backdrop_class: String,
+    /// Whether a click outside the open mobile menu closes it.
+    #[prop(default = true)]
+    close_on_outside_click: bool,
+    /// Callback to close the mobile menu, e.g. after a click outside of it.
+    #[prop(optional, into)]
+    on_close: Option<Callback<()>>,
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[prop(optional, into)]
+    current_path: Option<Signal<String>>,
+    /// How item links are matched against the current path.
+    #[prop(optional)]
+    active_match: ActiveMatch,
+    /// Style added to the active item's link, which also gets `aria-current="page"`.
+    #[prop(default = "font-weight: bold;")]
+    active_style: &'static str,
+    /// Optional class added to the active item's link.
+    #[prop(optional, into)]
+    active_class: String,
+) -> impl IntoView {
+    let id = id.unwrap_or_else(|| element_id("mobile-menu"));
+    let on_close = on_close.unwrap_or_else(|| Callback::new(|_| ()));
+    use_click_outside(
+        id.clone(),
+        Signal::derive(move || is_open.get() && close_on_outside_click),
+        on_close,
+    );
+    let (phase, on_transition_end) = use_transition(is_open, transition.clone());
+    let transition = StoredValue::new(transition.unwrap_or_default());
+    let modal = variant.is_modal();
+    let guard = StoredValue::new_local(None::<(FocusTrap, ScrollLock)>);
+    Effect::new({
+        let id = id.clone();
+        move |_| {
+            // Drops the previous trap first, so focus returns before a new one captures it.
+            guard.set_value(None);
+            if modal && is_open.get() && phase.get().is_mounted() {
+                let trap = FocusTrap::new(id.clone(), move || on_close.run(()));
+                guard.set_value(Some((trap, ScrollLock::new())));
+            }
+        }
+    });
+    on_cleanup(move || guard.dispose());
+    let style = with_phase(container_style, variant.panel_style());
+    let menus = StoredValue::new(menus);
+    let dropdown_items = StoredValue::new(dropdown_items);
+    let location = use_location_path();
+    let path =
+        Signal::derive(move || current_path.map_or_else(|| location.get(), |path| path.get()));
+    let active_match = StoredValue::new(active_match);
+    let active =
+        move |link: &str| active_match.with_value(|m| path.with(|path| m.is_active(link, path)));
+
+    view! {
+        <Show when=move || keep_mounted.get() || phase.get().is_mounted()>
+            {modal
+                .then(|| {
+                    view! {
+                        <div
+                            style=backdrop_style
+                            class=backdrop_class.clone()
+                            aria-hidden="true"
+                            on:click=move |_| on_close.run(())
+                        ></div>
+                    }
+                })}
+            <div
+                id=id.clone()
+                role=modal.then_some("dialog")
+                aria-modal=modal.then_some("true")
+                aria-label=move || modal.then(|| label.get())
+                style={
+                    let style = style.clone();
+                    move || transition.with_value(|t| with_phase(&style, t.style(phase.get())))
+                }
+                class={
+                    let container_class = container_class.clone();
+                    move || transition.with_value(|t| with_phase(&container_class, t.class(phase.get())))
+                }
+                on:transitionend=move |_| on_transition_end.run(())
+            >
+                <NavbarMenu
+                    menus=menus.get_value()
+                    menu_item_style=menu_item_style
+                    menu_item_class=menu_item_class.clone()
+                    list_style=""
+                    submenu_style=submenu_style
+                    submenu_class=submenu_class.clone()
+                    accordion=true
+                    current_path=path
+                    active_match=active_match.get_value()
+                    active_style=active_style
+                    active_class=active_class.clone()
+                />
+                {show_search
+                    .then(|| {
+                        view! {
+                            <NavbarSearch
+                                value=search_value
+                                placeholder=search_placeholder
+                                input_style=search_input_style
+                                input_class=search_input_class.clone()
+                                on_input=on_search_input.unwrap_or_else(|| Callback::new(|_| ()))
+                                on_submit=on_search_submit.unwrap_or_else(|| Callback::new(|_| ()))
+                                show_button=show_search_button
+                                button_text=search_button_text
+                                button_style=search_button_style
+                                button_class=search_button_class.clone()
+                            />
+                        }
+                    })}
+                {show_profile_menu
+                    .then(|| {
+                        view! {
+                            <ul style=dropdown_style class=dropdown_class.clone()>
+                                {dropdown_items
+                                    .get_value()
+                                    .into_iter()
+                                    .map(|item| {
+                                        let is_active = Signal::derive({
+                                            let link = item.link.clone();
+                                            move || active(&link)
+                                        });
+                                        let link = item.link.to_string();
+                                        let active_class = active_class.clone();
+                                        view! {
+                                            <li
+                                                style=dropdown_item_style
+                                                class=dropdown_item_class.clone()
+                                            >
+                                                <a
+                                                    href=link
+                                                    aria-current=move || is_active.get().then_some("page")
+                                                    style=move || is_active.get().then_some(active_style)
+                                                    class=move || is_active.get().then(|| active_class.clone())
+                                                >
+                                                    {item.label.to_string()}
+                                                </a>
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                    })}
+                {show_notifications
+                    .then(|| {
+                        let item_class = dropdown_item_class.clone();
+                        let action_class = dropdown_item_class.clone();
+                        let badge_class = notifications_badge_class.clone();
+                        view! {
+                            <section aria-label=notifications_label>
+                                <p style="margin: 0; padding: 0.5rem; font-weight: bold;">
+                                    {notifications_label}
+                                    {move || {
+                                        unread_badge(
+                                            notifications.with(|items| unread_count(items)),
+                                            notifications_badge_max,
+                                            notifications_badge_style,
+                                            badge_class.clone(),
+                                        )
+                                    }}
+                                </p>
+                                <ul style=dropdown_style class=dropdown_class.clone()>
+                                    <For
+                                        each=move || notifications.get()
+                                        key=|item| (item.id, item.read)
+                                        let:item
+                                    >
+                                        <li style=dropdown_item_style class=item_class.clone()>
+                                            <button
+                                                type="button"
+                                                style=with_active(notification_style, "font-weight: bold;", !item.read)
+                                                on:click={
+                                                    let item = item.clone();
+                                                    move |_| {
+                                                        if let Some(on_select) = on_notification_select {
+                                                            on_select.run(item.clone());
+                                                        }
+                                                    }
+                                                }
+                                            >
+                                                {unread_prefix(!item.read, unread_text)}
+                                                <span>{item.title.to_string()}</span>
+                                                <small>{item.time.to_string()}</small>
+                                            </button>
+                                        </li>
+                                    </For>
+                                    <Show when=move || notifications.with(|items| unread_count(items) > 0)>
+                                        <li style=dropdown_item_style class=action_class.clone()>
+                                            <button
+                                                type="button"
+                                                style=notification_style
+                                                on:click=move |_| {
+                                                    if let Some(on_mark_all_read) = on_mark_all_read {
+                                                        on_mark_all_read.run(());
+                                                    }
+                                                }
+                                            >
+                                                {mark_all_read_text}
+                                            </button>
+                                        </li>
+                                    </Show>
+                                </ul>
+                            </section>
+                        }
+                    })}
+                {extra.clone().map(|extra| extra.run())}
+            </div>
+        </Show>
+    }
+}
+
+/// Tracks the viewport against a mobile breakpoint and a set of named breakpoints.
+///
+/// The returned signal is `None` outside a browser. The viewport is observed with `matchMedia`
+/// change events (falling back to `resize`), coalesced to one update per animation frame, so the
+/// signal only changes when a breakpoint or the mobile layout is crossed; `width` is the viewport
+/// width at the last crossing. Listeners are removed when the owning reactive scope is cleaned up.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use navbar::core::{Breakpoint, Breakpoints};
+/// use navbar::leptos::use_viewport;
+///
+/// #[component]
+/// fn Sidebar() -> impl IntoView {
+///     let viewport = use_viewport(1024.0, Breakpoints::default());
+///     let wide = move || viewport.get().is_some_and(|v| v.breakpoint >= Breakpoint::Lg);
+///     view! { <Show when=wide><aside>"Filters"</aside></Show> }
+/// }
+/// ```
+pub fn use_viewport(
+    mobile_breakpoint: f64,
+    breakpoints: Breakpoints,
+) -> ReadSignal<Option<Viewport>> {
+    let initial = viewport_width().map(|w| breakpoints.viewport(w, mobile_breakpoint));
+    let (viewport, set_viewport) = signal(initial);
+
+    let mut tracker = BreakpointTracker::default();
+    if let Some(initial) = initial {
+        tracker.observe(initial);
+    }
+    let observer =
+        ViewportObserver::new(&breakpoints.boundaries(mobile_breakpoint), move |width| {
+            if let Some(next) = tracker.observe(breakpoints.viewport(width, mobile_breakpoint)) {
+                set_viewport.set(Some(next));
+            }
+        });
+    let observer = StoredValue::new_local(observer);
+    on_cleanup(move || observer.dispose());
+
+    viewport
+}
+
+/// Tracks the page scroll for a [`ScrollBehavior`].
+///
+/// The signal holds whether the navbar should be hidden ([`ScrollBehavior::HideOnScroll`]) or
+/// scrolled ([`ScrollBehavior::Shrink`]); it stays at its default for untracked behaviors.
+/// Scrolling is observed with a passive listener, coalesced to one update per animation frame,
+/// and the signal only changes with the state. The listener is removed when the owning reactive
+/// scope is cleaned up.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use navbar::core::ScrollBehavior;
+/// use navbar::leptos::use_scroll_state;
+///
+/// #[component]
+/// fn BackToTop() -> impl IntoView {
+///     let scroll = use_scroll_state(ScrollBehavior::Shrink(400.0));
+///     view! { <Show when=move || scroll.get().scrolled><a href="#top">"Back to top"</a></Show> }
+/// }
+/// ```
+pub fn use_scroll_state(behavior: ScrollBehavior) -> ReadSignal<ScrollState> {
+    let (state, set_state) = signal(ScrollState::default());
+
+    let observer = behavior.is_tracked().then(|| {
+        let mut tracker = ScrollTracker::new(behavior);
+        ScrollObserver::new(move |y| {
+            let next = tracker.observe(y);
+            if state.get_untracked() != next {
+                set_state.set(next);
+            }
+        })
+    });
+    let observer = StoredValue::new_local(observer);
+    on_cleanup(move || observer.dispose());
+
+    state
+}
+
+/// Drives the enter and exit transitions of an element shown while `show` is `true`.
+///
+/// The returned signal holds the element's [`TransitionPhase`]: render it while
+/// [`TransitionPhase::is_mounted`], with [`Transition::style`]/[`Transition::class`] of that phase,
+/// and run the returned callback on its `transitionend`. Exiting elements unmount on
+/// `transitionend`, or after [`Transition::timeout_ms`] at the latest. Without a transition, or
+/// when the user prefers reduced motion, the element mounts and unmounts with `show`.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use navbar::core::Transition;
+/// use navbar::leptos::use_transition;
+///
+/// #[component]
+/// fn Toast(#[prop(into)] show: Signal<bool>) -> impl IntoView {
+///     let fade = StoredValue::new(Transition::fade(200));
+///     let (phase, on_end) = use_transition(show, Some(fade.get_value()));
+///     view! {
+///         <Show when=move || phase.get().is_mounted()>
+///             <div
+///                 style=move || fade.with_value(|fade| fade.style(phase.get()).to_string())
+///                 on:transitionend=move |_| on_end.run(())
+///             >
+///                 "Saved"
+///             </div>
+///         </Show>
+///     }
+/// }
+/// ```
+pub fn use_transition(
+    show: impl Into<Signal<bool>>,
+    transition: Option<Transition>,
+) -> (ReadSignal<TransitionPhase>, Callback<()>) {
+    let show = show.into();
+    let animate = transition.is_some() && !prefers_reduced_motion();
+    let timeout_ms = transition.map_or(0, |transition| transition.timeout_ms);
+    let (phase, set_phase) = signal(TransitionPhase::initial(show.get_untracked()));
+    let timer = StoredValue::new_local(None::<PhaseTimer>);
+
+    Effect::new(move |_| {
+        let current = phase.get_untracked();
+        let next = if show.get() {
+            current.show(animate)
+        } else {
+            current.hide(animate)
+        };
+        if next != current {
+            set_phase.set(next);
+        }
+    });
+    Effect::new(move |_| {
+        let current = phase.get();
+        let next = PhaseTimer::new(current, timeout_ms, move || {
+            set_phase.set(current.advance())
+        });
+        timer.set_value(Some(next));
+    });
+    on_cleanup(move || timer.dispose());
+
+    let on_end = Callback::new(move |_| {
+        if phase.get_untracked() == TransitionPhase::Exit {
+            set_phase.set(TransitionPhase::Exited);
+        }
+    });
+    (phase, on_end)
+}
+
+/// Calls `on_outside` whenever a click lands outside the element with the given `id`.
+///
+/// This is the dismissal mechanism behind every navbar popup. The document listener is only
+/// attached while `enabled` is `true`, and is removed when it turns `false` or the owning
+/// reactive scope is cleaned up.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use navbar::leptos::use_click_outside;
+///
+/// #[component]
+/// fn Popover() -> impl IntoView {
+///     let open = RwSignal::new(true);
+///     use_click_outside("popover".to_string(), open, Callback::new(move |_| open.set(false)));
+///     view! { <Show when=move || open.get()><div id="popover">"Hello"</div></Show> }
+/// }
+/// ```
+pub fn use_click_outside(id: String, enabled: impl Into<Signal<bool>>, on_outside: Callback<()>) {
+    let enabled = enabled.into();
+    let listener = StoredValue::new_local(None::<OutsideClickListener>);
+
+    Effect::new(move |_| {
+        let next = enabled
+            .get()
+            .then(|| OutsideClickListener::new(id.clone(), move || on_outside.run(())));
+        listener.set_value(next);
+    });
+    on_cleanup(move || listener.dispose());
+}
+
+/// Returns the current `window.location.pathname`, updated on history navigation (`popstate`).
+///
+/// The signal holds an empty path outside a browser. Routers that navigate with `pushState` do
+/// not fire `popstate`; pass their current path through the `current_path` props instead.
+///
+/// # Example
+/// ```rust
+/// use leptos::prelude::*;
+/// use navbar::leptos::use_location_path;
+///
+/// #[component]
+/// fn Breadcrumb() -> impl IntoView {
+///     let path = use_location_path();
+///     view! { <span>{path}</span> }
+/// }
+/// ```
+pub fn use_location_path() -> ReadSignal<String> {
+    let (path, set_path) = signal(location_path().unwrap_or_default());
+    let listener = StoredValue::new_local(watch_location(move |next| set_path.set(next)));
+    on_cleanup(move || listener.dispose());
+    path
+}
+
+/// Default style of the backdrop behind a mobile drawer or sheet.
+const MOBILE_BACKDROP_STYLE: &str = "position: fixed; inset: 0; z-index: 1000; background: var(--navbar-backdrop, rgba(0,0,0,0.4));";
+
+/// Returns an element id that is unique to this component instance.
+///
+/// Under a [`Navbar`], the id comes from its `id_prefix` and stays the same between a server
+/// render and hydration.
+fn element_id(name: &str) -> String {
+    crate::dom::element_id(use_context::<IdScope>().as_ref(), name)
+}
+
+/// Returns `base` followed by the style or class of a transition phase, if any.
+fn with_phase(base: &str, extra: &str) -> String {
+    with_active(base, extra, !extra.is_empty())
+}
+
+/// Returns `base` with `extra` appended when `active` is `true`.
+fn with_active(base: &str, extra: &str, active: bool) -> String {
+    if active {
+        format!("{base} {extra}")
+    } else {
+        base.to_string()
+    }
+}
+
+/// Navbar Component
+///
+/// A responsive and reactive navigation bar component built with Leptos, supporting dropdowns,
+/// mega menus, mobile toggles, profile menus, and search inputs. All open/close state is held in
+/// signals, so only the affected parts of the view update.
+///
+/// # Features
+/// - **Responsive Behavior**:
+///   Adapts based on screen width through [`use_viewport`], which uses `matchMedia` change events
+///   throttled to animation frames and removes its listeners on cleanup.
+///   - Mobile breakpoint: `<= mobile_breakpoint` (768px by default)
+///   - `on_breakpoint_change` reports named breakpoint (`sm`/`md`/`lg`/`xl`) and layout changes
+///   - `initial_layout` sets the layout rendered on the server and on the hydrating render,
+///     before the viewport is measured, and element ids start with `id_prefix`, so both renders
+///     produce the same markup
+///   - `css_responsive` renders both layouts and switches between them with media queries, with
+///     a `<details>` disclosure for the mobile menu until the app hydrates
+///
+/// - **Scroll Behavior**:
+///   `scroll_behavior` keeps the navbar static (default) or sticky, hides it while scrolling down
+///   and reveals it on the way up, or adds `scrolled_style`/`scrolled_class` past a threshold.
+///   Scrolling is tracked through [`use_scroll_state`], with a passive listener throttled to
+///   animation frames that is removed on cleanup
+///
+/// - **Theming**:
+///   `theme` sets the navbar colors as CSS custom properties on the `<nav>`, which every default
+///   style refers to; see [`NavbarTheme`]. `show_theme_toggle` adds a light/dark/system
+///   [`ThemeToggle`] that switches the navbar to `dark_theme`, marks `<html>` with `data-theme`
+///   and remembers the choice
+///
+/// - **Transitions**:
+///   `mobile_menu_transition`, `dropdown_transition`, `mega_menu_transition` and
+///   `notifications_transition` animate the menus in and out, e.g. with [`Transition::fade`] or
+///   [`Transition::slide_down`]. Closed menus stay mounted until their exit transition ends;
+///   motion is skipped when the user prefers reduced motion. See [`use_transition`]
+///
+/// - **Dropdown & Mega Menu**:
+///   - Profile dropdown toggled on click
+///   - Mega menu shown on hover with mouse enter/leave detection
+///
+/// - **Active Route**:
+///   The item whose `link` matches the current path (`active_match`: exact, prefix or custom) gets
+///   `aria-current="page"` and `active_style`/`active_class`. The path defaults to
+///   `window.location.pathname`; pass `current_path` when using a router.
+///
+/// - **Search Support**:
+///   A customizable search input bound to a local signal, or to an external `RwSignal<String>`
+///   passed through `search_state`, and shared by the desktop and mobile layouts.
+///   `on_search_submit` fires on `Enter` (or the optional search button), and `on_search_change`
+///   fires once typing pauses for `search_debounce_ms`.
+///
+/// - **Mobile Toggle Menu**:
+///   Displays a dropdown menu when the hamburger is clicked on smaller screens, or with
+///   `mobile_variant` a left or right drawer or a full-screen sheet over a backdrop, with focus
+///   trapped inside and the page scroll locked while it is open. The hamburger is a button with
+///   `aria-expanded` and `aria-controls` bound to the menu; `menu_toggle_animation` morphs its
+///   icon into an X or an arrow
+///
+/// - **CTA Button**:
+///   Optional call-to-action button next to the menu
+///
+/// - **Notifications**:
+///   With `show_notifications`, a bell with an unread badge (capped at `"99+"`) opens a
+///   [`NotificationMenu`] listing `notifications`, with "Mark all read" and per-item callbacks.
+///   It also shows as a section of the mobile menu
+///
+/// - **Custom Layout**:
+///   `start`, `center` and `end` slots take arbitrary markup around the built-in parts, and
+///   `mobile_extra` goes at the bottom of the mobile menu. `parts` orders the built-in parts
+///   ([`NavbarPart`]); a part left out is hidden
+///
+/// - **Event Management**:
+///   Clicking outside the mobile menu, profile dropdown or mega menu closes it (see
+///   [`use_click_outside`]). All listeners are removed when the component is unmounted.
+///
+/// # Examples
+///
+/// ## Basic Navbar
+/// ```rust
+/// use leptos::prelude::*;
+/// use navbar::leptos::{Menu, Navbar};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Navbar menus=vec![
+///             Menu::new(1, "Home", "/"),
+///         ] />
+///     }
+/// }
+/// ```
+///
+/// ## Navbar with Search and Profile
+/// ```rust
+/// use leptos::prelude::*;
+/// use navbar::leptos::{Menu, DropdownItem, Navbar};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let search = RwSignal::new(String::new());
+///     view! {
+///         <Navbar
+///             show_search=true
+///             search_state=search
+///             search_placeholder="Search here..."
+///             show_profile_menu=true
+///             profile_button_text="User"
+///             dropdown_items=vec![
+///                 DropdownItem::new(1, "Account", "/account"),
+///                 DropdownItem::new(2, "Logout", "/logout"),
+///             ]
+///             menus=vec![
+///                 Menu::new(1, "Dashboard", "/"),
+///             ]
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Mega Menu Navbar
+/// ```rust
+/// use leptos::prelude::*;
+/// use navbar::leptos::{Menu, MegaMenuItem, Navbar};
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Navbar
+///             show_mega_menu=true
+///             more_button_text="Explore"
+///             mega_menu_items=vec![
+///                 MegaMenuItem::new("Docs", "Technical docs", "/docs"),
+///                 MegaMenuItem::new("Blog", "Latest updates", "/blog"),
+///             ]
+///             menus=vec![
+///                 Menu::new(1, "Platform", "/"),
+///             ]
+///         />
+///     }
+/// }
+/// ```
+///
+/// # Notes
+/// - Open/close behavior is driven by the shared [`NavbarState`] machine held in an `RwSignal`.
+/// - Search uses an `RwSignal<String>` for reactive state, created locally unless `search_state` is set.
+/// - The component is composed of subcomponents: `NavbarMenu`, `MegaMenu`, `ProfileMenu`, etc.
+/// - All sub-styles and class props allow fine-grained CSS control.
+///
+/// # See Also
+/// - [MDN `<nav>` Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/nav)
+#[component]
+pub fn Navbar(
+    /// Top-level menu items for the main navigation bar.
+    #[prop(optional)]
+    menus: Vec<Menu>,
+
+    /// Items for the profile dropdown menu.
+    #[prop(optional)]
+    dropdown_items: Vec<DropdownItem>,
+
+    /// Items shown in a mega menu panel.
+    #[prop(optional)]
+    mega_menu_items: Vec<MegaMenuItem>,
+
+    /// Whether to display the search input field.
+    #[prop(optional)]
+    show_search: bool,
+
+    /// Whether to show the mega menu.
+    #[prop(optional)]
+    show_mega_menu: bool,
+
+    /// Whether to show the profile menu (avatar or dropdown).
+    #[prop(optional)]
+    show_profile_menu: bool,
+
+    /// Text for the main call-to-action button.
+    #[prop(into, default = "Sign up".into())]
+    button_text: Signal<String>,
+
+    /// Href for the main call-to-action button.
+    #[prop(into, default = "#".into())]
+    button_href: Signal<String>,
+
+    /// Source path for the logo image.
+    #[prop(into, default = "/assets/logo.webp".into())]
+    logo_src: Signal<String>,
+
+    /// Alternative text for the logo image.
+    #[prop(into, default = "logo".into())]
+    logo_alt: Signal<String>,
+
+    /// Link the logo should redirect to when clicked.
+    #[prop(into, default = "/".into())]
+    logo_link: Signal<String>,
+
+    /// Target attribute for the CTA button link.
+    #[prop(into, default = "_blank".into())]
+    button_target: Signal<String>,
+
+    /// Placeholder text for the search input.
+    #[prop(into, default = "Search...".into())]
+    search_placeholder: Signal<String>,
+
+    /// Text label for the profile menu button/avatar.
+    #[prop(into, default = "Profile".into())]
+    profile_button_text: Signal<String>,
+
+    /// Accessible label (`aria-label`) of the hamburger toggle button.
+    #[prop(into, default = "Toggle Menu".into())]
+    menu_toggle_label: Signal<String>,
+
+    // Notification props
+    /// Whether to show the notification bell and its feed.
+    #[prop(optional)]
+    show_notifications: bool,
+
+    /// Notifications listed in the feed, newest first.
+    #[prop(into, default = Vec::new().into())]
+    notifications: Signal<Vec<Notification>>,
+
+    /// Called with the notification the user clicked.
+    #[prop(optional, into)]
+    on_notification_select: Option<Callback<Notification>>,
+
+    /// Called when the user clicks "Mark all read".
+    #[prop(optional, into)]
+    on_mark_all_read: Option<Callback<()>>,
+
+    /// Unread count above which the badge shows `"{notifications_badge_max}+"`.
+    #[prop(default = BADGE_MAX)]
+    notifications_badge_max: usize,
+
+    /// Accessible name of the bell, and of the notification section of the mobile menu.
+    #[prop(into, default = "Notifications".into())]
+    notifications_label: Signal<String>,
+
+    /// Text of the button that marks every notification as read.
+    #[prop(into, default = "Mark all read".into())]
+    mark_all_read_text: Signal<String>,
+
+    // Layout props
+    /// Content rendered right after the logo, e.g. a workspace switcher.
+    #[prop(optional, into)]
+    start: Option<ViewFn>,
+
+    /// Content rendered between the `start` slot and the built-in parts, e.g. a page title.
+    #[prop(optional, into)]
+    center: Option<ViewFn>,
+
+    /// Content rendered after the built-in parts, on desktop and mobile, e.g. a cart icon.
+    #[prop(optional, into)]
+    end: Option<ViewFn>,
+
+    /// Content rendered at the bottom of the mobile menu.
+    #[prop(optional, into)]
+    mobile_extra: Option<ViewFn>,
+
+    /// Built-in parts of the desktop layout, in order. A part left out is hidden, on mobile too.
+    #[prop(default = NavbarPart::DEFAULT_ORDER.to_vec())]
+    parts: Vec<NavbarPart>,
+
+    // Responsive props
+    /// Viewport width, in pixels, at or below which the mobile layout is used.
+    #[prop(default = MOBILE_BREAKPOINT)]
+    mobile_breakpoint: f64,
+
+    /// Named breakpoint widths (`sm`, `md`, `lg`, `xl`) reported through `on_breakpoint_change`.
+    #[prop(optional)]
+    breakpoints: Breakpoints,
+
+    /// Called on mount and whenever the viewport crosses a named breakpoint or the mobile breakpoint.
+    #[prop(optional, into)]
+    on_breakpoint_change: Option<Callback<Viewport>>,
+
+    /// Layout of the first render, before the viewport is measured: the measured viewport (the
+    /// default), desktop, mobile, or a guess from the `User-Agent` header. Set it when server
+    /// rendering so the hydrating render matches the server markup.
+    #[prop(optional)]
+    initial_layout: InitialLayout,
+
+    /// Prefix of the element ids of the navbar and its menus, e.g. `navbar-mobile-menu`. Ids
+    /// don't depend on anything rendered before, so they match between a server render and
+    /// hydration; give each navbar on a page its own prefix.
+    #[prop(into, default = "navbar".to_string())]
+    id_prefix: String,
+
+    /// Renders both the desktop and the mobile layout and shows one of them with media queries
+    /// (see [`responsive_css`]), so the right one is painted before the app hydrates, or without
+    /// JavaScript at all. The hamburger toggles a `<details>` until hydration takes over.
+    #[prop(optional)]
+    css_responsive: bool,
+
+    // Scroll props
+    /// How the navbar reacts to the page scrolling: static (the default), sticky, hidden while
+    /// scrolling down, or shrunk past a threshold.
+    #[prop(optional)]
+    scroll_behavior: ScrollBehavior,
+
+    /// Style added to the `<nav>` once the page is scrolled past the [`ScrollBehavior::Shrink`]
+    /// threshold.
+    #[prop(
+        default = "padding-top: 0.5rem; padding-bottom: 0.5rem; box-shadow: 0 2px 4px var(--navbar-shadow, rgba(0,0,0,0.1));"
+    )]
+    scrolled_style: &'static str,
+
+    // Dismissal props
+    /// Whether a click outside the open mobile menu closes it.
+    #[prop(default = true)]
+    mobile_menu_close_on_outside_click: bool,
+
+    /// Whether a click outside the open profile dropdown closes it.
+    #[prop(default = true)]
+    dropdown_close_on_outside_click: bool,
+
+    /// Whether a click outside the open mega menu closes it.
+    #[prop(default = true)]
+    mega_menu_close_on_outside_click: bool,
+
+    /// Whether a click outside the open notification feed closes it.
+    #[prop(default = true)]
+    notifications_close_on_outside_click: bool,
+
+    // Transition props
+    /// Enter and exit transition of the mobile menu, e.g. [`Transition::slide_down`].
+    ///
+    /// Without one, the menu mounts and unmounts at once, except for the drawers and the sheet
+    /// of `mobile_variant`, which slide in.
+    #[prop(optional)]
+    mobile_menu_transition: Option<Transition>,
+
+    /// Enter and exit transition of the profile dropdown list.
+    #[prop(optional)]
+    dropdown_transition: Option<Transition>,
+
+    /// Enter and exit transition of the mega menu.
+    #[prop(optional)]
+    mega_menu_transition: Option<Transition>,
+
+    /// Enter and exit transition of the notification feed.
+    #[prop(optional)]
+    notifications_transition: Option<Transition>,
+
+    // Mobile menu props
+    /// Layout of the mobile menu: a dropdown under the toggle (the default), a left or right
+    /// drawer, or a full-screen sheet.
+    ///
+    /// Drawers and the sheet open over a backdrop, keep focus inside the menu and lock the page
+    /// scroll while open; `Escape` or a click on the backdrop closes them.
+    #[prop(optional)]
+    mobile_variant: MobileVariant,
+
+    /// Style of the backdrop behind a drawer or sheet.
+    #[prop(default = MOBILE_BACKDROP_STYLE)]
+    mobile_backdrop_style: &'static str,
+
+    /// Class of the backdrop behind a drawer or sheet.
+    #[prop(default = "")]
+    mobile_backdrop_class: &'static str,
+
+    // Theme props
+    /// Colors of the navbar, set as CSS custom properties on the `<nav>` and used by every
+    /// default style: [`NavbarTheme::light`] (the default), [`NavbarTheme::dark`] or a custom
+    /// theme.
+    #[prop(optional, into)]
+    theme: Signal<NavbarTheme>,
+
+    /// Colors used instead of `theme` while the theme toggle applies the dark theme.
+    #[prop(into, default = Signal::derive(NavbarTheme::dark))]
+    dark_theme: Signal<NavbarTheme>,
+
+    /// Shows a [`ThemeToggle`] (light, dark or system) at `NavbarPart::ThemeToggle` in `parts`,
+    /// last by default; the mobile layout shows it after `end`.
+    #[prop(optional)]
+    show_theme_toggle: bool,
+
+    /// `localStorage` key the toggle saves the picked mode under; empty to keep it in memory only.
+    #[prop(into, default = "navbar-theme".into())]
+    theme_storage_key: String,
+
+    /// Callback fired with the toggle's preference on mount and whenever the applied theme may
+    /// change.
+    #[prop(optional, into)]
+    on_theme_change: Option<Callback<ThemePreference>>,
+
+    // Active route props
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[prop(optional, into)]
+    current_path: Option<Signal<String>>,
+
+    /// How item links are matched against the current path.
+    #[prop(optional)]
+    active_match: ActiveMatch,
+
+    /// Style added to the link of the active menu, mobile menu and dropdown item.
+    #[prop(default = "font-weight: bold;")]
+    active_style: &'static str,
+
+    /// CSS class added to the link of the active menu, mobile menu and dropdown item.
+    #[prop(optional)]
+    active_class: &'static str,
+
+    // Styles
+    /// Style for the main navbar container.
+    #[prop(
+        default = "display: flex; align-items: center; justify-content: space-between; padding: 1rem; background-color: var(--navbar-bg, #fff); color: var(--navbar-fg, black);"
+    )]
+    navbar_style: &'static str,
+
+    /// Style for the inner container within the navbar.
+    #[prop(default = "display: flex; align-items: center; width: 100%;")]
+    inner_style: &'static str,
+
+    /// Style for the maximum width container wrapping all navbar elements.
+    #[prop(
+        default = "max-width: 1200px; margin: auto; display: flex; width: 100%; align-items: center; justify-content: space-between;"
+    )]
+    container_style: &'static str,
+
+    /// Style applied to the logo image.
+    #[prop(default = "height: 40px;")]
+    logo_style: &'static str,
+
+    /// Style for the hamburger menu button.
+    #[prop(
+        default = "display: flex; flex-direction: column; gap: 5px; cursor: pointer; background: none; border: none; padding: 0;"
+    )]
+    menu_toggle_style: &'static str,
+
+    /// How the hamburger icon animates when the mobile menu opens: not at all (the default),
+    /// morphing into an X, spinning into an X, or folding into an arrow.
+    #[prop(optional)]
+    menu_toggle_animation: ToggleAnimation,
+
+    /// Style for the lines in the hamburger icon.
+    #[prop(default = "width: 25px; height: 2px; background: var(--navbar-fg, black);")]
+    line_style: &'static str,
+
+    /// Style applied to the `<ul>` navigation container.
+    #[prop(default = "display: flex; gap: 1rem; list-style: none; margin: 0; padding: 0;")]
+    nav_style: &'static str,
+
+    /// Style for individual `<li>` items in the navbar.
+    #[prop(
+        default = "text-decoration: none; color: var(--navbar-fg, black); padding: 0.5rem 1rem; transition: background 0.3s ease;"
+    )]
+    menu_item_style: &'static str,
+
+    /// Style for the dropdown menu panel.
+    #[prop(
+        default = "position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); z-index: 1000;"
+    )]
+    dropdown_style: &'static str,
+
+    /// Style for the flyout of a menu item with `children`.
+    ///
+    /// Nested flyouts open beside their parent item instead of below it.
+    #[prop(default = SUBMENU_STYLE)]
+    submenu_style: &'static str,
+
+    /// Style for each dropdown menu item.
+    #[prop(default = "padding: 0.5rem 1rem; white-space: nowrap;")]
+    dropdown_item_style: &'static str,
+
+    /// Style applied to the search input field.
+    #[prop(
+        default = "padding: 0.5rem; font-size: 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
+    )]
+    search_input_style: &'static str,
+
+    /// Style for the CTA button wrapper.
+    #[prop(default = "margin-left: 1rem; white-space: nowrap;")]
+    button_style: &'static str,
+
+    /// Style for the `<a>` link inside the CTA button.
+    #[prop(
+        default = "text-decoration: none; color: var(--navbar-accent-text, white); background: var(--navbar-accent, #007bff); padding: 0.5rem 1rem; border-radius: 4px;"
+    )]
+    button_link_style: &'static str,
+
+    /// Style for the mega menu dropdown.
+    #[prop(
+        default = "position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); padding: 0; margin-top: 0.5rem; z-index: 1000;"
+    )]
+    mega_menu_style: &'static str,
+
+    /// Style for each card/item in the mega menu.
+    #[prop(
+        default = "background: var(--navbar-surface, white); display: flex; gap: 2rem; padding: 1rem; box-shadow: 0 2px 8px var(--navbar-shadow, rgba(0,0,0,0.1));"
+    )]
+    mega_menu_card_style: &'static str,
+
+    /// Text for the "More" button shown in the navbar.
+    #[prop(into, default = "More".into())]
+    more_button_text: Signal<String>,
+
+    /// Style applied to the "More" button element.
+    #[prop(default = "background: transparent; border: none; cursor: pointer; font-weight: bold;")]
+    more_button_style: &'static str,
+
+    // State and image props
+    /// Optional external state for the search input value.
+    #[prop(optional)]
+    search_state: Option<RwSignal<String>>,
+
+    /// Callback fired with the search text on `Enter` or a click on the search button.
+    #[prop(optional, into)]
+    on_search_submit: Option<Callback<String>>,
+
+    /// Callback fired with the search text once typing pauses for `search_debounce_ms`.
+    #[prop(optional, into)]
+    on_search_change: Option<Callback<String>>,
+
+    /// Delay (ms) after the last keystroke before `on_search_change` fires.
+    #[prop(default = 300)]
+    search_debounce_ms: u32,
+
+    /// Whether to show a submit button next to the search input.
+    #[prop(default = false)]
+    show_search_button: bool,
+
+    /// Text of the search submit button.
+    #[prop(into, default = "Search".into())]
+    search_button_text: Signal<String>,
+
+    /// Style applied to the search submit button.
+    #[prop(
+        default = "padding: 0.5rem 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;"
+    )]
+    search_button_style: &'static str,
+
+    /// Optional profile image URL for the avatar in the navbar.
+    #[prop(optional)]
+    profile_image_url: Option<String>,
+
+    /// CSS class for the outer `<nav>` element of the navbar.
+    ///
+    /// Allows customization of the outermost wrapper of the navbar. Defaults to an empty string.
+    #[prop(optional)]
+    navbar_class: &'static str,
+
+    /// CSS class added to the `<nav>` once the page is scrolled past the
+    /// [`ScrollBehavior::Shrink`] threshold.
+    #[prop(optional)]
+    scrolled_class: &'static str,
+
+    /// CSS class for the inner wrapper of the navbar content.
+    ///
+    /// Used to apply styles to the inner layout container that holds all navbar elements.
+    #[prop(optional)]
+    inner_class: &'static str,
+
+    /// CSS class for the container that constrains navbar width.
+    ///
+    /// Useful for centering and limiting the maximum width of navbar contents.
+    #[prop(optional)]
+    container_class: &'static str,
+
+    /// CSS class for the logo image element.
+    ///
+    /// Use this to style the logo, such as sizing or spacing.
+    #[prop(optional)]
+    logo_class: &'static str,
+
+    /// CSS class for the menu toggle button (commonly the hamburger icon).
+    ///
+    /// Used in responsive design for toggling the navigation menu on smaller screens.
+    #[prop(optional)]
+    menu_toggle_class: &'static str,
+
+    /// CSS class for individual lines inside the hamburger toggle button.
+    ///
+    /// Typically used to style each bar in the toggle icon.
+    #[prop(optional)]
+    line_class: &'static str,
+
+    /// CSS class for the navigation list element.
+    ///
+    /// Styles the `<ul>` or equivalent container that holds menu items.
+    #[prop(optional)]
+    nav_class: &'static str,
+
+    /// CSS class for individual menu item links.
+    ///
+    /// Use this to style each navigation link (e.g., padding, hover effects).
+    #[prop(optional)]
+    menu_item_class: &'static str,
+
+    /// CSS class for the dropdown menu container.
+    ///
+    /// This class applies to the container holding dropdown items below a menu.
+    #[prop(optional)]
+    dropdown_class: &'static str,
+
+    /// CSS class for the submenu of a menu item with `children`.
+    ///
+    /// Applies to desktop flyouts and mobile accordions, at every level.
+    #[prop(optional)]
+    submenu_class: &'static str,
+
+    /// CSS class for individual dropdown menu items.
+    ///
+    /// Used to style each item within a dropdown menu.
+    #[prop(optional)]
+    dropdown_item_class: &'static str,
+
+    /// CSS class for the search input element.
+    ///
+    /// Applies styles to the optional search bar input field.
+    #[prop(optional)]
+    search_input_class: &'static str,
+
+    /// CSS class for the search submit button.
+    #[prop(optional)]
+    search_button_class: &'static str,
+
+    /// CSS class for the container wrapping the button.
+    ///
+    /// Use this to style the wrapper that holds the sign-up/login button.
+    #[prop(optional)]
+    button_class: &'static str,
+
+    /// CSS class for the sign-up/login button link.
+    ///
+    /// Applies styles directly to the clickable `<a>` or button element.
+    #[prop(optional)]
+    button_link_class: &'static str,
+
+    /// CSS class for the mega menu container.
+    ///
+    /// Used when the mega menu is shown—applies to the entire dropdown card area.
+    #[prop(optional)]
+    mega_menu_class: &'static str,
+
+    /// CSS class for the cards within the mega menu.
+    ///
+    /// Applies to each item in the mega menu layout (e.g., a flex card).
+    #[prop(optional)]
+    mega_menu_card_class: &'static str,
+
+    /// CSS class for the "More" button in the navbar.
+    ///
+    /// This button typically toggles more navigation options or links.
+    #[prop(optional)]
+    more_button_class: &'static str,
+) -> impl IntoView {
+    let viewport = use_viewport(mobile_breakpoint, breakpoints);
+    let state = RwSignal::new(NavbarState::new(
+        initial_layout.is_mobile(viewport.get_untracked()),
+    ));
+    let dispatch = move |action: NavbarAction| {
+        let next = state.get_untracked().apply(action);
+        if next != state.get_untracked() {
+            state.set(next);
+        }
+    };
+    let is_mobile = Memo::new(move |_| state.with(|s| s.is_mobile));
+    let scroll_state = use_scroll_state(scroll_behavior);
+    // An open menu keeps the navbar in view.
+    let scroll = Memo::new(move |_| {
+        let scroll = scroll_state.get();
+        ScrollState {
+            hidden: scroll.hidden && !state.with(|s| s.any_open()),
+            ..scroll
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some(viewport) = viewport.get() {
+            dispatch(NavbarAction::SetMobile(viewport.is_mobile));
+            if let Some(callback) = on_breakpoint_change {
+                callback.run(viewport);
+            }
+        }
+    });
+
+    let toggle_mobile_menu =
+        Callback::new(move |_: MouseEvent| dispatch(NavbarAction::Toggle(Popup::MobileMenu)));
+
+    let toggle_dropdown =
+        Callback::new(move |_: MouseEvent| dispatch(NavbarAction::Toggle(Popup::Dropdown)));
+
+    let close = move |popup: Popup| Callback::new(move |_| dispatch(NavbarAction::Close(popup)));
+
+    let search_state = search_state.unwrap_or_else(|| RwSignal::new(String::new()));
+    let debounce = StoredValue::new_local(Debounce::default());
+    on_cleanup(move || debounce.dispose());
+    let on_search_input = Callback::new(move |value: String| {
+        search_state.set(value.clone());
+        if let Some(on_search_change) = on_search_change {
+            debounce.with_value(|debounce| {
+                debounce.call(search_debounce_ms, move || on_search_change.run(value));
+            });
+        }
+    });
+    let on_search_submit = on_search_submit.unwrap_or_else(|| Callback::new(|_| ()));
+
+    let location = use_location_path();
+    let current_path = current_path.unwrap_or_else(|| location.into());
+    let active_match = StoredValue::new(active_match);
+
+    let menus = StoredValue::new(menus);
+    let parts = StoredValue::new(parts);
+    let mobile_extra = StoredValue::new(mobile_extra);
+    let show_part = move |part| parts.with_value(|parts| parts.contains(&part));
+    let dropdown_items = StoredValue::new(dropdown_items);
+    let theme_storage_key = StoredValue::new(theme_storage_key);
+    let mega_menu_items = StoredValue::new(mega_menu_items);
+    let profile_image_url = StoredValue::new(profile_image_url);
+    let mobile_menu_id = StoredValue::new(popup_id(&id_prefix, Popup::MobileMenu));
+    let mega_menu_id = StoredValue::new(popup_id(&id_prefix, Popup::MegaMenu));
+    provide_context(IdScope::new(id_prefix));
+    let mobile_menu_transition =
+        StoredValue::new(mobile_menu_transition.or_else(|| mobile_variant.transition(200)));
+    let dropdown_transition = StoredValue::new(dropdown_transition);
+    let mega_menu_transition = StoredValue::new(mega_menu_transition);
+    let notifications_transition = StoredValue::new(notifications_transition);
+    let dark = RwSignal::new(false);
+    let on_theme_change = Callback::new(move |preference: ThemePreference| {
+        dark.set(preference.is_dark());
+        if let Some(on_theme_change) = on_theme_change {
+            on_theme_change.run(preference);
+        }
+    });
+
+    // In the CSS responsive mode, the `<details>` opens natively until the navbar is mounted;
+    // from then on, the state drives the menu, starting from whatever the user left open.
+    let disclosure = NodeRef::<Details>::new();
+    let hydrated = RwSignal::new(false);
+    Effect::new(move |_| {
+        let open = disclosure
+            .get_untracked()
+            .is_some_and(|details| details.has_attribute("open"));
+        if css_responsive && open {
+            dispatch(NavbarAction::Open(Popup::MobileMenu));
+        }
+        hydrated.set(true);
+    });
+    let mobile_block = view! {
+        <Show when=move || css_responsive || is_mobile.get()>
+            <NavbarToggle
+                onclick=toggle_mobile_menu
+                open=Signal::derive(move || state.with(|s| s.mobile_menu_open))
+                disclosure=css_responsive
+                controls=mobile_menu_id.get_value()
+                label=menu_toggle_label
+                animation=menu_toggle_animation
+                toggle_style=menu_toggle_style
+                toggle_class=menu_toggle_class
+                line_style=line_style
+                line_class=line_class
+            />
+        </Show>
+        <Show when=move || css_responsive || is_mobile.get()>
+            <MobileMenu
+                id=mobile_menu_id.get_value()
+                is_open=Signal::derive(move || state.with(|s| s.mobile_menu_open))
+                keep_mounted=Signal::derive(move || css_responsive && !hydrated.get())
+                transition=mobile_menu_transition.get_value()
+                variant=mobile_variant
+                backdrop_style=mobile_backdrop_style
+                backdrop_class=mobile_backdrop_class
+                menus=if show_part(NavbarPart::Menu) { menus.get_value() } else { Vec::new() }
+                menu_item_style=menu_item_style
+                menu_item_class=menu_item_class
+                submenu_class=submenu_class
+                dropdown_items=dropdown_items.get_value()
+                dropdown_style=dropdown_style
+                dropdown_class=dropdown_class
+                dropdown_item_style=dropdown_item_style
+                dropdown_item_class=dropdown_item_class
+                show_profile_menu=show_profile_menu && show_part(NavbarPart::Profile)
+                show_search=show_search && show_part(NavbarPart::Search)
+                show_notifications=show_notifications && show_part(NavbarPart::Notifications)
+                notifications=notifications
+                on_notification_select=on_notification_select
+                on_mark_all_read=on_mark_all_read
+                notifications_label=notifications_label
+                notifications_badge_max=notifications_badge_max
+                mark_all_read_text=mark_all_read_text
+                search_input_style=search_input_style
+                search_input_class=search_input_class
+                search_placeholder=search_placeholder
+                search_value=search_state
+                on_search_input=on_search_input
+                on_search_submit=on_search_submit
+                show_search_button=show_search_button
+                search_button_text=search_button_text
+                search_button_style=search_button_style
+                search_button_class=search_button_class
+                close_on_outside_click=mobile_menu_close_on_outside_click
+                on_close=close(Popup::MobileMenu)
+                current_path=current_path
+                active_match=active_match.get_value()
+                active_style=active_style
+                active_class=active_class
+                extra=mobile_extra.get_value()
+            />
+        </Show>
+    };
+
+    view! {
+        <nav
+            style=move || {
+                let style = scroll.get().nav_style(scroll_behavior, navbar_style, scrolled_style);
+                let theme = if show_theme_toggle && dark.get() { dark_theme } else { theme };
+                theme.with(|theme| with_phase(&theme.css_variables(), &style))
+            }
+            class=move || with_active(navbar_class, scrolled_class, scroll.get().scrolled)
+            data-navbar-breakpoint=css_responsive.then(|| mobile_breakpoint.to_string())
+        >
+            {css_responsive.then(|| view! { <style>{responsive_css(mobile_breakpoint)}</style> })}
+            <div style=container_style class=container_class>
+                <NavbarLogo
+                    logo_src=logo_src
+                    logo_alt=logo_alt
+                    logo_link=logo_link
+                    logo_style=logo_style
+                    logo_class=logo_class
+                />
+                {start.map(|start| start.run())}
+                {center.map(|center| center.run())}
+                <div
+                    style=inner_style
+                    class=with_active(inner_class, DESKTOP_CLASS, css_responsive)
+                >
+                    <Show when=move || css_responsive || !is_mobile.get()>
+                        {parts
+                            .get_value()
+                            .into_iter()
+                            .map(|part| match part {
+                                NavbarPart::Menu => view! {
+                                    <NavbarMenu
+                                        menus=menus.get_value()
+                                        menu_item_style=menu_item_style
+                                        list_style=nav_style
+                                        list_class=nav_class
+                                        menu_item_class=menu_item_class
+                                        submenu_style=submenu_style
+                                        submenu_class=submenu_class
+                                        current_path=current_path
+                                        active_match=active_match.get_value()
+                                        active_style=active_style
+                                        active_class=active_class
+                                    />
+                                }
+                                .into_any(),
+                                NavbarPart::MegaMenu if show_mega_menu => view! {
+                                    // Taps also fire pointer events; only a mouse opens the menu on
+                                    // hover, so a tap is left to the click that toggles it.
+                                    <div
+                                        on:pointerenter=move |e: PointerEvent| {
+                                            if e.pointer_type() == "mouse" {
+                                                dispatch(NavbarAction::Open(Popup::MegaMenu));
+                                            }
+                                        }
+                                        style="position: relative; display: inline-block;"
+                                    >
+                                        <button
+                                            type="button"
+                                            on:click=move |_| dispatch(NavbarAction::Toggle(Popup::MegaMenu))
+                                            aria-controls=mega_menu_id.get_value()
+                                            aria-haspopup="menu"
+                                            aria-expanded=move || state.with(|s| s.mega_menu_open).to_string()
+                                            style=more_button_style
+                                            class=more_button_class
+                                        >
+                                            {more_button_text}
+                                        </button>
+                                        <div on:pointerleave=move |e: PointerEvent| {
+                                            if e.pointer_type() == "mouse" {
+                                                dispatch(NavbarAction::Close(Popup::MegaMenu));
+                                            }
+                                        }>
+                                            <MegaMenu
+                                                id=mega_menu_id.get_value()
+                                                is_open=Signal::derive(move || state.with(|s| s.mega_menu_open))
+                                                transition=mega_menu_transition.get_value()
+                                                items=mega_menu_items.get_value()
+                                                wrapper_style=mega_menu_style
+                                                wrapper_class=mega_menu_class
+                                                card_style=mega_menu_card_style
+                                                card_class=mega_menu_card_class
+                                                close_on_outside_click=mega_menu_close_on_outside_click
+                                                on_close=close(Popup::MegaMenu)
+                                            />
+                                        </div>
+                                    </div>
+                                }
+                                .into_any(),
+                                NavbarPart::Search if show_search => view! {
+                                    <NavbarSearch
+                                        value=search_state
+                                        placeholder=search_placeholder
+                                        input_style=search_input_style
+                                        input_class=search_input_class
+                                        on_input=on_search_input
+                                        on_submit=on_search_submit
+                                        show_button=show_search_button
+                                        button_text=search_button_text
+                                        button_style=search_button_style
+                                        button_class=search_button_class
+                                    />
+                                }
+                                .into_any(),
+                                NavbarPart::Button => view! {
+                                    <Show when=move || button_text.with(|text| !text.is_empty())>
+                                        <NavbarButton
+                                            href=button_href
+                                            text=button_text
+                                            button_style=button_style
+                                            button_class=button_class
+                                            link_style=button_link_style
+                                            link_class=button_link_class
+                                            target=button_target
+                                        />
+                                    </Show>
+                                }
+                                .into_any(),
+                                NavbarPart::Notifications if show_notifications => view! {
+                                    <NotificationMenu
+                                        items=notifications
+                                        is_open=Signal::derive(move || state.with(|s| s.notifications_open))
+                                        toggle=Callback::new(move |_| dispatch(NavbarAction::Toggle(Popup::Notifications)))
+                                        on_close=close(Popup::Notifications)
+                                        on_select=Callback::new(move |item| {
+                                            if let Some(on_select) = on_notification_select {
+                                                on_select.run(item);
+                                            }
+                                        })
+                                        on_mark_all_read=Callback::new(move |_| {
+                                            if let Some(on_mark_all_read) = on_mark_all_read {
+                                                on_mark_all_read.run(());
+                                            }
+                                        })
+                                        badge_max=notifications_badge_max
+                                        label=notifications_label
+                                        mark_all_read_text=mark_all_read_text
+                                        transition=notifications_transition.get_value()
+                                        close_on_outside_click=notifications_close_on_outside_click
+                                    />
+                                }
+                                .into_any(),
+                                NavbarPart::Profile if show_profile_menu => view! {
+                                    <ProfileMenu
+                                        profile_image_url=profile_image_url.get_value()
+                                        items=dropdown_items.get_value()
+                                        dropdown_style=dropdown_style
+                                        dropdown_class=dropdown_class
+                                        item_style=dropdown_item_style
+                                        item_class=dropdown_item_class
+                                        is_open=Signal::derive(move || state.with(|s| s.dropdown_open))
+                                        transition=dropdown_transition.get_value()
+                                        toggle=toggle_dropdown
+                                        profile_text=profile_button_text
+                                        close_on_outside_click=dropdown_close_on_outside_click
+                                        on_close=close(Popup::Dropdown)
+                                        current_path=current_path
+                                        active_match=active_match.get_value()
+                                        active_style=active_style
+                                        active_class=active_class
+                                    />
+                                }
+                                .into_any(),
+                                NavbarPart::ThemeToggle if show_theme_toggle => view! {
+                                    <ThemeToggle
+                                        storage_key=theme_storage_key.get_value()
+                                        on_theme_change=on_theme_change
+                                    />
+                                }
+                                .into_any(),
+                                _ => ().into_any(),
+                            })
+                            .collect_view()}
+                    </Show>
+                </div>
+                {end.map(|end| end.run())}
+                // The mobile layout has no parts row, so the toggle follows `end` there.
+                <Show when=move || {
+                    show_theme_toggle
+                        && show_part(NavbarPart::ThemeToggle)
+                        && (css_responsive || is_mobile.get())
+                }>
+                    <ThemeToggle
+                        storage_key=theme_storage_key.get_value()
+                        on_theme_change=on_theme_change
+                        class=if css_responsive { MOBILE_CLASS } else { "" }
+                    />
+                </Show>
+            </div>
+            {if css_responsive {
+                view! {
+                    <details
+                        node_ref=disclosure
+                        open=move || hydrated.get()
+                        style="position: relative; display: inline-block;"
+                        class=MOBILE_CLASS
+                    >
+                        {mobile_block}
+                    </details>
+                }
+                .into_any()
+            } else {
+                view! {
+                    <div style="position: relative; display: inline-block;">{mobile_block}</div>
+                }
+                .into_any()
+            }}
+        </nav>
+    }
+}
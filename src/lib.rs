@@ -5,6 +5,11 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![doc = include_str!("../README.md")]
 
+pub mod core;
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+mod dom;
+
 #[cfg(feature = "yew")]
 pub mod yew;
 
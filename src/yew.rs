@@ -1,62 +1,50 @@
 #![doc = include_str!("../YEW.md")]
 
-use gloo::events::EventListener;
+use crate::core::{
+    ActiveMatch, BADGE_MAX, BELL_ICON_PATH, BreakpointTracker, Breakpoints, ComboboxKey, Command,
+    CommandAction, CommandSet, DESKTOP_CLASS, InitialLayout, MOBILE_BREAKPOINT, MOBILE_CLASS,
+    MenuKey, MobileVariant, NavbarAction, NavbarPart, NavbarState, NavbarTheme, Notification,
+    Orientation, Popup, RecentCommands, ScrollBehavior, ScrollState, ScrollTracker, SearchIndex,
+    Suggestion, SuggestionProvider, Suggestions, ThemeMode, ThemePreference, ToggleAnimation,
+    Transition, TransitionPhase, VISUALLY_HIDDEN_STYLE, Viewport, badge_text, highlight_match,
+    responsive_css, unread_count,
+};
+use crate::dom::{
+    Debounce, FocusReturn, FocusTrap, IdScope, OutsideClickListener, PhaseTimer, ScrollLock,
+    ScrollObserver, ViewportObserver, element_id, focus_element, focus_menu_item, focus_within,
+    is_details_open, item_id, listen_shortcut, load_recent_commands, load_theme_mode,
+    location_path, popup_id, prefers_dark_scheme, prefers_reduced_motion, save_recent_commands,
+    save_theme_mode, scroll_into_view, set_document_theme, viewport_width, watch_color_scheme,
+    watch_location,
+};
+use gloo::timers::future::TimeoutFuture;
+use router::use_link_router;
+use std::rc::Rc;
 use web_sys::HtmlInputElement;
-use web_sys::wasm_bindgen::JsCast;
-use web_sys::wasm_bindgen::closure::Closure;
 use yew::prelude::*;
-/// Represents a standard menu item in the navigation bar.
-///
-/// This struct is used for rendering main navigation links.
-#[derive(Clone, PartialEq)]
-pub struct Menu {
-    /// Unique identifier for the menu item.
-    pub id: usize,
-
-    /// The destination URL when the menu item is clicked.
-    pub link: &'static str,
 
-    /// The display name of the menu item.
-    pub name: &'static str,
+pub use crate::core::MegaMenuItem;
+#[cfg(feature = "yew-router")]
+pub use router::WithRoute;
 
-    /// Optional icon displayed before the name.
-    pub icon_start: Option<Html>,
+mod router;
 
-    /// Optional icon displayed after the name.
-    pub icon_end: Option<Html>,
-}
-
-/// Represents an item in a dropdown menu.
+/// Represents a standard menu item in the navigation bar.
 ///
-/// Used for profile or secondary dropdowns in the navbar.
-#[derive(Clone, PartialEq)]
-pub struct DropdownItem {
-    /// Unique identifier for the dropdown item.
-    pub id: usize,
-
-    /// Optional icon displayed before the label.
-    pub icon: Option<Html>,
-
-    /// The label/text for the dropdown item.
-    pub label: &'static str,
-
-    /// The destination URL for the item.
-    pub link: &'static str,
-}
+/// This is the shared [`crate::core::Menu`] model with Yew `Html` icons.
+pub type Menu = crate::core::Menu<Html>;
 
-/// Represents an item in a mega menu.
+/// Represents an item in a dropdown menu.
 ///
-/// Used for showcasing grouped or featured links in a grid.
-#[derive(Clone, PartialEq)]
-pub struct MegaMenuItem {
-    /// The title or name of the item.
-    pub title: &'static str,
+/// This is the shared [`crate::core::DropdownItem`] model with a Yew `Html` icon.
+pub type DropdownItem = crate::core::DropdownItem<Html>;
 
-    /// A short description explaining the item.
-    pub description: &'static str,
+impl Reducible for NavbarState {
+    type Action = NavbarAction;
 
-    /// The link URL associated with the item.
-    pub link: &'static str,
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        Rc::new(self.apply(action))
+    }
 }
 
 /// Props for the `Navbar` component.
@@ -89,41 +77,235 @@ pub struct NavbarProps {
     pub show_profile_menu: bool,
 
     /// Text for the main call-to-action button.
-    #[prop_or("Sign up")]
-    pub button_text: &'static str,
+    #[prop_or(AttrValue::Static("Sign up"))]
+    pub button_text: AttrValue,
 
     /// Href for the main call-to-action button.
-    #[prop_or("#")]
-    pub button_href: &'static str,
+    #[prop_or(AttrValue::Static("#"))]
+    pub button_href: AttrValue,
 
     /// Source path for the logo image.
-    #[prop_or("/assets/logo.webp")]
-    pub logo_src: &'static str,
+    #[prop_or(AttrValue::Static("/assets/logo.webp"))]
+    pub logo_src: AttrValue,
 
     /// Alternative text for the logo image.
-    #[prop_or("logo")]
-    pub logo_alt: &'static str,
+    #[prop_or(AttrValue::Static("logo"))]
+    pub logo_alt: AttrValue,
 
     /// Link the logo should redirect to when clicked.
-    #[prop_or("/")]
-    pub logo_link: &'static str,
+    #[prop_or(AttrValue::Static("/"))]
+    pub logo_link: AttrValue,
 
     /// Target attribute for the CTA button link.
-    #[prop_or("_blank")]
-    pub button_target: &'static str,
+    #[prop_or(AttrValue::Static("_blank"))]
+    pub button_target: AttrValue,
 
     /// Placeholder text for the search input.
-    #[prop_or("Search...")]
-    pub search_placeholder: &'static str,
+    #[prop_or(AttrValue::Static("Search..."))]
+    pub search_placeholder: AttrValue,
 
     /// Text label for the profile menu button/avatar.
-    #[prop_or("Profile")]
-    pub profile_button_text: &'static str,
+    #[prop_or(AttrValue::Static("Profile"))]
+    pub profile_button_text: AttrValue,
+
+    /// Accessible label (`aria-label`) of the hamburger toggle button.
+    #[prop_or(AttrValue::Static("Toggle Menu"))]
+    pub menu_toggle_label: AttrValue,
+
+    // Notification props
+    /// Whether to show the notification bell and its feed.
+    #[prop_or(false)]
+    pub show_notifications: bool,
+
+    /// Notifications listed in the feed, newest first.
+    #[prop_or_default]
+    pub notifications: Vec<Notification>,
+
+    /// Called with the notification the user clicked.
+    #[prop_or_default]
+    pub on_notification_select: Callback<Notification>,
+
+    /// Called when the user clicks "Mark all read".
+    #[prop_or_default]
+    pub on_mark_all_read: Callback<()>,
+
+    /// Unread count above which the badge shows `"{notifications_badge_max}+"`.
+    #[prop_or(BADGE_MAX)]
+    pub notifications_badge_max: usize,
+
+    /// Accessible name of the bell, and of the notification section of the mobile menu.
+    #[prop_or(AttrValue::Static("Notifications"))]
+    pub notifications_label: AttrValue,
+
+    /// Text of the button that marks every notification as read.
+    #[prop_or(AttrValue::Static("Mark all read"))]
+    pub mark_all_read_text: AttrValue,
+
+    // Layout props
+    /// Content rendered right after the logo, e.g. a workspace switcher.
+    #[prop_or_default]
+    pub start: Html,
+
+    /// Content rendered between the `start` slot and the built-in parts, e.g. a page title.
+    #[prop_or_default]
+    pub center: Html,
+
+    /// Content rendered after the built-in parts, on desktop and mobile, e.g. a cart icon.
+    #[prop_or_default]
+    pub end: Html,
+
+    /// Content rendered at the bottom of the mobile menu.
+    #[prop_or_default]
+    pub mobile_extra: Html,
+
+    /// Built-in parts of the desktop layout, in order. A part left out is hidden, on mobile too.
+    #[prop_or_else(|| NavbarPart::DEFAULT_ORDER.to_vec())]
+    pub parts: Vec<NavbarPart>,
+
+    // Responsive props
+    /// Viewport width, in pixels, at or below which the mobile layout is used.
+    #[prop_or(MOBILE_BREAKPOINT)]
+    pub mobile_breakpoint: f64,
+
+    /// Named breakpoint widths (`sm`, `md`, `lg`, `xl`) reported through `on_breakpoint_change`.
+    #[prop_or_default]
+    pub breakpoints: Breakpoints,
+
+    /// Called on mount and whenever the viewport crosses a named breakpoint or the mobile breakpoint.
+    #[prop_or_default]
+    pub on_breakpoint_change: Callback<Viewport>,
+
+    /// Layout of the first render, before the viewport is measured: the measured viewport (the
+    /// default), desktop, mobile, or a guess from the `User-Agent` header. Set it when server
+    /// rendering so the hydrating render matches the server markup.
+    #[prop_or_default]
+    pub initial_layout: InitialLayout,
+
+    /// Prefix of the element ids of the navbar and its menus, e.g. `navbar-mobile-menu`. Ids
+    /// don't depend on anything rendered before, so they match between a server render and
+    /// hydration; give each navbar on a page its own prefix.
+    #[prop_or(AttrValue::Static("navbar"))]
+    pub id_prefix: AttrValue,
+
+    /// Renders both the desktop and the mobile layout and shows one of them with media queries
+    /// (see [`responsive_css`]), so the right one is painted before the app hydrates, or without
+    /// JavaScript at all. The hamburger toggles a `<details>` until hydration takes over.
+    #[prop_or(false)]
+    pub css_responsive: bool,
+
+    // Scroll props
+    /// How the navbar reacts to the page scrolling: static (the default), sticky, hidden while
+    /// scrolling down, or shrunk past a threshold.
+    #[prop_or_default]
+    pub scroll_behavior: ScrollBehavior,
+
+    /// Style added to the `<nav>` once the page is scrolled past the [`ScrollBehavior::Shrink`]
+    /// threshold.
+    #[prop_or(
+        "padding-top: 0.5rem; padding-bottom: 0.5rem; box-shadow: 0 2px 4px var(--navbar-shadow, rgba(0,0,0,0.1));"
+    )]
+    pub scrolled_style: &'static str,
+
+    // Dismissal props
+    /// Whether a click outside the open mobile menu closes it.
+    #[prop_or(true)]
+    pub mobile_menu_close_on_outside_click: bool,
+
+    /// Whether a click outside the open profile dropdown closes it.
+    #[prop_or(true)]
+    pub dropdown_close_on_outside_click: bool,
+
+    /// Whether a click outside the open mega menu closes it.
+    #[prop_or(true)]
+    pub mega_menu_close_on_outside_click: bool,
+
+    /// Whether a click outside the open notification feed closes it.
+    #[prop_or(true)]
+    pub notifications_close_on_outside_click: bool,
+
+    // Transition props
+    /// Enter and exit transition of the mobile menu, e.g. [`Transition::slide_down`].
+    ///
+    /// Without one, the menu mounts and unmounts at once, except for the drawers and the sheet
+    /// of `mobile_variant`, which slide in.
+    #[prop_or_default]
+    pub mobile_menu_transition: Option<Transition>,
+
+    /// Enter and exit transition of the profile dropdown list.
+    #[prop_or_default]
+    pub dropdown_transition: Option<Transition>,
+
+    /// Enter and exit transition of the mega menu.
+    #[prop_or_default]
+    pub mega_menu_transition: Option<Transition>,
+
+    /// Enter and exit transition of the notification feed.
+    #[prop_or_default]
+    pub notifications_transition: Option<Transition>,
+
+    // Mobile menu props
+    /// Layout of the mobile menu: a dropdown under the toggle (the default), a left or right
+    /// drawer, or a full-screen sheet.
+    ///
+    /// Drawers and the sheet open over a backdrop, keep focus inside the menu and lock the page
+    /// scroll while open; `Escape` or a click on the backdrop closes them.
+    #[prop_or_default]
+    pub mobile_variant: MobileVariant,
+
+    /// Style of the backdrop behind a drawer or sheet.
+    #[prop_or(MOBILE_BACKDROP_STYLE)]
+    pub mobile_backdrop_style: &'static str,
+
+    /// Class of the backdrop behind a drawer or sheet.
+    #[prop_or_default]
+    pub mobile_backdrop_class: &'static str,
+
+    // Theme props
+    /// Colors of the navbar, set as CSS custom properties on the `<nav>` and used by every
+    /// default style: [`NavbarTheme::light`] (the default), [`NavbarTheme::dark`] or a custom
+    /// theme.
+    #[prop_or_default]
+    pub theme: NavbarTheme,
+
+    /// Colors used instead of `theme` while the theme toggle applies the dark theme.
+    #[prop_or_else(NavbarTheme::dark)]
+    pub dark_theme: NavbarTheme,
+
+    /// Shows a [`ThemeToggle`] (light, dark or system) at `NavbarPart::ThemeToggle` in `parts`,
+    /// last by default; the mobile layout shows it after `end`.
+    #[prop_or_default]
+    pub show_theme_toggle: bool,
+
+    /// `localStorage` key the toggle saves the picked mode under; empty to keep it in memory only.
+    #[prop_or(AttrValue::Static("navbar-theme"))]
+    pub theme_storage_key: AttrValue,
+
+    /// Callback fired with the toggle's preference on mount and whenever the applied theme may
+    /// change.
+    #[prop_or_default]
+    pub on_theme_change: Callback<ThemePreference>,
+
+    // Active route props
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[prop_or_default]
+    pub current_path: Option<AttrValue>,
+
+    /// How item links are matched against the current path.
+    #[prop_or_default]
+    pub active_match: ActiveMatch,
+
+    /// Style added to the link of the active menu, mobile menu and dropdown item.
+    #[prop_or("font-weight: bold;")]
+    pub active_style: &'static str,
+
+    /// CSS class added to the link of the active menu, mobile menu and dropdown item.
+    #[prop_or_default]
+    pub active_class: &'static str,
 
     // Styles
     /// Style for the main navbar container.
     #[prop_or(
-        "display: flex; align-items: center; justify-content: space-between; padding: 1rem; background-color: #fff;"
+        "display: flex; align-items: center; justify-content: space-between; padding: 1rem; background-color: var(--navbar-bg, #fff); color: var(--navbar-fg, black);"
     )]
     pub navbar_style: &'static str,
 
@@ -142,11 +324,18 @@ pub struct NavbarProps {
     pub logo_style: &'static str,
 
     /// Style for the hamburger menu button.
-    #[prop_or("display: flex; flex-direction: column; gap: 5px; cursor: pointer;")]
+    #[prop_or(
+        "display: flex; flex-direction: column; gap: 5px; cursor: pointer; background: none; border: none; padding: 0;"
+    )]
     pub menu_toggle_style: &'static str,
 
+    /// How the hamburger icon animates when the mobile menu opens: not at all (the default),
+    /// morphing into an X, spinning into an X, or folding into an arrow.
+    #[prop_or_default]
+    pub menu_toggle_animation: ToggleAnimation,
+
     /// Style for the lines in the hamburger icon.
-    #[prop_or("width: 25px; height: 2px; background: black;")]
+    #[prop_or("width: 25px; height: 2px; background: var(--navbar-fg, black);")]
     pub line_style: &'static str,
 
     /// Style applied to the `<ul>` navigation container.
@@ -155,22 +344,30 @@ pub struct NavbarProps {
 
     /// Style for individual `<li>` items in the navbar.
     #[prop_or(
-        "text-decoration: none; color: black; padding: 0.5rem 1rem; transition: background 0.3s ease;"
+        "text-decoration: none; color: var(--navbar-fg, black); padding: 0.5rem 1rem; transition: background 0.3s ease;"
     )]
     pub menu_item_style: &'static str,
 
     /// Style for the dropdown menu panel.
     #[prop_or(
-        "position: absolute; top: 100%; left: 0; background: white; box-shadow: 0 4px 8px rgba(0,0,0,0.1); z-index: 1000;"
+        "position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); z-index: 1000;"
     )]
     pub dropdown_style: &'static str,
 
+    /// Style for the flyout of a menu item with `children`.
+    ///
+    /// Nested flyouts open beside their parent item instead of below it.
+    #[prop_or(SUBMENU_STYLE)]
+    pub submenu_style: &'static str,
+
     /// Style for each dropdown menu item.
     #[prop_or("padding: 0.5rem 1rem; white-space: nowrap;")]
     pub dropdown_item_style: &'static str,
 
     /// Style applied to the search input field.
-    #[prop_or("padding: 0.5rem; font-size: 1rem; border: 1px solid #ccc;")]
+    #[prop_or(
+        "padding: 0.5rem; font-size: 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
+    )]
     pub search_input_style: &'static str,
 
     /// Style for the CTA button wrapper.
@@ -179,25 +376,25 @@ pub struct NavbarProps {
 
     /// Style for the `<a>` link inside the CTA button.
     #[prop_or(
-        "text-decoration: none; color: white; background: #007bff; padding: 0.5rem 1rem; border-radius: 4px;"
+        "text-decoration: none; color: var(--navbar-accent-text, white); background: var(--navbar-accent, #007bff); padding: 0.5rem 1rem; border-radius: 4px;"
     )]
     pub button_link_style: &'static str,
 
     /// Style for the mega menu dropdown.
     #[prop_or(
-        "position: absolute; top: 100%; left: 0; background: white; padding: 0; margin-top: 0.5rem; z-index: 1000;"
+        "position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); padding: 0; margin-top: 0.5rem; z-index: 1000;"
     )]
     pub mega_menu_style: &'static str,
 
     /// Style for each card/item in the mega menu.
     #[prop_or(
-        "background: white; display: flex; gap: 2rem; padding: 1rem; box-shadow: 0 2px 8px rgba(0,0,0,0.1);"
+        "background: var(--navbar-surface, white); display: flex; gap: 2rem; padding: 1rem; box-shadow: 0 2px 8px var(--navbar-shadow, rgba(0,0,0,0.1));"
     )]
     pub mega_menu_card_style: &'static str,
 
     /// Text for the "More" button shown in the navbar.
-    #[prop_or("More")]
-    pub more_button_text: &'static str,
+    #[prop_or(AttrValue::Static("More"))]
+    pub more_button_text: AttrValue,
 
     /// Style applied to the "More" button element.
     #[prop_or("background: transparent; border: none; cursor: pointer; font-weight: bold;")]
@@ -208,6 +405,82 @@ pub struct NavbarProps {
     #[prop_or_default]
     pub search_state: Option<UseStateHandle<String>>,
 
+    /// Callback fired with the search text on `Enter` or a click on the search button.
+    #[prop_or_default]
+    pub on_search_submit: Callback<String>,
+
+    /// Callback fired with the search text once typing pauses for `search_debounce_ms`.
+    #[prop_or_default]
+    pub on_search_change: Callback<String>,
+
+    /// Delay (ms) after the last keystroke before `on_search_change` fires.
+    #[prop_or(300)]
+    pub search_debounce_ms: u32,
+
+    /// Whether to show a submit button next to the search input.
+    #[prop_or(false)]
+    pub show_search_button: bool,
+
+    /// Text of the search submit button.
+    #[prop_or(AttrValue::Static("Search"))]
+    pub search_button_text: AttrValue,
+
+    /// Style applied to the search submit button.
+    #[prop_or(
+        "padding: 0.5rem 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;"
+    )]
+    pub search_button_style: &'static str,
+
+    /// Provider of the suggestions listed under the search input as the user types.
+    #[prop_or_default]
+    pub suggestions: Option<SuggestionProvider>,
+
+    /// Suggests the navbar's own items (`menus`, `dropdown_items` and `mega_menu_items`) as the
+    /// user types, fuzzy-matched on their names and descriptions, unless `suggestions` is set.
+    #[prop_or(false)]
+    pub search_items: bool,
+
+    /// Callback fired with the chosen suggestion, before navigating to its `link` (if any).
+    #[prop_or_default]
+    pub on_suggestion_select: Callback<Suggestion>,
+
+    /// Text shown while an asynchronous suggestion provider is loading.
+    #[prop_or(AttrValue::Static("Loading..."))]
+    pub suggestions_loading_text: AttrValue,
+
+    /// Text shown when the suggestion provider finds nothing.
+    #[prop_or(AttrValue::Static("No results"))]
+    pub suggestions_empty_text: AttrValue,
+
+    /// Style applied to the suggestions listbox.
+    #[prop_or(SUGGESTIONS_STYLE)]
+    pub suggestions_style: &'static str,
+
+    /// Style applied to each suggestion.
+    #[prop_or("padding: 0.5rem 1rem; cursor: pointer;")]
+    pub suggestion_style: &'static str,
+
+    /// Style added to the highlighted suggestion.
+    #[prop_or("background: var(--navbar-highlight, #f0f0f0);")]
+    pub suggestion_active_style: &'static str,
+
+    /// Whether to render a command palette over `menus`, `dropdown_items`, `mega_menu_items`
+    /// and `commands`, opened with `Ctrl` (or `Cmd`) and `command_palette_shortcut`.
+    #[prop_or(false)]
+    pub show_command_palette: bool,
+
+    /// App commands (links or actions) listed in the command palette next to the navbar items.
+    #[prop_or_default]
+    pub commands: Vec<Command>,
+
+    /// Key that opens the command palette together with `Ctrl` (or `Cmd` on macOS).
+    #[prop_or(AttrValue::Static("k"))]
+    pub command_palette_shortcut: AttrValue,
+
+    /// Callback fired with the command chosen in the palette, before it navigates or runs.
+    #[prop_or_default]
+    pub on_command_select: Callback<Command>,
+
     /// Optional profile image URL for the avatar in the navbar.
     #[prop_or_default]
     pub profile_image_url: Option<String>,
@@ -217,6 +490,11 @@ pub struct NavbarProps {
     #[prop_or_default]
     pub navbar_class: &'static str,
 
+    /// CSS class added to the `<nav>` once the page is scrolled past the
+    /// [`ScrollBehavior::Shrink`] threshold.
+    #[prop_or_default]
+    pub scrolled_class: &'static str,
+
     /// CSS class for the inner wrapper of the navbar content.
     ///
     /// Used to apply styles to the inner layout container that holds all navbar elements.
@@ -265,6 +543,12 @@ pub struct NavbarProps {
     #[prop_or_default]
     pub dropdown_class: &'static str,
 
+    /// CSS class for the submenu of a menu item with `children`.
+    ///
+    /// Applies to desktop flyouts and mobile accordions, at every level.
+    #[prop_or_default]
+    pub submenu_class: &'static str,
+
     /// CSS class for individual dropdown menu items.
     ///
     /// Used to style each item within a dropdown menu.
@@ -277,6 +561,22 @@ pub struct NavbarProps {
     #[prop_or_default]
     pub search_input_class: &'static str,
 
+    /// CSS class for the search submit button.
+    #[prop_or_default]
+    pub search_button_class: &'static str,
+
+    /// CSS class for the suggestions listbox.
+    #[prop_or_default]
+    pub suggestions_class: &'static str,
+
+    /// CSS class for each suggestion.
+    #[prop_or_default]
+    pub suggestion_class: &'static str,
+
+    /// CSS class added to the highlighted suggestion.
+    #[prop_or_default]
+    pub suggestion_active_class: &'static str,
+
     /// CSS class for the container wrapping the button.
     ///
     /// Use this to style the wrapper that holds the sign-up/login button.
@@ -308,423 +608,2786 @@ pub struct NavbarProps {
     pub more_button_class: &'static str,
 }
 
-/// Navbar Component
-///
-/// A responsive and interactive navigation bar component built using Yew. It adapts to screen size
-/// changes and supports features such as a mobile toggle menu, dropdowns, mega menu, search input,
-/// and profile menu. The component is highly configurable through styles and class properties.
-///
-/// # Features
+/// Tracks the viewport against a mobile breakpoint and a set of named breakpoints.
 ///
-/// - **Responsive Design**:
-///   - Automatically switches to mobile view if `window.innerWidth() <= 768`.
-///   - A `resize` event listener dynamically updates the layout state.
+/// Returns `None` outside a browser. The viewport is observed with `matchMedia` change events
+/// (falling back to `resize`), coalesced to one update per animation frame, so the calling
+/// component only re-renders when a breakpoint or the mobile layout is crossed; `width` is the
+/// viewport width at the last crossing. Listeners are removed on unmount and re-created when
+/// either argument changes.
 ///
-/// - **Mobile Menu Toggle**:
-///   - A hamburger icon appears on smaller screens.
-///   - Opens a collapsible menu containing navigation links, search bar, and profile dropdown (if enabled).
-///   - Global click event listener closes the mobile menu when clicking outside.
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use navbar::core::{Breakpoint, Breakpoints};
+/// use navbar::yew::use_viewport;
 ///
-/// - **Dropdown Menu**:
-///   - Profile dropdown toggled on user click.
-///   - Can be styled via dropdown props.
+/// #[function_component]
+/// fn Sidebar() -> Html {
+///     let viewport = use_viewport(1024.0, Breakpoints::default());
+///     let wide = viewport.is_some_and(|v| v.breakpoint >= Breakpoint::Lg);
+///     html! { if wide { <aside>{ "Filters" }</aside> } }
+/// }
+/// ```
+#[hook]
+pub fn use_viewport(mobile_breakpoint: f64, breakpoints: Breakpoints) -> Option<Viewport> {
+    let viewport =
+        use_state_eq(|| viewport_width().map(|w| breakpoints.viewport(w, mobile_breakpoint)));
+
+    {
+        let viewport = viewport.setter();
+        use_effect_with(
+            (mobile_breakpoint, breakpoints),
+            move |&(mobile_breakpoint, breakpoints)| {
+                let mut tracker = BreakpointTracker::default();
+                let mut report = move |width: f64| {
+                    let next = breakpoints.viewport(width, mobile_breakpoint);
+                    if let Some(next) = tracker.observe(next) {
+                        viewport.set(Some(next));
+                    }
+                };
+                if let Some(width) = viewport_width() {
+                    report(width);
+                }
+
+                let observer =
+                    ViewportObserver::new(&breakpoints.boundaries(mobile_breakpoint), report);
+                move || drop(observer)
+            },
+        );
+    }
+
+    *viewport
+}
+
+/// Tracks the page scroll for a [`ScrollBehavior`].
 ///
-/// - **Mega Menu**:
-///   - Shown on mouse hover when `show_mega_menu` is true.
-///   - Items appear in a grid-style layout under the "More" button.
+/// Returns whether the navbar should be hidden ([`ScrollBehavior::HideOnScroll`]) or scrolled
+/// ([`ScrollBehavior::Shrink`]); the state stays at its default for untracked behaviors. Scrolling
+/// is observed with a passive listener, coalesced to one update per animation frame, and the
+/// calling component only re-renders when the state changes. The listener is removed on unmount
+/// and re-created when `behavior` changes.
 ///
-/// - **Search Input**:
-///   - Optional text input for searching.
-///   - Supports local state or controlled component mode via `search_state`.
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use navbar::core::ScrollBehavior;
+/// use navbar::yew::use_scroll_state;
 ///
-/// - **Call-to-Action Button**:
-///   - An optional button beside the menu that links to an external/internal page.
+/// #[function_component]
+/// fn BackToTop() -> Html {
+///     let scroll = use_scroll_state(ScrollBehavior::Shrink(400.0));
+///     html! { if scroll.scrolled { <a href="#top">{ "Back to top" }</a> } }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_state(behavior: ScrollBehavior) -> ScrollState {
+    let state = use_state_eq(ScrollState::default);
+
+    {
+        let state = state.setter();
+        use_effect_with(behavior, move |&behavior| {
+            state.set(ScrollState::default());
+            let observer = behavior.is_tracked().then(|| {
+                let mut tracker = ScrollTracker::new(behavior);
+                ScrollObserver::new(move |y| state.set(tracker.observe(y)))
+            });
+            move || drop(observer)
+        });
+    }
+
+    *state
+}
+
+/// Drives the enter and exit transitions of an element shown while `show` is `true`.
 ///
-/// # Examples
+/// Returns the element's [`TransitionPhase`], to render it while [`TransitionPhase::is_mounted`]
+/// with [`Transition::style`]/[`Transition::class`] of that phase, and a callback to fire on its
+/// `transitionend`. Exiting elements unmount on `transitionend`, or after
+/// [`Transition::timeout_ms`] at the latest. Without a transition, or when the user prefers
+/// reduced motion, the element mounts and unmounts with `show`.
 ///
-/// ## Basic Navbar
+/// # Example
 /// ```rust
 /// use yew::prelude::*;
-/// use navbar::yew::{Menu, MegaMenuItem, Navbar};
+/// use navbar::core::Transition;
+/// use navbar::yew::use_transition;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct ToastProps {
+///     show: bool,
+/// }
 ///
 /// #[function_component]
-/// fn App() -> Html {
+/// fn Toast(props: &ToastProps) -> Html {
+///     let fade = Transition::fade(200);
+///     let (phase, on_end) = use_transition(props.show, Some(fade.clone()));
 ///     html! {
-///         <Navbar menus={vec![
-///             Menu { id: 1, link: "/home", name: "Home", icon_start: None, icon_end: None },
-///         ]} />
+///         if phase.is_mounted() {
+///             <div style={fade.style(phase).to_string()} ontransitionend={on_end.reform(|_| ())}>
+///                 { "Saved" }
+///             </div>
+///         }
 ///     }
 /// }
 /// ```
+#[hook]
+pub fn use_transition(
+    show: bool,
+    transition: Option<Transition>,
+) -> (TransitionPhase, Callback<()>) {
+    let phase = use_state_eq(|| TransitionPhase::initial(show));
+    let animate = transition.is_some() && !prefers_reduced_motion();
+    let timeout_ms = transition.map_or(0, |transition| transition.timeout_ms);
+
+    {
+        let phase = phase.clone();
+        use_effect_with((show, animate), move |&(show, animate)| {
+            phase.set(if show {
+                phase.show(animate)
+            } else {
+                phase.hide(animate)
+            });
+        });
+    }
+    {
+        let setter = phase.setter();
+        use_effect_with((*phase, timeout_ms), move |&(current, timeout_ms)| {
+            let timer = PhaseTimer::new(current, timeout_ms, move || setter.set(current.advance()));
+            move || drop(timer)
+        });
+    }
+
+    let on_end = {
+        let phase = phase.clone();
+        Callback::from(move |_| {
+            if *phase == TransitionPhase::Exit {
+                phase.set(TransitionPhase::Exited);
+            }
+        })
+    };
+    if animate {
+        (*phase, on_end)
+    } else {
+        (TransitionPhase::initial(show), on_end)
+    }
+}
+
+/// Calls `on_outside` whenever a click lands outside the element with the given `id`.
 ///
-/// ## With Profile Menu and Search
+/// This is the dismissal mechanism behind every navbar popup. The document listener is only
+/// attached while `enabled` is `true`, and is removed when it turns `false` or the component
+/// unmounts. The latest `on_outside` is always used without re-attaching the listener.
+///
+/// # Example
 /// ```rust
 /// use yew::prelude::*;
-/// use navbar::yew::{Menu, MegaMenuItem, Navbar, DropdownItem};
+/// use navbar::yew::use_click_outside;
 ///
 /// #[function_component]
-/// fn App() -> Html {
-///     html! {
-///         <Navbar
-///             show_search={true}
-///             search_placeholder={"Search..."}
-///             show_profile_menu={true}
-///             profile_button_text={"Profile"}
-///             dropdown_items={vec![
-///                 DropdownItem { id: 1, link: "/settings", label: "Settings", icon: None },
-///                 DropdownItem { id: 2, link: "/logout", label: "Logout", icon: None },
-///             ]}
-///             menus={vec![
-///                 Menu { id: 1, link: "/dashboard", name: "Dashboard", icon_start: None, icon_end: None },
-///             ]}
-///         />
-///     }
+/// fn Popover() -> Html {
+///     let open = use_state(|| true);
+///     let close = {
+///         let open = open.clone();
+///         Callback::from(move |_| open.set(false))
+///     };
+///     use_click_outside("popover".into(), *open, close);
+///     html! { if *open { <div id="popover">{ "Hello" }</div> } }
 /// }
 /// ```
+#[hook]
+pub fn use_click_outside(id: AttrValue, enabled: bool, on_outside: Callback<()>) {
+    let on_outside_ref = use_mut_ref(|| on_outside.clone());
+    *on_outside_ref.borrow_mut() = on_outside;
+
+    use_effect_with((id, enabled), move |(id, enabled)| {
+        let listener = enabled.then(|| {
+            OutsideClickListener::new(id.to_string(), move || {
+                let on_outside = on_outside_ref.borrow().clone();
+                on_outside.emit(());
+            })
+        });
+        move || drop(listener)
+    });
+}
+
+/// Returns the current `window.location.pathname`, updated on history navigation (`popstate`).
 ///
-/// ## With Mega Menu
+/// With the `yew-router` feature, inside a router this is the router's current path (without
+/// its basename), so it also follows client-side navigation. Returns an empty path outside a
+/// browser. Other routers that navigate with `pushState` do not fire `popstate`; pass their
+/// current path through the `current_path` props instead.
+///
+/// # Example
 /// ```rust
 /// use yew::prelude::*;
-/// use navbar::yew::{Menu, MegaMenuItem, Navbar};
+/// use navbar::yew::use_location_path;
 ///
 /// #[function_component]
-/// fn App() -> Html {
-///     html! {
-///         <Navbar
-///             show_mega_menu={true}
-///             more_button_text={"Explore"}
-///             mega_menu_items={vec![
-///                 MegaMenuItem { title: "Docs", description: "Learn more about our APIs", link: "/docs" },
-///                 MegaMenuItem { title: "Blog", description: "See what we're up to", link: "/blog" },
-///             ]}
-///             menus={vec![
-///                 Menu { id: 1, link: "/platform", name: "Platform", icon_start: None, icon_end: None },
-///             ]}
-///         />
-///     }
+/// fn Breadcrumb() -> Html {
+///     let path = use_location_path();
+///     html! { <span>{ path }</span> }
 /// }
 /// ```
-///
-/// # Notes
-/// - The component uses `use_state` for responsiveness and open/close behaviors.
-/// - Subcomponents include `NavbarMenu`, `MegaMenu`, `ProfileMenu`, `MobileMenu`, and `NavbarToggle`.
-/// - This component is customizable and works well with Tailwind CSS or other utility-first CSS frameworks.
-///
-/// # See Also
-/// - [MDN `<nav>` Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/nav)
-#[function_component(Navbar)]
-pub fn navbar(props: &NavbarProps) -> Html {
-    let is_mobile = use_state(|| {
-        let width = web_sys::window()
-            .unwrap()
-            .inner_width()
-            .unwrap()
-            .as_f64()
-            .unwrap();
-        width <= 768.0
-    });
-
-    let is_mobile_menu_open = use_state(|| false);
-    let is_dropdown_open = use_state(|| false);
-    let is_mega_menu_open = use_state(|| false);
+#[hook]
+pub fn use_location_path() -> AttrValue {
+    #[cfg(feature = "yew-router")]
+    let routed = router::use_router_path();
+    let path = use_state_eq(|| AttrValue::from(location_path().unwrap_or_default()));
 
     {
-        let is_mobile = is_mobile.clone();
+        let path = path.setter();
         use_effect_with((), move |_| {
-            let closure = Closure::wrap(Box::new(move || {
-                let width = web_sys::window()
-                    .unwrap()
-                    .inner_width()
-                    .unwrap()
-                    .as_f64()
-                    .unwrap();
-                is_mobile.set(width <= 768.0);
-            }) as Box<dyn Fn()>);
-
-            web_sys::window()
-                .unwrap()
-                .add_event_listener_with_callback("resize", closure.as_ref().unchecked_ref())
-                .unwrap();
-
-            closure.forget();
-            || ()
+            let listener = watch_location(move |next| path.set(next.into()));
+            move || drop(listener)
         });
     }
 
-    {
-        let is_mobile_menu_open = is_mobile_menu_open.clone();
-        use_effect(move || {
-            let document = web_sys::window().unwrap().document().unwrap();
-            let listener = EventListener::new(&document, "click", move |_| {
-                is_mobile_menu_open.set(false);
-            });
-            || drop(listener)
-        });
+    #[cfg(feature = "yew-router")]
+    if let Some(routed) = routed {
+        return routed;
     }
+    (*path).clone()
+}
 
-    let toggle_mobile_menu = {
-        let state = is_mobile_menu_open.clone();
-        Callback::from(move |e: MouseEvent| {
-            e.stop_propagation();
-            state.set(!*state)
+/// Returns the suggestions of `provider` for `query`.
+///
+/// A synchronous provider answers right away. An asynchronous one is called once `query` has
+/// not changed for `debounce_ms`, reporting [`Suggestions::Loading`] meanwhile; answers to an
+/// outdated query are discarded. An empty query (or no provider) gives [`Suggestions::Idle`].
+///
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use navbar::core::{Suggestion, SuggestionProvider};
+/// use navbar::yew::use_suggestions;
+///
+/// #[function_component]
+/// fn Lookup() -> Html {
+///     let provider = use_memo((), |_| {
+///         SuggestionProvider::new(|query| vec![Suggestion::new(0, query.to_uppercase())])
+///     });
+///     let suggestions = use_suggestions(Some((*provider).clone()), "docs".into(), 300);
+///     html! { <span>{ suggestions.items().len() }</span> }
+/// }
+/// ```
+#[hook]
+pub fn use_suggestions(
+    provider: Option<SuggestionProvider>,
+    query: AttrValue,
+    debounce_ms: u32,
+) -> Suggestions {
+    let suggestions = use_state_eq(Suggestions::default);
+    let latest = use_mut_ref(|| 0usize);
+
+    {
+        let suggestions = suggestions.setter();
+        use_effect_with((provider, query), move |(provider, query)| {
+            let request = *latest.borrow();
+            let is_current = {
+                let latest = latest.clone();
+                move || *latest.borrow() == request
+            };
+            match provider {
+                Some(_) if query.trim().is_empty() => suggestions.set(Suggestions::Idle),
+                None => suggestions.set(Suggestions::Idle),
+                Some(SuggestionProvider::Sync(provider)) => {
+                    suggestions.set(Suggestions::Ready(provider(query)));
+                }
+                Some(SuggestionProvider::Async(provider)) => {
+                    suggestions.set(Suggestions::Loading);
+                    let provider = provider.clone();
+                    let query = query.to_string();
+                    yew::platform::spawn_local(async move {
+                        TimeoutFuture::new(debounce_ms).await;
+                        if !is_current() {
+                            return;
+                        }
+                        let items = provider(query).await;
+                        if is_current() {
+                            suggestions.set(Suggestions::Ready(items));
+                        }
+                    });
+                }
+            }
+            // A newer query (or unmounting) makes this request outdated.
+            move || *latest.borrow_mut() += 1
+        });
+    }
+
+    (*suggestions).clone()
+}
+
+/// Options of [`use_navbar`].
+///
+/// The fields mirror the matching [`NavbarProps`].
+#[derive(Clone, PartialEq)]
+pub struct NavbarOptions {
+    /// Viewport width, in pixels, at or below which the mobile layout is used.
+    pub mobile_breakpoint: f64,
+    /// Named breakpoint widths (`sm`, `md`, `lg`, `xl`) reported through `on_breakpoint_change`.
+    pub breakpoints: Breakpoints,
+    /// Called on mount and whenever the viewport crosses a named breakpoint or the mobile breakpoint.
+    pub on_breakpoint_change: Callback<Viewport>,
+    /// Layout of the first render, before the viewport is measured.
+    pub initial_layout: InitialLayout,
+    /// How the navbar reacts to page scroll, reported through [`UseNavbarHandle::scroll`].
+    pub scroll_behavior: ScrollBehavior,
+    /// Path whose changes close every popup. Defaults to [`use_location_path`].
+    pub current_path: Option<AttrValue>,
+    /// Popups that close on a click outside them, i.e. outside the element carrying their
+    /// [`PopupProps::id`]. All of them by default.
+    pub close_on_outside_click: Vec<Popup>,
+    /// Prefix of the popup ids, e.g. `navbar` for `navbar-mobile-menu`. Give each navbar on a
+    /// page its own prefix.
+    pub id_prefix: AttrValue,
+}
+
+impl Default for NavbarOptions {
+    fn default() -> Self {
+        Self {
+            mobile_breakpoint: MOBILE_BREAKPOINT,
+            breakpoints: Breakpoints::default(),
+            on_breakpoint_change: Callback::noop(),
+            initial_layout: InitialLayout::default(),
+            scroll_behavior: ScrollBehavior::default(),
+            current_path: None,
+            close_on_outside_click: Popup::ALL.to_vec(),
+            id_prefix: AttrValue::Static("navbar"),
+        }
+    }
+}
+
+/// Attributes and handlers of the button that opens a popup, from
+/// [`UseNavbarHandle::trigger_props`].
+#[derive(Clone, PartialEq)]
+pub struct TriggerProps {
+    /// Id of the button, which gets focus back when the popup is closed with `Escape`.
+    pub id: AttrValue,
+    /// `aria-controls`: the id of the popup.
+    pub aria_controls: AttrValue,
+    /// `aria-expanded`: `"true"` while the popup is open.
+    pub aria_expanded: &'static str,
+    /// `aria-haspopup`: `"menu"` for every popup but the mobile menu.
+    pub aria_haspopup: Option<&'static str>,
+    /// Toggles the popup.
+    pub onclick: Callback<MouseEvent>,
+    /// Opens the popup with `Enter`, `Space` or the arrow keys, and closes it with `Escape`.
+    pub onkeydown: Callback<KeyboardEvent>,
+}
+
+/// Attributes and handlers of a popup, from [`UseNavbarHandle::popup_props`].
+#[derive(Clone, PartialEq)]
+pub struct PopupProps {
+    /// Id of the popup element, which outside clicks are checked against.
+    pub id: AttrValue,
+    /// Whether the popup is open.
+    pub is_open: bool,
+    /// Item to focus once the popup opens, after a key press on its trigger.
+    pub initial_focus: Option<MenuKey>,
+    /// Closes the popup with `Escape` and returns focus to its trigger.
+    pub onkeydown: Callback<KeyboardEvent>,
+    /// Closes the popup.
+    pub on_close: Callback<()>,
+}
+
+/// State and prop-getters of a navbar, returned by [`use_navbar`].
+#[derive(Clone)]
+pub struct UseNavbarHandle {
+    state: UseReducerHandle<NavbarState>,
+    viewport: Option<Viewport>,
+    scroll: ScrollState,
+    id_prefix: AttrValue,
+    focus: UseStateHandle<Option<(Popup, MenuKey)>>,
+}
+
+impl UseNavbarHandle {
+    /// Returns the open/close state.
+    pub fn state(&self) -> NavbarState {
+        *self.state
+    }
+
+    /// Returns the measured viewport, or `None` before it is measured or outside a browser.
+    pub fn viewport(&self) -> Option<Viewport> {
+        self.viewport
+    }
+
+    /// Returns the scroll state. The navbar is never `hidden` while a popup is open.
+    pub fn scroll(&self) -> ScrollState {
+        self.scroll
+    }
+
+    /// Returns `true` if the mobile layout applies.
+    pub fn is_mobile(&self) -> bool {
+        self.state.is_mobile
+    }
+
+    /// Returns `true` if the given popup is open.
+    pub fn is_open(&self, popup: Popup) -> bool {
+        self.state.is_open(popup)
+    }
+
+    /// Applies an action to the state.
+    pub fn dispatch(&self, action: NavbarAction) {
+        self.state.dispatch(action);
+    }
+
+    /// Returns a callback that opens a popup, e.g. on hover.
+    pub fn open<T: 'static>(&self, popup: Popup) -> Callback<T> {
+        let state = self.state.dispatcher();
+        let focus = self.focus.setter();
+        Callback::from(move |_| {
+            focus.set(None);
+            state.dispatch(NavbarAction::Open(popup));
+        })
+    }
+
+    /// Returns a callback that closes a popup.
+    pub fn close<T: 'static>(&self, popup: Popup) -> Callback<T> {
+        let state = self.state.dispatcher();
+        Callback::from(move |_| state.dispatch(NavbarAction::Close(popup)))
+    }
+
+    /// Returns a callback that opens a closed popup or closes an open one.
+    pub fn toggle<T: 'static>(&self, popup: Popup) -> Callback<T> {
+        let state = self.state.dispatcher();
+        let focus = self.focus.setter();
+        Callback::from(move |_| {
+            focus.set(None);
+            state.dispatch(NavbarAction::Toggle(popup));
+        })
+    }
+
+    fn id(&self, popup: Popup) -> AttrValue {
+        popup_id(&self.id_prefix, popup).into()
+    }
+
+    fn trigger_id(&self, popup: Popup) -> AttrValue {
+        format!("{}-button", self.id(popup)).into()
+    }
+
+    /// Returns the attributes and handlers of the button that opens a popup.
+    pub fn trigger_props(&self, popup: Popup) -> TriggerProps {
+        let onkeydown = {
+            let state = self.state.dispatcher();
+            let focus = self.focus.setter();
+            let is_open = self.is_open(popup);
+            Callback::from(move |e: KeyboardEvent| {
+                let key = e.key();
+                match MenuKey::from_trigger_key(&key) {
+                    Some(MenuKey::Close) => state.dispatch(NavbarAction::Close(popup)),
+                    // Like a click, `Enter` and `Space` close an open popup.
+                    Some(_) if is_open && matches!(key.as_str(), "Enter" | " ") => {
+                        e.prevent_default();
+                        state.dispatch(NavbarAction::Close(popup));
+                    }
+                    Some(focus_key) => {
+                        e.prevent_default();
+                        focus.set(Some((popup, focus_key)));
+                        state.dispatch(NavbarAction::Open(popup));
+                    }
+                    None => {}
+                }
+            })
+        };
+        TriggerProps {
+            id: self.trigger_id(popup),
+            aria_controls: self.id(popup),
+            aria_expanded: if self.is_open(popup) { "true" } else { "false" },
+            aria_haspopup: (popup != Popup::MobileMenu).then_some("menu"),
+            onclick: self.toggle(popup),
+            onkeydown,
+        }
+    }
+
+    /// Returns the attributes and handlers of a popup.
+    pub fn popup_props(&self, popup: Popup) -> PopupProps {
+        let onkeydown = {
+            let state = self.state.dispatcher();
+            let trigger_id = self.trigger_id(popup);
+            Callback::from(move |e: KeyboardEvent| {
+                if e.key() == "Escape" {
+                    state.dispatch(NavbarAction::Close(popup));
+                    focus_element(&trigger_id);
+                }
+            })
+        };
+        PopupProps {
+            id: self.id(popup),
+            is_open: self.is_open(popup),
+            initial_focus: self
+                .focus
+                .and_then(|(focused, key)| (focused == popup).then_some(key)),
+            onkeydown,
+            on_close: self.close(popup),
+        }
+    }
+}
+
+/// Runs the behavior of a navbar without its markup.
+///
+/// The hook tracks the viewport against the breakpoints, holds the [`NavbarState`] of the
+/// popups, follows the page scroll, closes every popup when the path changes and, for those
+/// listed in `close_on_outside_click`, on a click outside the open one. The returned handle
+/// reads the state and hands out the attributes and handlers of each popup and of the button
+/// that opens it: [`UseNavbarHandle::trigger_props`] and [`UseNavbarHandle::popup_props`].
+/// [`Navbar`] is the default rendering built on this hook.
+///
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use navbar::core::Popup;
+/// use navbar::yew::{NavbarOptions, use_navbar};
+///
+/// #[function_component]
+/// fn Header() -> Html {
+///     let navbar = use_navbar(NavbarOptions::default());
+///     let trigger = navbar.trigger_props(Popup::MobileMenu);
+///     let menu = navbar.popup_props(Popup::MobileMenu);
+///     html! {
+///         <header>
+///             <button
+///                 id={trigger.id}
+///                 aria-controls={trigger.aria_controls}
+///                 aria-expanded={trigger.aria_expanded}
+///                 onclick={trigger.onclick}
+///                 onkeydown={trigger.onkeydown}
+///             >
+///                 { "Menu" }
+///             </button>
+///             if menu.is_open {
+///                 <ul id={menu.id} onkeydown={menu.onkeydown}>
+///                     <li><a href="/">{ "Home" }</a></li>
+///                 </ul>
+///             }
+///         </header>
+///     }
+/// }
+///
+/// let html = futures::executor::block_on(yew::LocalServerRenderer::<Header>::new().render());
+/// assert!(html.contains("aria-expanded=\"false\""));
+/// assert!(!html.contains("Home"));
+/// ```
+#[hook]
+pub fn use_navbar(options: NavbarOptions) -> UseNavbarHandle {
+    let viewport = use_viewport(options.mobile_breakpoint, options.breakpoints);
+    let state = use_reducer_eq(|| NavbarState::new(options.initial_layout.is_mobile(viewport)));
+    let scroll = use_scroll_state(options.scroll_behavior);
+    // An open menu keeps the navbar in view.
+    let scroll = ScrollState {
+        hidden: scroll.hidden && !state.any_open(),
+        ..scroll
+    };
+
+    {
+        let state = state.dispatcher();
+        let on_breakpoint_change = options.on_breakpoint_change.clone();
+        use_effect_with(viewport, move |viewport| {
+            if let Some(viewport) = *viewport {
+                state.dispatch(NavbarAction::SetMobile(viewport.is_mobile));
+                on_breakpoint_change.emit(viewport);
+            }
+        });
+    }
+
+    // Client-side navigation keeps the navbar mounted; close whatever led to the new page.
+    let location = use_location_path();
+    {
+        let state = state.dispatcher();
+        let path = options.current_path.clone().unwrap_or(location);
+        use_effect_with(path, move |_| state.dispatch(NavbarAction::CloseAll));
+    }
+
+    let handle = UseNavbarHandle {
+        viewport,
+        scroll,
+        id_prefix: options.id_prefix.clone(),
+        focus: use_state_eq(|| None),
+        state,
+    };
+    let outside =
+        |popup: Popup| options.close_on_outside_click.contains(&popup) && handle.is_open(popup);
+    use_click_outside(
+        handle.id(Popup::MobileMenu),
+        outside(Popup::MobileMenu),
+        handle.close(Popup::MobileMenu),
+    );
+    use_click_outside(
+        handle.id(Popup::Dropdown),
+        outside(Popup::Dropdown),
+        handle.close(Popup::Dropdown),
+    );
+    use_click_outside(
+        handle.id(Popup::MegaMenu),
+        outside(Popup::MegaMenu),
+        handle.close(Popup::MegaMenu),
+    );
+    use_click_outside(
+        handle.id(Popup::Notifications),
+        outside(Popup::Notifications),
+        handle.close(Popup::Notifications),
+    );
+    handle
+}
+
+/// Returns `base` with `extra` appended when `active` is `true`.
+fn with_active(base: &str, extra: &str, active: bool) -> String {
+    if active {
+        format!("{base} {extra}")
+    } else {
+        base.to_string()
+    }
+}
+
+/// Returns `base` followed by the style or class of a transition phase, if any.
+fn with_phase(base: &str, extra: &str) -> String {
+    with_active(base, extra, !extra.is_empty())
+}
+
+/// Returns a callback that calls `callback` for pointer events from a mouse only, e.g. to open
+/// a menu on hover but not on a tap, which is followed by a click that toggles it.
+fn on_mouse(callback: Callback<()>) -> Callback<PointerEvent> {
+    Callback::from(move |e: PointerEvent| {
+        if e.pointer_type() == "mouse" {
+            callback.emit(());
+        }
+    })
+}
+
+/// Returns an element id that is unique to this component instance.
+///
+/// Under a [`Navbar`], the id comes from its `id_prefix` and stays the same between a server
+/// render and hydration.
+#[hook]
+fn use_element_id(name: &'static str) -> AttrValue {
+    let scope = use_context::<IdScope>();
+    (*use_state(|| AttrValue::from(element_id(scope.as_ref(), name)))).clone()
+}
+
+/// Keyboard handling of a WAI-ARIA menu button and its vertical menu, returned by
+/// [`use_menu_button`].
+struct MenuButton {
+    /// Handles keys on the button: arrows open the menu onto an item, `Escape` closes it.
+    on_button_keydown: Callback<KeyboardEvent>,
+    id: AttrValue,
+    button_id: AttrValue,
+    len: usize,
+    on_close: Callback<()>,
+}
+
+impl MenuButton {
+    /// Returns the key handler of the item at `index`, which moves focus between the items and
+    /// closes the menu on `Escape` (returning focus to the button) or `Tab`.
+    fn on_item_keydown(&self, index: usize) -> Callback<KeyboardEvent> {
+        let id = self.id.clone();
+        let button_id = self.button_id.clone();
+        let len = self.len;
+        let on_close = self.on_close.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            match MenuKey::from_key(&e.key(), Orientation::Vertical) {
+                Some(MenuKey::Close) => {
+                    e.prevent_default();
+                    on_close.emit(());
+                    focus_element(&button_id);
+                }
+                Some(MenuKey::Leave) => on_close.emit(()),
+                Some(key) => {
+                    let moved = focus_menu_item(&id, key, Some(index), len);
+                    if moved {
+                        e.prevent_default();
+                    }
+                }
+                None => {}
+            }
+        })
+    }
+}
+
+/// Options of [`use_menu_button`].
+struct MenuButtonOptions {
+    /// Id of the menu; the button's id is `{id}-button`.
+    id: AttrValue,
+    /// Number of items in the menu.
+    len: usize,
+    is_open: bool,
+    /// Whether the open menu is mounted and can take focus.
+    shown: bool,
+    initial_focus: Option<MenuKey>,
+    on_open: Callback<()>,
+    on_close: Callback<()>,
+}
+
+/// Wires the keyboard of a menu button to the items of its menu, as used by [`ProfileMenu`] and
+/// [`NotificationMenu`].
+///
+/// Once the menu is shown, the item picked by the key that opened it, or else `initial_focus`,
+/// is focused.
+#[hook]
+fn use_menu_button(options: MenuButtonOptions) -> MenuButton {
+    let MenuButtonOptions {
+        id,
+        len,
+        is_open,
+        shown,
+        initial_focus,
+        on_open,
+        on_close,
+    } = options;
+    // Item to focus once the menu opens after a key press on the button.
+    let pending_focus = use_mut_ref(|| None::<MenuKey>);
+    {
+        let pending_focus = pending_focus.clone();
+        let id = id.clone();
+        use_effect_with((initial_focus, shown), move |&(focus, shown)| {
+            if !shown {
+                return;
+            }
+            if let Some(key) = pending_focus.borrow_mut().take().or(focus) {
+                focus_menu_item(&id, key, None, len);
+            }
+        });
+    }
+
+    let on_button_keydown = {
+        let id = id.clone();
+        let on_close = on_close.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let key = e.key();
+            match MenuKey::from_trigger_key(&key) {
+                Some(MenuKey::Close) => on_close.emit(()),
+                Some(focus) => {
+                    // Enter and Space also click the button, which toggles the menu.
+                    let clicks = matches!(key.as_str(), "Enter" | " ");
+                    if !clicks {
+                        e.prevent_default();
+                    }
+                    if !is_open {
+                        *pending_focus.borrow_mut() = Some(focus);
+                        if !clicks {
+                            on_open.emit(());
+                        }
+                    } else if !clicks {
+                        focus_menu_item(&id, focus, None, len);
+                    }
+                }
+                None => {}
+            }
+        })
+    };
+
+    MenuButton {
+        on_button_keydown,
+        button_id: format!("{id}-button").into(),
+        id,
+        len,
+        on_close,
+    }
+}
+
+/// Navbar Component
+///
+/// A responsive and interactive navigation bar component built using Yew. It adapts to screen size
+/// changes and supports features such as a mobile toggle menu, dropdowns, mega menu, search input,
+/// and profile menu. The component is highly configurable through styles and class properties.
+///
+/// # Features
+///
+/// - **Responsive Design**:
+///   - Automatically switches to mobile view if `window.innerWidth() <= mobile_breakpoint` (768px by default).
+///   - The viewport is observed through [`use_viewport`], which uses `matchMedia` change events
+///     throttled to animation frames and removes its listeners on unmount.
+///   - `on_breakpoint_change` reports named breakpoint (`sm`/`md`/`lg`/`xl`) and layout changes.
+///   - `initial_layout` sets the layout rendered on the server and on the hydrating render,
+///     before the viewport is measured, and element ids start with `id_prefix`, so both renders
+///     produce the same markup.
+///   - `css_responsive` renders both layouts and switches between them with media queries, with
+///     a `<details>` disclosure for the mobile menu until the app hydrates.
+///
+/// - **Scroll Behavior**:
+///   - `scroll_behavior` keeps the navbar static (default) or sticky, hides it while scrolling down
+///     and reveals it on the way up, or adds `scrolled_style`/`scrolled_class` past a threshold.
+///   - Scrolling is tracked through [`use_scroll_state`], with a passive listener throttled to
+///     animation frames that is removed on unmount.
+///
+/// - **Theming**:
+///   - `theme` sets the navbar colors as CSS custom properties on the `<nav>`, which every default
+///     style refers to; see [`NavbarTheme`].
+///   - `show_theme_toggle` adds a light/dark/system [`ThemeToggle`] that switches the navbar to
+///     `dark_theme`, marks `<html>` with `data-theme` and remembers the choice.
+///
+/// - **Transitions**:
+///   - `mobile_menu_transition`, `dropdown_transition`, `mega_menu_transition` and
+///     `notifications_transition` animate the menus in and out, e.g. with [`Transition::fade`]
+///     or [`Transition::slide_down`].
+///   - Closed menus stay mounted until their exit transition ends; motion is skipped when the
+///     user prefers reduced motion. See [`use_transition`].
+///
+/// - **Mobile Menu Toggle**:
+///   - A hamburger button appears on smaller screens, with `aria-expanded` and `aria-controls`
+///     bound to the menu; `menu_toggle_animation` morphs its icon into an X or an arrow.
+///   - Opens a collapsible menu containing navigation links, search bar, and profile dropdown (if enabled).
+///   - Clicking outside the mobile menu, profile dropdown or mega menu closes it (see [`use_click_outside`]).
+///   - `mobile_variant` shows the menu as a left or right drawer or a full-screen sheet instead,
+///     over a backdrop, with focus trapped inside and the page scroll locked while it is open.
+///
+/// - **Dropdown Menu**:
+///   - Profile dropdown toggled on user click.
+///   - Can be styled via dropdown props.
+///
+/// - **Notifications**:
+///   - With `show_notifications`, a bell with an unread badge (capped at `"99+"`) opens a
+///     [`NotificationMenu`] listing `notifications`, with "Mark all read" and per-item callbacks.
+///   - Shares the profile dropdown's keyboard and outside-click behavior, and shows as a section
+///     of the mobile menu.
+///
+/// - **Mega Menu**:
+///   - Shown on mouse hover when `show_mega_menu` is true, or from the keyboard on the "More" button.
+///   - Items appear in a grid-style layout under the "More" button.
+///
+/// - **Keyboard Navigation** (WAI-ARIA menubar pattern):
+///   - Arrow keys, `Home` and `End` move between top-level items, with a roving `tabindex`.
+///   - `Enter`, `Space` or `ArrowDown` on "More" or the profile button open the menu and focus its
+///     first item (`ArrowUp` focuses the last); arrow keys then move within the menu.
+///   - `Escape` closes the menu and returns focus to its trigger; `Tab` closes it.
+///
+/// - **Active Route**:
+///   - The item whose `link` matches the current path (`active_match`: exact, prefix or custom)
+///     gets `aria-current="page"` and `active_style`/`active_class`.
+///   - The path defaults to `window.location.pathname`; pass `current_path` when using a router.
+///
+/// - **Search Input**:
+///   - Optional text input for searching, shared by the desktop and mobile layouts.
+///   - Supports local state or controlled component mode via `search_state`.
+///   - `on_search_submit` fires on `Enter` (or the optional search button), and
+///     `on_search_change` fires once typing pauses for `search_debounce_ms`.
+///   - With a `suggestions` provider (sync or async), a listbox of suggestions opens under the
+///     input (a WAI-ARIA combobox), with the matched text highlighted and loading/empty states.
+///   - With `search_items`, the navbar suggests its own items instead, fuzzy-matched and ranked,
+///     and choosing one navigates to its link.
+///
+/// - **Command Palette**:
+///   - With `show_command_palette`, `Ctrl+K` / `Cmd+K` opens a [`CommandPalette`] listing every
+///     menu, dropdown and mega menu item plus the app's `commands`, fuzzy-filtered and grouped
+///     by section, with recently used commands first.
+///
+/// - **Call-to-Action Button**:
+///   - An optional button beside the menu that links to an external/internal page.
+///
+/// - **Custom Layout**:
+///   - `start`, `center` and `end` slots take arbitrary markup around the built-in parts, and
+///     `mobile_extra` goes at the bottom of the mobile menu.
+///   - `parts` orders the built-in parts ([`NavbarPart`]); a part left out is hidden.
+///
+/// # Examples
+///
+/// ## Basic Navbar
+/// ```rust
+/// use yew::prelude::*;
+/// use navbar::yew::{Menu, MegaMenuItem, Navbar};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <Navbar menus={vec![
+///             Menu::new(1, "Home", "/home"),
+///         ]} />
+///     }
+/// }
+/// ```
+///
+/// ## With Profile Menu and Search
+/// ```rust
+/// use yew::prelude::*;
+/// use navbar::yew::{Menu, MegaMenuItem, Navbar, DropdownItem};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <Navbar
+///             show_search={true}
+///             search_placeholder={"Search..."}
+///             show_profile_menu={true}
+///             profile_button_text={"Profile"}
+///             dropdown_items={vec![
+///                 DropdownItem::new(1, "Settings", "/settings"),
+///                 DropdownItem::new(2, "Logout", "/logout"),
+///             ]}
+///             menus={vec![
+///                 Menu::new(1, "Dashboard", "/dashboard"),
+///             ]}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## With Mega Menu
+/// ```rust
+/// use yew::prelude::*;
+/// use navbar::yew::{Menu, MegaMenuItem, Navbar};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <Navbar
+///             show_mega_menu={true}
+///             more_button_text={"Explore"}
+///             mega_menu_items={vec![
+///                 MegaMenuItem::new("Docs", "Learn more about our APIs", "/docs"),
+///                 MegaMenuItem::new("Blog", "See what we're up to", "/blog"),
+///             ]}
+///             menus={vec![
+///                 Menu::new(1, "Platform", "/platform"),
+///             ]}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Server-Side Rendering
+/// The navbar never touches `window` while rendering, so it renders under `ServerRenderer` (or
+/// in host-side tests). `initial_layout` picks the layout of the server markup; the hydrated
+/// navbar switches to the measured viewport once mounted. Element ids start with `id_prefix`,
+/// so every render of the same navbar produces the same markup.
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::LocalServerRenderer;
+/// use navbar::core::InitialLayout;
+/// use navbar::yew::{Menu, Navbar};
+///
+/// #[derive(Properties, PartialEq)]
+/// struct AppProps {
+///     user_agent: AttrValue,
+/// }
+///
+/// #[function_component]
+/// fn App(props: &AppProps) -> Html {
+///     html! {
+///         <Navbar
+///             initial_layout={InitialLayout::UserAgent(props.user_agent.to_string().into())}
+///             menus={vec![Menu::new(1, "Home", "/home")]}
+///         />
+///     }
+/// }
+///
+/// let render = |user_agent: &'static str| {
+///     let props = AppProps { user_agent: user_agent.into() };
+///     futures::executor::block_on(LocalServerRenderer::<App>::with_props(props).render())
+/// };
+///
+/// let desktop = render("Mozilla/5.0 (X11; Linux x86_64) Chrome/124.0");
+/// assert!(desktop.contains("Home"));
+/// assert!(!desktop.contains("aria-expanded=\"false\""));
+///
+/// let mobile = render("Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) Mobile/15E148");
+/// assert!(mobile.contains("aria-expanded=\"false\""));
+/// assert!(!mobile.contains("Home"));
+/// assert!(mobile.contains("aria-controls=\"navbar-mobile-menu\""));
+/// assert!(mobile.contains("id=\"navbar-mobile-menu-button\""));
+/// assert_eq!(mobile, render("Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) Mobile/15E148"));
+/// ```
+///
+/// ## CSS Responsive Mode
+/// With `css_responsive`, the markup holds both layouts and a generated stylesheet picks one, so
+/// the server does not need to guess:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::LocalServerRenderer;
+/// use navbar::yew::{Menu, Navbar};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! { <Navbar css_responsive=true menus={vec![Menu::new(1, "Home", "/home")]} /> }
+/// }
+///
+/// let html = futures::executor::block_on(LocalServerRenderer::<App>::new().render());
+/// assert!(html.contains("@media (max-width: 768px)"));
+/// assert!(html.contains("<details"));
+/// assert!(html.contains("<summary"));
+/// assert_eq!(html.matches("Home").count(), 2);
+/// ```
+///
+/// ## Custom Layout
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::LocalServerRenderer;
+/// use navbar::core::{InitialLayout, NavbarPart};
+/// use navbar::yew::{Menu, Navbar};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <Navbar
+///             initial_layout={InitialLayout::Desktop}
+///             show_search=true
+///             parts={vec![NavbarPart::Search, NavbarPart::Menu]}
+///             start={html! { <span>{ "Workspace" }</span> }}
+///             end={html! { <a href="/cart">{ "Cart" }</a> }}
+///             menus={vec![Menu::new(1, "Home", "/home")]}
+///         />
+///     }
+/// }
+///
+/// let html = futures::executor::block_on(LocalServerRenderer::<App>::new().render());
+/// let position = |needle: &str| html.find(needle).unwrap();
+/// assert!(position("Workspace") < position("<input"));
+/// assert!(position("<input") < position("Home"));
+/// assert!(position("Home") < position("Cart"));
+/// assert!(!html.contains("Sign up"));
+/// ```
+///
+/// # Notes
+/// - Open/close behavior is driven by the shared [`NavbarState`] machine through [`use_navbar`], the
+///   same hook that headless navbars render from.
+/// - Subcomponents include `NavbarMenu`, `MegaMenu`, `ProfileMenu`, `MobileMenu`, and `NavbarToggle`.
+/// - This component is customizable and works well with Tailwind CSS or other utility-first CSS frameworks.
+///
+/// # See Also
+/// - [MDN `<nav>` Element](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/nav)
+#[function_component(Navbar)]
+pub fn navbar(props: &NavbarProps) -> Html {
+    let navbar = use_navbar(NavbarOptions {
+        mobile_breakpoint: props.mobile_breakpoint,
+        breakpoints: props.breakpoints,
+        on_breakpoint_change: props.on_breakpoint_change.clone(),
+        initial_layout: props.initial_layout.clone(),
+        scroll_behavior: props.scroll_behavior,
+        current_path: props.current_path.clone(),
+        close_on_outside_click: [
+            (Popup::MobileMenu, props.mobile_menu_close_on_outside_click),
+            (Popup::Dropdown, props.dropdown_close_on_outside_click),
+            (Popup::MegaMenu, props.mega_menu_close_on_outside_click),
+            (
+                Popup::Notifications,
+                props.notifications_close_on_outside_click,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(popup, closes)| closes.then_some(popup))
+        .collect(),
+        id_prefix: props.id_prefix.clone(),
+    });
+    let state = navbar.state();
+    let scroll = navbar.scroll();
+    let hamburger = navbar.trigger_props(Popup::MobileMenu);
+    let mobile_menu = navbar.popup_props(Popup::MobileMenu);
+    let profile = navbar.trigger_props(Popup::Dropdown);
+    let dropdown = navbar.popup_props(Popup::Dropdown);
+    let more = navbar.trigger_props(Popup::MegaMenu);
+    let mega_menu = navbar.popup_props(Popup::MegaMenu);
+    let bell = navbar.trigger_props(Popup::Notifications);
+    let feed = navbar.popup_props(Popup::Notifications);
+    let disclosure_id = AttrValue::from(format!("{}-disclosure", props.id_prefix));
+    let id_scope = use_memo(props.id_prefix.clone(), |prefix| {
+        IdScope::new(prefix.as_str())
+    });
+
+    // In the CSS responsive mode, the `<details>` opens natively until the navbar is mounted;
+    // from then on, the state drives the menu, starting from whatever the user left open.
+    let hydrated = use_state_eq(|| false);
+    {
+        let hydrated = hydrated.setter();
+        let navbar = navbar.clone();
+        let disclosure_id = disclosure_id.clone();
+        use_effect_with(props.css_responsive, move |&css_responsive| {
+            if css_responsive && is_details_open(&disclosure_id) {
+                navbar.dispatch(NavbarAction::Open(Popup::MobileMenu));
+            }
+            hydrated.set(true);
+        });
+    }
+    let show_desktop = props.css_responsive || !state.is_mobile;
+    let show_mobile = props.css_responsive || state.is_mobile;
+    let item_suggestions = use_memo(
+        (
+            props.search_items,
+            props.menus.clone(),
+            props.dropdown_items.clone(),
+            props.mega_menu_items.clone(),
+        ),
+        |(enabled, menus, dropdown_items, mega_menu_items)| {
+            enabled.then(|| {
+                let index = SearchIndex::new()
+                    .with_menus(menus)
+                    .with_dropdown_items(dropdown_items)
+                    .with_mega_menu_items(mega_menu_items);
+                SuggestionProvider::new(move |query| index.search(query))
+            })
+        },
+    );
+    let suggestions = props
+        .suggestions
+        .clone()
+        .or_else(|| (*item_suggestions).clone());
+    let search_state = props
+        .search_state
+        .clone()
+        .unwrap_or(use_state(|| "".to_string()));
+    let search_val = AttrValue::from((*search_state).clone());
+    let dark = use_state_eq(|| false);
+    let on_theme_change = {
+        let dark = dark.setter();
+        let on_theme_change = props.on_theme_change.clone();
+        Callback::from(move |preference: ThemePreference| {
+            dark.set(preference.is_dark());
+            on_theme_change.emit(preference);
         })
     };
+    let theme = if props.show_theme_toggle && *dark {
+        &props.dark_theme
+    } else {
+        &props.theme
+    };
+    let debounce = use_mut_ref(Debounce::default);
+    let on_search_input = {
+        let on_search_change = props.on_search_change.clone();
+        let delay = props.search_debounce_ms;
+        Callback::from(move |value: String| {
+            search_state.set(value.clone());
+            let on_search_change = on_search_change.clone();
+            debounce
+                .borrow()
+                .call(delay, move || on_search_change.emit(value));
+        })
+    };
+    let show_part = |part| props.parts.contains(&part);
+    let render_part = |part: NavbarPart| match part {
+        NavbarPart::Menu => html! {
+            <NavbarMenu
+                menus={props.menus.clone()}
+                menu_item_style={props.menu_item_style}
+                menu_item_class={props.menu_item_class}
+                submenu_style={props.submenu_style}
+                submenu_class={props.submenu_class}
+                current_path={props.current_path.clone()}
+                active_match={props.active_match.clone()}
+                active_style={props.active_style}
+                active_class={props.active_class}
+            />
+        },
+        NavbarPart::MegaMenu if props.show_mega_menu => html! {
+            <div
+                onpointerenter={on_mouse(navbar.open(Popup::MegaMenu))}
+                onkeydown={mega_menu.onkeydown.clone()}
+                style="position: relative; display: inline-block;"
+            >
+                <button
+                    id={more.id.clone()}
+                    type="button"
+                    onclick={more.onclick.clone()}
+                    onkeydown={more.onkeydown.clone()}
+                    aria-controls={more.aria_controls.clone()}
+                    aria-haspopup={more.aria_haspopup}
+                    aria-expanded={more.aria_expanded}
+                    style={props.more_button_style}
+                    class={props.more_button_class}
+                >
+                    { props.more_button_text.clone() }
+                </button>
+                <div onpointerleave={on_mouse(navbar.close(Popup::MegaMenu))}>
+                    <MegaMenu
+                        id={mega_menu.id.clone()}
+                        is_open={mega_menu.is_open}
+                        transition={props.mega_menu_transition.clone()}
+                        items={props.mega_menu_items.clone()}
+                        wrapper_style={props.mega_menu_style}
+                        wrapper_class={props.mega_menu_class}
+                        card_style={props.mega_menu_card_style}
+                        card_class={props.mega_menu_card_class}
+                        close_on_outside_click=false
+                        on_close={mega_menu.on_close.clone()}
+                        initial_focus={mega_menu.initial_focus}
+                    />
+                </div>
+            </div>
+        },
+        NavbarPart::Search if props.show_search => html! {
+            <NavbarSearch
+                value={search_val.clone()}
+                placeholder={props.search_placeholder.clone()}
+                input_style={props.search_input_style}
+                input_class={props.search_input_class}
+                on_input={on_search_input.clone()}
+                on_submit={props.on_search_submit.clone()}
+                show_button={props.show_search_button}
+                button_text={props.search_button_text.clone()}
+                button_style={props.search_button_style}
+                button_class={props.search_button_class}
+                suggestions={suggestions.clone()}
+                on_suggestion_select={props.on_suggestion_select.clone()}
+                debounce_ms={props.search_debounce_ms}
+                suggestions_loading_text={props.suggestions_loading_text.clone()}
+                suggestions_empty_text={props.suggestions_empty_text.clone()}
+                suggestions_style={props.suggestions_style}
+                suggestions_class={props.suggestions_class}
+                suggestion_style={props.suggestion_style}
+                suggestion_class={props.suggestion_class}
+                suggestion_active_style={props.suggestion_active_style}
+                suggestion_active_class={props.suggestion_active_class}
+            />
+        },
+        NavbarPart::Button if !props.button_text.is_empty() => html! {
+            <NavbarButton
+                href={props.button_href.clone()}
+                text={props.button_text.clone()}
+                button_style={props.button_style}
+                button_class={props.button_class}
+                link_style={props.button_link_style}
+                link_class={props.button_link_class}
+                target={props.button_target.clone()}
+            />
+        },
+        NavbarPart::Notifications if props.show_notifications => html! {
+            <NotificationMenu
+                items={props.notifications.clone()}
+                id={feed.id.clone()}
+                is_open={feed.is_open}
+                initial_focus={feed.initial_focus}
+                toggle={bell.onclick.clone()}
+                on_button_keydown={bell.onkeydown.clone()}
+                on_close={feed.on_close.clone()}
+                on_select={props.on_notification_select.clone()}
+                on_mark_all_read={props.on_mark_all_read.clone()}
+                badge_max={props.notifications_badge_max}
+                label={props.notifications_label.clone()}
+                mark_all_read_text={props.mark_all_read_text.clone()}
+                transition={props.notifications_transition.clone()}
+                close_on_outside_click=false
+            />
+        },
+        NavbarPart::Profile if props.show_profile_menu => html! {
+            <ProfileMenu
+                profile_image_url={props.profile_image_url.clone()}
+                items={props.dropdown_items.clone()}
+                dropdown_style={props.dropdown_style}
+                dropdown_class={props.dropdown_class}
+                item_style={props.dropdown_item_style}
+                item_class={props.dropdown_item_class}
+                id={dropdown.id.clone()}
+                is_open={dropdown.is_open}
+                initial_focus={dropdown.initial_focus}
+                transition={props.dropdown_transition.clone()}
+                toggle={profile.onclick.clone()}
+                on_button_keydown={profile.onkeydown.clone()}
+                current_path={props.current_path.clone()}
+                active_match={props.active_match.clone()}
+                active_style={props.active_style}
+                active_class={props.active_class}
+                profile_text={props.profile_button_text.clone()}
+                close_on_outside_click=false
+                on_close={dropdown.on_close.clone()}
+            />
+        },
+        NavbarPart::ThemeToggle if props.show_theme_toggle => html! {
+            <ThemeToggle
+                storage_key={props.theme_storage_key.clone()}
+                on_theme_change={on_theme_change.clone()}
+            />
+        },
+        _ => html! {},
+    };
+
+    html! {
+        <ContextProvider<IdScope> context={(*id_scope).clone()}>
+            <nav
+                style={with_phase(
+                    &theme.css_variables(),
+                    &scroll.nav_style(props.scroll_behavior, props.navbar_style, props.scrolled_style),
+                )}
+                class={with_active(props.navbar_class, props.scrolled_class, scroll.scrolled)}
+                data-navbar-breakpoint={props.css_responsive.then(|| props.mobile_breakpoint.to_string())}
+            >
+                if props.css_responsive {
+                    <style>{ responsive_css(props.mobile_breakpoint) }</style>
+                }
+                <div style={props.container_style} class={props.container_class}>
+                    <NavbarLogo
+                        logo_src={props.logo_src.clone()}
+                        logo_alt={props.logo_alt.clone()}
+                        logo_link={props.logo_link.clone()}
+                        logo_style={props.logo_style}
+                        logo_class={props.logo_class}
+                    />
+                    { props.start.clone() }
+                    { props.center.clone() }
+                    <div
+                        style={props.inner_style}
+                        class={with_active(props.inner_class, DESKTOP_CLASS, props.css_responsive)}
+                    >
+                        if show_desktop {
+                            { for props.parts.iter().map(|&part| render_part(part)) }
+                        }
+                    </div>
+                    { props.end.clone() }
+                    // The mobile layout has no parts row, so the toggle follows `end` there.
+                    if show_mobile && props.show_theme_toggle && show_part(NavbarPart::ThemeToggle) {
+                        <ThemeToggle
+                            storage_key={props.theme_storage_key.clone()}
+                            on_theme_change={on_theme_change.clone()}
+                            class={if props.css_responsive { MOBILE_CLASS } else { "" }}
+                        />
+                    }
+                </div>
+                <@{if props.css_responsive { "details" } else { "div" }}
+                    id={props.css_responsive.then(|| disclosure_id.clone())}
+                    open={props.css_responsive && *hydrated}
+                    style="position: relative; display: inline-block;"
+                    class={props.css_responsive.then_some(MOBILE_CLASS)}
+                >
+                    if show_mobile {
+                        <NavbarToggle
+                            id={hamburger.id.clone()}
+                            onclick={hamburger.onclick.clone()}
+                            onkeydown={hamburger.onkeydown.clone()}
+                            open={mobile_menu.is_open}
+                            disclosure={props.css_responsive}
+                            controls={hamburger.aria_controls.clone()}
+                            label={props.menu_toggle_label.clone()}
+                            animation={props.menu_toggle_animation}
+                            toggle_style={props.menu_toggle_style}
+                            toggle_class={props.menu_toggle_class}
+                            line_style={props.line_style}
+                            line_class={props.line_class}
+                        />
+                    }
+                    if show_mobile {
+                        <MobileMenu
+                            id={mobile_menu.id.clone()}
+                            is_open={mobile_menu.is_open}
+                            initial_focus={mobile_menu.initial_focus}
+                            onkeydown={mobile_menu.onkeydown.clone()}
+                            keep_mounted={props.css_responsive && !*hydrated}
+                            transition={props.mobile_menu_transition.clone().or_else(|| props.mobile_variant.transition(200))}
+                            variant={props.mobile_variant}
+                            backdrop_style={props.mobile_backdrop_style}
+                            backdrop_class={props.mobile_backdrop_class}
+                            menus={if show_part(NavbarPart::Menu) { props.menus.clone() } else { Vec::new() }}
+                            menu_item_style={props.menu_item_style}
+                            menu_item_class={props.menu_item_class}
+                            submenu_class={props.submenu_class}
+                            dropdown_items={props.dropdown_items.clone()}
+                            dropdown_style={props.dropdown_style}
+                            dropdown_class={props.dropdown_class}
+                            dropdown_item_style={props.dropdown_item_style}
+                            dropdown_item_class={props.dropdown_item_class}
+                            show_profile_menu={props.show_profile_menu && show_part(NavbarPart::Profile)}
+                            show_search={props.show_search && show_part(NavbarPart::Search)}
+                            show_notifications={props.show_notifications && show_part(NavbarPart::Notifications)}
+                            notifications={props.notifications.clone()}
+                            on_notification_select={props.on_notification_select.clone()}
+                            on_mark_all_read={props.on_mark_all_read.clone()}
+                            notifications_label={props.notifications_label.clone()}
+                            notifications_badge_max={props.notifications_badge_max}
+                            mark_all_read_text={props.mark_all_read_text.clone()}
+                            search_input_style={props.search_input_style}
+                            search_input_class={props.search_input_class}
+                            search_placeholder={props.search_placeholder.clone()}
+                            search_value={search_val}
+                            on_search_input={on_search_input}
+                            on_search_submit={props.on_search_submit.clone()}
+                            show_search_button={props.show_search_button}
+                            search_button_text={props.search_button_text.clone()}
+                            search_button_style={props.search_button_style}
+                            search_button_class={props.search_button_class}
+                            suggestions={suggestions}
+                            on_suggestion_select={props.on_suggestion_select.clone()}
+                            search_debounce_ms={props.search_debounce_ms}
+                            suggestions_loading_text={props.suggestions_loading_text.clone()}
+                            suggestions_empty_text={props.suggestions_empty_text.clone()}
+                            suggestions_style={props.suggestions_style}
+                            suggestions_class={props.suggestions_class}
+                            suggestion_style={props.suggestion_style}
+                            suggestion_class={props.suggestion_class}
+                            suggestion_active_style={props.suggestion_active_style}
+                            suggestion_active_class={props.suggestion_active_class}
+                            close_on_outside_click=false
+                            on_close={mobile_menu.on_close.clone()}
+                            current_path={props.current_path.clone()}
+                            active_match={props.active_match.clone()}
+                            active_style={props.active_style}
+                            active_class={props.active_class}
+                            extra={props.mobile_extra.clone()}
+                        />
+                    }
+                </@>
+                if props.show_command_palette {
+                    <CommandPalette
+                        menus={props.menus.clone()}
+                        dropdown_items={props.dropdown_items.clone()}
+                        mega_menu_items={props.mega_menu_items.clone()}
+                        commands={props.commands.clone()}
+                        shortcut={props.command_palette_shortcut.clone()}
+                        on_select={props.on_command_select.clone()}
+                    />
+                }
+            </nav>
+        </ContextProvider<IdScope>>
+    }
+}
+
+/// Properties for rendering a logo inside a navigation bar.
+#[derive(Properties, PartialEq)]
+pub struct LogoProps {
+    /// Path to the logo image.
+    #[prop_or(AttrValue::Static("/logo.png"))]
+    pub logo_src: AttrValue,
+    /// Alternative text for the logo image.
+    #[prop_or(AttrValue::Static("Logo"))]
+    pub logo_alt: AttrValue,
+    /// URL to redirect when the logo is clicked.
+    #[prop_or(AttrValue::Static("/"))]
+    pub logo_link: AttrValue,
+    /// Inline CSS style for the logo image.
+    #[prop_or("height: 40px;")]
+    pub logo_style: &'static str,
+    /// Inline CSS style for the link wrapping the logo.
+    #[prop_or("display: inline-block;")]
+    pub link_style: &'static str,
+    /// Optional class name for the logo image.
+    #[prop_or_default]
+    pub logo_class: String,
+    /// Optional class name for the anchor tag wrapping the logo.
+    #[prop_or_default]
+    pub link_class: String,
+}
+
+#[function_component(NavbarLogo)]
+fn navbar_logo(props: &LogoProps) -> Html {
+    let router = use_link_router();
+    html! {
+        <a
+            href={router.href(&props.logo_link)}
+            onclick={router.onclick(&props.logo_link, "")}
+            style={props.link_style} class={props.link_class.clone()}>
+            <img
+                src={props.logo_src.clone()}
+                alt={props.logo_alt.clone()}
+                style={props.logo_style}
+                class={props.logo_class.clone()}
+            />
+        </a>
+    }
+}
+
+/// Properties for rendering a hamburger toggle icon (used in responsive menus).
+#[derive(Properties, PartialEq)]
+pub struct ToggleProps {
+    /// Callback triggered when the toggle is clicked.
+    pub onclick: Callback<MouseEvent>,
+    /// Key presses on the toggle, e.g. [`TriggerProps::onkeydown`].
+    #[prop_or_default]
+    pub onkeydown: Callback<KeyboardEvent>,
+    /// Id of the toggle, e.g. [`TriggerProps::id`], which gets focus back when the menu is
+    /// closed with `Escape`.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    /// Whether the menu the toggle controls is open, exposed as `aria-expanded`.
+    #[prop_or(false)]
+    pub open: bool,
+    /// Id of the menu the toggle controls, exposed as `aria-controls`.
+    #[prop_or_default]
+    pub controls: Option<AttrValue>,
+    /// Accessible label (`aria-label`) of the toggle button.
+    #[prop_or(AttrValue::Static("Toggle Menu"))]
+    pub label: AttrValue,
+    /// How the icon animates when the menu opens.
+    #[prop_or_default]
+    pub animation: ToggleAnimation,
+    /// Renders the toggle as the `<summary>` of a surrounding `<details>`, which opens and closes
+    /// without JavaScript until `onclick` takes over (its default action is then prevented).
+    #[prop_or(false)]
+    pub disclosure: bool,
+    /// Inline style for the toggle button.
+    #[prop_or(
+        "display: flex; flex-direction: column; gap: 5px; cursor: pointer; background: none; border: none; padding: 0;"
+    )]
+    pub toggle_style: &'static str,
+    /// Inline style for each line/bar in the hamburger icon.
+    #[prop_or("width: 25px; height: 3px; background: var(--navbar-fg, black);")]
+    pub line_style: &'static str,
+    /// Optional class for the toggle container.
+    #[prop_or_default]
+    pub toggle_class: String,
+    /// Optional class for each line in the icon.
+    #[prop_or_default]
+    pub line_class: String,
+}
+
+#[function_component(NavbarToggle)]
+fn navbar_toggle(props: &ToggleProps) -> Html {
+    let line = |index: usize| {
+        let style = with_phase(
+            props.line_style,
+            props.animation.line_style(index, props.open),
+        );
+        html! { <span aria-hidden="true" style={style} class={props.line_class.clone()} /> }
+    };
+    let (tag, kind) = if props.disclosure {
+        ("summary", None)
+    } else {
+        ("button", Some("button"))
+    };
+    let onclick = {
+        let onclick = props.onclick.clone();
+        let disclosure = props.disclosure;
+        Callback::from(move |e: MouseEvent| {
+            if disclosure {
+                e.prevent_default();
+            }
+            onclick.emit(e);
+        })
+    };
+
+    html! {
+        <@{tag}
+            id={props.id.clone()}
+            type={kind}
+            style={with_phase(props.toggle_style, props.animation.toggle_style(props.open))}
+            class={props.toggle_class.clone()}
+            onclick={onclick}
+            onkeydown={props.onkeydown.clone()}
+            aria-label={props.label.clone()}
+            aria-controls={props.controls.clone()}
+            aria-expanded={props.open.to_string()}
+        >
+            { line(0) }
+            { line(1) }
+            { line(2) }
+        </@>
+    }
+}
+
+/// Properties for rendering a horizontal navigation menu.
+#[derive(Properties, PartialEq)]
+pub struct MenuProps {
+    /// List of menu items to display.
+    pub menus: Vec<Menu>,
+    /// Inline style for each menu item anchor tag.
+    #[prop_or("padding: 0.5rem 1rem; text-decoration: none; color: var(--navbar-fg, black);")]
+    pub menu_item_style: &'static str,
+    /// Inline style for the entire list container.
+    #[prop_or("display: flex; gap: 1rem; list-style: none; margin: 0; padding: 0;")]
+    pub list_style: &'static str,
+    /// Optional class for each menu item.
+    #[prop_or_default]
+    pub menu_item_class: String,
+    /// Optional class for the list container.
+    #[prop_or_default]
+    pub list_class: String,
+    /// Layout of the items, which decides the arrow keys that move focus between them.
+    #[prop_or_default]
+    pub orientation: Orientation,
+    /// Style for the submenu of an item with `children`.
+    #[prop_or(SUBMENU_STYLE)]
+    pub submenu_style: &'static str,
+    /// Optional class for the submenu of an item with `children`.
+    #[prop_or_default]
+    pub submenu_class: String,
+    /// Expands submenus inline below their item (an accordion) instead of as flyouts.
+    #[prop_or(false)]
+    pub accordion: bool,
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[prop_or_default]
+    pub current_path: Option<AttrValue>,
+    /// How item links are matched against the current path.
+    #[prop_or_default]
+    pub active_match: ActiveMatch,
+    /// Style added to the active item's link, which also gets `aria-current="page"`.
+    #[prop_or("font-weight: bold;")]
+    pub active_style: &'static str,
+    /// Optional class added to the active item's link.
+    #[prop_or_default]
+    pub active_class: String,
+}
+
+/// Renders the items as a WAI-ARIA menubar.
+///
+/// Only one item is in the tab sequence at a time (roving `tabindex`); the arrow keys for the
+/// `orientation`, `Home` and `End` move focus between items. Items with `children` open a
+/// submenu, as a flyout or (with `accordion`) inline.
+#[function_component(NavbarMenu)]
+fn navbar_menu(props: &MenuProps) -> Html {
+    let id = use_element_id("menu");
+    let router = use_link_router();
+    let location = use_location_path();
+    let path = props.current_path.clone().unwrap_or(location);
+    let focused = use_state_eq(|| 0);
+    let len = props.menus.len();
+    let orientation = props.orientation;
+
+    html! {
+        <ul
+            role="menubar"
+            aria-orientation={orientation.aria_orientation()}
+            style={props.list_style}
+            class={props.list_class.clone()}
+        >
+            { for props.menus.iter().enumerate().map(|(index, m)| {
+                let onkeydown = {
+                    let id = id.clone();
+                    Callback::from(move |e: KeyboardEvent| {
+                        let moved = MenuKey::from_key(&e.key(), orientation)
+                            .is_some_and(|key| focus_menu_item(&id, key, Some(index), len));
+                        if moved {
+                            e.prevent_default();
+                        }
+                    })
+                };
+                let onfocus = {
+                    let focused = focused.setter();
+                    Callback::from(move |_| focused.set(index))
+                };
+                let tabindex = if index == (*focused).min(len - 1) { "0" } else { "-1" };
+                if m.has_submenu() {
+                    return html! {
+                        <NavbarSubmenu
+                            key={m.id}
+                            item={m.clone()}
+                            id={item_id(&id, index)}
+                            {orientation}
+                            accordion={props.accordion}
+                            {tabindex}
+                            on_keydown={onkeydown}
+                            on_focus={onfocus}
+                            item_style={props.menu_item_style}
+                            item_class={props.menu_item_class.clone()}
+                            submenu_style={props.submenu_style}
+                            submenu_class={props.submenu_class.clone()}
+                            path={path.clone()}
+                            active_match={props.active_match.clone()}
+                            active_style={props.active_style}
+                            active_class={props.active_class.clone()}
+                        />
+                    };
+                }
+                let active = props.active_match.is_active(&m.link, &path);
+                html! {
+                    <li key={m.id} role="none">
+                        <a
+                            id={item_id(&id, index)}
+                            href={router.href(&m.link)}
+                            onclick={router.onclick(&m.link, "")}
+                            role="menuitem"
+                            {tabindex}
+                            {onkeydown}
+                            {onfocus}
+                            aria-current={active.then_some("page")}
+                            style={with_active(props.menu_item_style, props.active_style, active)}
+                            class={classes!(
+                                props.menu_item_class.clone(),
+                                active.then(|| props.active_class.clone()),
+                            )}
+                        >
+                            { m.icon_start.clone().unwrap_or_default() }
+                            { m.name.clone() }
+                            { m.icon_end.clone().unwrap_or_default() }
+                        </a>
+                    </li>
+                }
+            }) }
+        </ul>
+    }
+}
+
+/// Default style for a desktop submenu flyout.
+const SUBMENU_STYLE: &str = "position: absolute; top: 100%; left: 0; z-index: 1000; min-width: 12rem; margin: 0; padding: 0.5rem 0; list-style: none; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1));";
+
+/// Properties for rendering a menu item that opens a submenu of its `children`.
+#[derive(Properties, PartialEq)]
+struct SubmenuProps {
+    /// The item whose children are shown.
+    item: Menu,
+    /// Element id of the item, which labels its submenu.
+    id: AttrValue,
+    /// Layout of the menu containing the item.
+    orientation: Orientation,
+    /// Expands the submenu inline below the item instead of as a flyout.
+    accordion: bool,
+    /// `tabindex` of the item in its containing menu.
+    tabindex: &'static str,
+    /// Keys pressed on the item that do not open or close its submenu.
+    on_keydown: Callback<KeyboardEvent>,
+    /// Called when the item receives focus.
+    #[prop_or_default]
+    on_focus: Callback<FocusEvent>,
+    /// Called after a link in the submenu, at any depth, is followed.
+    #[prop_or_default]
+    on_navigate: Callback<()>,
+    item_style: &'static str,
+    item_class: String,
+    submenu_style: &'static str,
+    submenu_class: String,
+    path: AttrValue,
+    active_match: ActiveMatch,
+    active_style: &'static str,
+    active_class: String,
+}
+
+/// Renders a menu item with `children` as a WAI-ARIA menu button followed by its submenu.
+///
+/// As a flyout, the submenu opens on hover or click and closes on mouse leave, a click outside,
+/// `Escape` or after following one of its links. As an accordion it stays open until its item is
+/// activated again. Opening it from the keyboard (`Enter`, `Space`, or `ArrowDown`/`ArrowRight`
+/// depending on `orientation`) focuses its first item; `Escape` or `ArrowLeft` returns focus to
+/// the item.
+#[function_component(NavbarSubmenu)]
+fn navbar_submenu(props: &SubmenuProps) -> Html {
+    let wrapper_id = use_element_id("submenu");
+    let menu_id = AttrValue::from(format!("{}-menu", props.id));
+    let router = use_link_router();
+    let open = use_state_eq(|| false);
+    let len = props.item.children.len();
+    let accordion = props.accordion;
+
+    let close = {
+        let open = open.setter();
+        Callback::from(move |_| open.set(false))
+    };
+    use_click_outside(wrapper_id.clone(), *open && !accordion, close.clone());
+
+    // Item to focus once the submenu opens after a key press on its item.
+    let initial_focus = use_mut_ref(|| None::<MenuKey>);
+    {
+        let initial_focus = initial_focus.clone();
+        let menu_id = menu_id.clone();
+        use_effect_with(*open, move |&is_open| {
+            let focus = initial_focus.borrow_mut().take();
+            if let Some(key) = focus.filter(|_| is_open) {
+                focus_menu_item(&menu_id, key, None, len);
+            }
+        });
+    }
 
-    let toggle_dropdown = {
-        let state = is_dropdown_open.clone();
+    let on_item_click = {
+        let open = open.clone();
         Callback::from(move |e: MouseEvent| {
-            e.stop_propagation();
-            state.set(!*state)
+            e.prevent_default();
+            open.set(!*open);
+        })
+    };
+
+    let on_item_keydown = {
+        let open = open.clone();
+        let menu_id = menu_id.clone();
+        let orientation = props.orientation;
+        let on_keydown = props.on_keydown.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let key = e.key();
+            let closes = *open
+                && (MenuKey::from_key(&key, orientation) == Some(MenuKey::Close)
+                    || accordion && matches!(key.as_str(), "Enter" | " "));
+            if closes {
+                e.prevent_default();
+                open.set(false);
+            } else if let Some(focus) = MenuKey::submenu_focus(&key, orientation) {
+                e.prevent_default();
+                if *open {
+                    focus_menu_item(&menu_id, focus, None, len);
+                } else {
+                    *initial_focus.borrow_mut() = Some(focus);
+                    open.set(true);
+                }
+            } else {
+                on_keydown.emit(e);
+            }
+        })
+    };
+
+    let on_child_keydown = |index: usize| {
+        let open = open.setter();
+        let id = props.id.clone();
+        let menu_id = menu_id.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            match MenuKey::from_key(&e.key(), Orientation::Vertical) {
+                Some(MenuKey::Close) => {
+                    e.prevent_default();
+                    open.set(false);
+                    focus_element(&id);
+                }
+                Some(MenuKey::Leave) if !accordion => open.set(false),
+                Some(key) => {
+                    let moved = focus_menu_item(&menu_id, key, Some(index), len);
+                    if moved {
+                        e.prevent_default();
+                    }
+                }
+                None => {}
+            }
         })
     };
 
-    let on_mouse_enter = {
-        let state = is_mega_menu_open.clone();
-        Callback::from(move |_| state.set(true))
+    // Following a link closes every flyout up to the menubar.
+    let on_navigate = {
+        let on_navigate = props.on_navigate.clone();
+        Callback::from(move |_| {
+            if !accordion {
+                close.emit(());
+            }
+            on_navigate.emit(());
+        })
     };
 
-    let on_mouse_leave = {
-        let state = is_mega_menu_open.clone();
-        Callback::from(move |_| state.set(false))
+    let (wrapper_style, submenu_style) = match (accordion, props.orientation) {
+        (true, _) => (None, props.submenu_style.to_string()),
+        (false, Orientation::Vertical) => (
+            Some("position: relative;"),
+            format!("{} top: 0; left: 100%;", props.submenu_style),
+        ),
+        (false, _) => (Some("position: relative;"), props.submenu_style.to_string()),
     };
-    let search_state = props
-        .search_state
-        .clone()
-        .unwrap_or(use_state(|| "".to_string()));
-    let search_val = (*search_state).clone();
-    let on_input = Callback::from(move |e: InputEvent| {
-        let input: HtmlInputElement = e.target_unchecked_into();
-        search_state.set(input.value());
-    });
+    // Only a mouse opens the flyout on hover; a tap is left to the click that toggles it.
+    let (onpointerenter, onpointerleave) = if accordion {
+        (None, None)
+    } else {
+        let enter = open.setter();
+        let leave = open.setter();
+        (
+            Some(on_mouse(Callback::from(move |_| enter.set(true)))),
+            Some(on_mouse(Callback::from(move |_| leave.set(false)))),
+        )
+    };
+    let active = props.item.contains_active(&props.active_match, &props.path);
+
     html! {
-        <nav style={props.navbar_style} class={props.navbar_class}>
-            <div style={props.container_style} class={props.container_class}>
-                <NavbarLogo
-                    logo_src={props.logo_src}
-                    logo_alt={props.logo_alt}
-                    logo_link={props.logo_link}
-                    logo_style={props.logo_style}
-                    logo_class={props.logo_class}
-                />
-                <div style={props.inner_style} class={props.inner_class}>
-                    if !*is_mobile {
-                        <NavbarMenu
-                            menus={props.menus.clone()}
-                            menu_item_style={props.menu_item_style}
-                            menu_item_class={props.menu_item_class}
-                        />
-                        if props.show_mega_menu {
-                            <div
-                                onmouseenter={on_mouse_enter.clone()}
-                                style="position: relative; display: inline-block;"
-                            >
-                                <button
-                                    style={props.more_button_style}
-                                    class={props.more_button_class}
-                                >
-                                    { props.more_button_text }
-                                </button>
-                                if *is_mega_menu_open {
-                                    <div onmouseleave={on_mouse_leave.clone()}>
-                                        <MegaMenu
-                                            items={props.mega_menu_items.clone()}
-                                            wrapper_style={props.mega_menu_style}
-                                            wrapper_class={props.mega_menu_class}
-                                            card_style={props.mega_menu_card_style}
-                                            card_class={props.mega_menu_card_class}
-                                        />
-                                    </div>
-                                }
-                            </div>
-                        }
-                        if props.show_search {
-                            if let Some(_search_state) = &props.search_state {
-                                <input
-                                    type="text"
-                                    placeholder={props.search_placeholder}
-                                    style={props.search_input_style}
-                                    class={props.search_input_class}
-                                    value={search_val}
-                                    oninput={on_input.clone()}
-                                />
-                            } else {
-                                <input
-                                    type="text"
-                                    placeholder={props.search_placeholder}
-                                    style={props.search_input_style}
-                                    class={props.search_input_class}
+        <li id={wrapper_id} role="none" style={wrapper_style} {onpointerenter} {onpointerleave}>
+            <a
+                id={props.id.clone()}
+                href={router.href(&props.item.link)}
+                role="menuitem"
+                tabindex={props.tabindex}
+                aria-haspopup="menu"
+                aria-expanded={open.to_string()}
+                aria-controls={menu_id.clone()}
+                onclick={on_item_click}
+                onkeydown={on_item_keydown}
+                onfocus={props.on_focus.clone()}
+                style={with_active(props.item_style, props.active_style, active)}
+                class={classes!(
+                    props.item_class.clone(),
+                    active.then(|| props.active_class.clone()),
+                )}
+            >
+                { props.item.icon_start.clone().unwrap_or_default() }
+                { props.item.name.clone() }
+                { props.item.icon_end.clone().unwrap_or_default() }
+            </a>
+            if *open {
+                <ul
+                    id={menu_id.clone()}
+                    role="menu"
+                    aria-labelledby={props.id.clone()}
+                    style={submenu_style}
+                    class={props.submenu_class.clone()}
+                >
+                    { for props.item.children.iter().enumerate().map(|(index, child)| {
+                        let child_id = AttrValue::from(item_id(&menu_id, index));
+                        if child.has_submenu() {
+                            return html! {
+                                <NavbarSubmenu
+                                    key={child.id}
+                                    item={child.clone()}
+                                    id={child_id}
+                                    orientation={Orientation::Vertical}
+                                    {accordion}
+                                    tabindex="-1"
+                                    on_keydown={on_child_keydown(index)}
+                                    on_navigate={on_navigate.clone()}
+                                    item_style={props.item_style}
+                                    item_class={props.item_class.clone()}
+                                    submenu_style={props.submenu_style}
+                                    submenu_class={props.submenu_class.clone()}
+                                    path={props.path.clone()}
+                                    active_match={props.active_match.clone()}
+                                    active_style={props.active_style}
+                                    active_class={props.active_class.clone()}
                                 />
-                            }
-                        }
-                        if !props.button_text.is_empty() {
-                            <NavbarButton
-                                href={props.button_href}
-                                text={props.button_text}
-                                button_style={props.button_style}
-                                button_class={props.button_class}
-                                link_style={props.button_link_style}
-                                link_class={props.button_link_class}
-                                target={props.button_target}
-                            />
+                            };
                         }
-                        if props.show_profile_menu {
-                            <ProfileMenu
-                                profile_image_url={props.profile_image_url.clone()}
-                                items={props.dropdown_items.clone()}
-                                dropdown_style={props.dropdown_style}
-                                dropdown_class={props.dropdown_class}
-                                item_style={props.dropdown_item_style}
-                                item_class={props.dropdown_item_class}
-                                is_open={*is_dropdown_open}
-                                toggle={toggle_dropdown.clone()}
-                                profile_text={props.profile_button_text}
-                            />
+                        let active = props.active_match.is_active(&child.link, &props.path);
+                        let onclick = {
+                            let route = router.onclick(&child.link, "");
+                            let on_navigate = on_navigate.clone();
+                            Callback::from(move |e: MouseEvent| {
+                                if let Some(route) = &route {
+                                    route.emit(e);
+                                }
+                                on_navigate.emit(());
+                            })
+                        };
+                        html! {
+                            <li key={child.id} role="none">
+                                <a
+                                    id={child_id}
+                                    href={router.href(&child.link)}
+                                    role="menuitem"
+                                    tabindex="-1"
+                                    {onclick}
+                                    onkeydown={on_child_keydown(index)}
+                                    aria-current={active.then_some("page")}
+                                    style={with_active(props.item_style, props.active_style, active)}
+                                    class={classes!(
+                                        props.item_class.clone(),
+                                        active.then(|| props.active_class.clone()),
+                                    )}
+                                >
+                                    { child.icon_start.clone().unwrap_or_default() }
+                                    { child.name.clone() }
+                                    { child.icon_end.clone().unwrap_or_default() }
+                                </a>
+                            </li>
                         }
+                    }) }
+                </ul>
+            }
+        </li>
+    }
+}
+
+/// Default style of the backdrop behind a mobile drawer or sheet.
+const MOBILE_BACKDROP_STYLE: &str = "position: fixed; inset: 0; z-index: 1000; background: var(--navbar-backdrop, rgba(0,0,0,0.4));";
+
+/// Default style of the suggestions listbox under the search input.
+const SUGGESTIONS_STYLE: &str = "position: absolute; top: 100%; left: 0; right: 0; z-index: 1000; margin: 0.25rem 0 0; padding: 0.25rem 0; list-style: none; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); max-height: 20rem; overflow-y: auto;";
+
+/// Properties for rendering a search form.
+#[derive(Properties, PartialEq)]
+pub struct SearchProps {
+    /// Current search text. `None` leaves the input uncontrolled.
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+    /// Placeholder text for the search input.
+    #[prop_or(AttrValue::Static("Search..."))]
+    pub placeholder: AttrValue,
+    /// Style for the search input field.
+    #[prop_or(
+        "padding: 0.5rem; font-size: 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
+    )]
+    pub input_style: &'static str,
+    /// Optional class for the search input.
+    #[prop_or_default]
+    pub input_class: String,
+    /// Callback fired with the text on every keystroke.
+    #[prop_or_default]
+    pub on_input: Callback<String>,
+    /// Callback fired with the text on `Enter` or a click on the search button.
+    #[prop_or_default]
+    pub on_submit: Callback<String>,
+    /// Whether to show a submit button next to the input.
+    #[prop_or(false)]
+    pub show_button: bool,
+    /// Text of the submit button.
+    #[prop_or(AttrValue::Static("Search"))]
+    pub button_text: AttrValue,
+    /// Style for the submit button.
+    #[prop_or(
+        "padding: 0.5rem 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;"
+    )]
+    pub button_style: &'static str,
+    /// Optional class for the submit button.
+    #[prop_or_default]
+    pub button_class: String,
+    /// Provider of the suggestions listed under the input as the user types.
+    #[prop_or_default]
+    pub suggestions: Option<SuggestionProvider>,
+    /// Callback fired with the chosen suggestion, before navigating to its `link` (if any).
+    #[prop_or_default]
+    pub on_suggestion_select: Callback<Suggestion>,
+    /// Delay (ms) after the last keystroke before an asynchronous provider is called.
+    #[prop_or(300)]
+    pub debounce_ms: u32,
+    /// Text shown while an asynchronous provider is loading.
+    #[prop_or(AttrValue::Static("Loading..."))]
+    pub suggestions_loading_text: AttrValue,
+    /// Text shown when the provider finds no suggestions.
+    #[prop_or(AttrValue::Static("No results"))]
+    pub suggestions_empty_text: AttrValue,
+    /// Style for the suggestions listbox.
+    #[prop_or(SUGGESTIONS_STYLE)]
+    pub suggestions_style: &'static str,
+    /// Optional class for the suggestions listbox.
+    #[prop_or_default]
+    pub suggestions_class: String,
+    /// Style for each suggestion.
+    #[prop_or("padding: 0.5rem 1rem; cursor: pointer;")]
+    pub suggestion_style: &'static str,
+    /// Optional class for each suggestion.
+    #[prop_or_default]
+    pub suggestion_class: String,
+    /// Style added to the highlighted suggestion.
+    #[prop_or("background: var(--navbar-highlight, #f0f0f0);")]
+    pub suggestion_active_style: &'static str,
+    /// Optional class added to the highlighted suggestion.
+    #[prop_or_default]
+    pub suggestion_active_class: String,
+}
+
+/// Renders the search input inside a `role="search"` form, so `Enter` submits it.
+///
+/// With `suggestions`, the input is a WAI-ARIA combobox: a listbox of suggestions opens under it
+/// as the user types, `ArrowDown`/`ArrowUp` highlight a suggestion, `Enter` chooses it and
+/// `Escape` (or a click outside) closes the listbox.
+#[function_component(NavbarSearch)]
+fn navbar_search(props: &SearchProps) -> Html {
+    let id = use_element_id("search");
+    let listbox_id = use_element_id("suggestions");
+    let input_ref = use_node_ref();
+    let router = use_link_router();
+    let typed = use_state_eq(AttrValue::default);
+    let query = props.value.clone().unwrap_or_else(|| (*typed).clone());
+    let suggestions = use_suggestions(props.suggestions.clone(), query.clone(), props.debounce_ms);
+    let active = use_state_eq(|| None::<usize>);
+    let dismissed = use_state_eq(|| false);
+    let open = props.suggestions.is_some() && !*dismissed && suggestions.is_open();
+
+    {
+        let active = active.setter();
+        use_effect_with(suggestions.clone(), move |_| active.set(None));
+    }
+    {
+        let dismissed = dismissed.setter();
+        use_click_outside(
+            id.clone(),
+            open,
+            Callback::from(move |_| dismissed.set(true)),
+        );
+    }
+
+    let select = {
+        let items = suggestions.items().to_vec();
+        let dismissed = dismissed.setter();
+        let on_suggestion_select = props.on_suggestion_select.clone();
+        Callback::from(move |index: usize| {
+            if let Some(suggestion) = items.get(index) {
+                dismissed.set(true);
+                on_suggestion_select.emit(suggestion.clone());
+                if let Some(link) = &suggestion.link {
+                    router.navigate(link);
+                }
+            }
+        })
+    };
+
+    let oninput = {
+        let on_input = props.on_input.clone();
+        let typed = typed.setter();
+        let dismissed = dismissed.setter();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            typed.set(input.value().into());
+            dismissed.set(false);
+            on_input.emit(input.value());
+        })
+    };
+
+    let onkeydown = {
+        let active = active.clone();
+        let dismissed = dismissed.setter();
+        let select = select.clone();
+        let len = suggestions.items().len();
+        let enabled = props.suggestions.is_some();
+        Callback::from(move |e: KeyboardEvent| {
+            if !enabled {
+                return;
+            }
+            match ComboboxKey::from_key(&e.key()) {
+                Some(ComboboxKey::Close) if open => {
+                    e.prevent_default();
+                    dismissed.set(true);
+                }
+                Some(ComboboxKey::Select) if open => {
+                    // Without a highlighted suggestion, `Enter` submits the search instead.
+                    if let Some(index) = *active {
+                        e.prevent_default();
+                        select.emit(index);
                     }
-                </div>
-            </div>
-            <div style="position: relative; display: inline-block;">
-                if *is_mobile {
-                    <NavbarToggle
-                        onclick={toggle_mobile_menu.clone()}
-                        toggle_style={props.menu_toggle_style}
-                        toggle_class={props.menu_toggle_class}
-                        line_style={props.line_style}
-                        line_class={props.line_class}
-                    />
                 }
-                if *is_mobile && *is_mobile_menu_open {
-                    <MobileMenu
-                        menus={props.menus.clone()}
-                        menu_item_style={props.menu_item_style}
-                        menu_item_class={props.menu_item_class}
-                        dropdown_items={props.dropdown_items.clone()}
-                        dropdown_style={props.dropdown_style}
-                        dropdown_class={props.dropdown_class}
-                        dropdown_item_style={props.dropdown_item_style}
-                        dropdown_item_class={props.dropdown_item_class}
-                        show_profile_menu={props.show_profile_menu}
-                        show_search={props.show_search}
-                        search_input_style={props.search_input_style}
-                        search_input_class={props.search_input_class}
-                        search_placeholder={props.search_placeholder}
-                    />
+                Some(key @ (ComboboxKey::Next | ComboboxKey::Previous)) => {
+                    e.prevent_default();
+                    if open {
+                        active.set(key.target(*active, len));
+                    } else {
+                        dismissed.set(false);
+                    }
                 }
-            </div>
-        </nav>
+                _ => {}
+            }
+        })
+    };
+
+    let onsubmit = {
+        let input_ref = input_ref.clone();
+        let on_submit = props.on_submit.clone();
+        let dismissed = dismissed.setter();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            dismissed.set(true);
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                on_submit.emit(input.value());
+            }
+        })
+    };
+
+    let combobox = props.suggestions.is_some();
+    html! {
+        <form
+            id={id}
+            role="search"
+            style="position: relative; display: flex; gap: 0.5rem; margin: 0;"
+            {onsubmit}
+        >
+            <input
+                ref={input_ref}
+                type="search"
+                role={combobox.then_some("combobox")}
+                aria-autocomplete={combobox.then_some("list")}
+                aria-expanded={combobox.then(|| open.to_string())}
+                aria-controls={combobox.then(|| listbox_id.clone())}
+                aria-activedescendant={active.filter(|_| open).map(|index| item_id(&listbox_id, index))}
+                aria-label={props.placeholder.clone()}
+                placeholder={props.placeholder.clone()}
+                style={props.input_style}
+                class={props.input_class.clone()}
+                value={props.value.clone()}
+                {oninput}
+                {onkeydown}
+            />
+            if props.show_button {
+                <button type="submit" style={props.button_style} class={props.button_class.clone()}>
+                    { props.button_text.clone() }
+                </button>
+            }
+            if open {
+                <SearchSuggestions
+                    id={listbox_id.clone()}
+                    suggestions={suggestions.clone()}
+                    query={query}
+                    active={*active}
+                    on_select={select}
+                    on_highlight={Callback::from(move |index| active.set(Some(index)))}
+                    loading_text={props.suggestions_loading_text.clone()}
+                    empty_text={props.suggestions_empty_text.clone()}
+                    list_style={props.suggestions_style}
+                    list_class={props.suggestions_class.clone()}
+                    item_style={props.suggestion_style}
+                    item_class={props.suggestion_class.clone()}
+                    active_style={props.suggestion_active_style}
+                    active_class={props.suggestion_active_class.clone()}
+                />
+            }
+        </form>
     }
 }
 
-/// Properties for rendering a logo inside a navigation bar.
+/// Properties for rendering the listbox of search suggestions.
 #[derive(Properties, PartialEq)]
-pub struct LogoProps {
-    /// Path to the logo image.
-    #[prop_or("/logo.png")]
-    pub logo_src: &'static str,
-    /// Alternative text for the logo image.
-    #[prop_or("Logo")]
-    pub logo_alt: &'static str,
-    /// URL to redirect when the logo is clicked.
-    #[prop_or("/")]
-    pub logo_link: &'static str,
-    /// Inline CSS style for the logo image.
-    #[prop_or("height: 40px;")]
-    pub logo_style: &'static str,
-    /// Inline CSS style for the link wrapping the logo.
-    #[prop_or("display: inline-block;")]
-    pub link_style: &'static str,
-    /// Optional class name for the logo image.
+pub struct SearchSuggestionsProps {
+    /// Element id of the listbox, referenced by the input's `aria-controls`.
+    pub id: AttrValue,
+    /// The suggestions for the current query.
+    pub suggestions: Suggestions,
+    /// The current query, highlighted in each suggestion.
     #[prop_or_default]
-    pub logo_class: String,
-    /// Optional class name for the anchor tag wrapping the logo.
+    pub query: AttrValue,
+    /// Index of the highlighted suggestion.
     #[prop_or_default]
-    pub link_class: String,
+    pub active: Option<usize>,
+    /// Callback fired with the index of a clicked suggestion.
+    #[prop_or_default]
+    pub on_select: Callback<usize>,
+    /// Callback fired with the index of the suggestion under the pointer.
+    #[prop_or_default]
+    pub on_highlight: Callback<usize>,
+    /// Text shown while the suggestions are loading.
+    #[prop_or(AttrValue::Static("Loading..."))]
+    pub loading_text: AttrValue,
+    /// Text shown when there are no suggestions.
+    #[prop_or(AttrValue::Static("No results"))]
+    pub empty_text: AttrValue,
+    /// Style for the listbox.
+    #[prop_or(SUGGESTIONS_STYLE)]
+    pub list_style: &'static str,
+    /// Optional class for the listbox.
+    #[prop_or_default]
+    pub list_class: String,
+    /// Style for each suggestion.
+    #[prop_or("padding: 0.5rem 1rem; cursor: pointer;")]
+    pub item_style: &'static str,
+    /// Optional class for each suggestion.
+    #[prop_or_default]
+    pub item_class: String,
+    /// Style added to the highlighted suggestion.
+    #[prop_or("background: var(--navbar-highlight, #f0f0f0);")]
+    pub active_style: &'static str,
+    /// Optional class added to the highlighted suggestion.
+    #[prop_or_default]
+    pub active_class: String,
 }
 
-#[function_component(NavbarLogo)]
-fn navbar_logo(props: &LogoProps) -> Html {
+/// Renders the suggestions as a `role="listbox"`, with the matched part of each label in a
+/// `<mark>`, or a loading or empty state.
+#[function_component(SearchSuggestions)]
+fn search_suggestions(props: &SearchSuggestionsProps) -> Html {
+    let status = |text: &AttrValue| {
+        html! {
+            <li role="option" aria-disabled="true" style={props.item_style} class={props.item_class.clone()}>
+                { text.clone() }
+            </li>
+        }
+    };
+
     html! {
-        <a href={props.logo_link} style={props.link_style} class={props.link_class.clone()}>
-            <img
-                src={props.logo_src}
-                alt={props.logo_alt}
-                style={props.logo_style}
-                class={props.logo_class.clone()}
-            />
-        </a>
+        <ul
+            id={props.id.clone()}
+            role="listbox"
+            aria-busy={(props.suggestions == Suggestions::Loading).then_some("true")}
+            style={props.list_style}
+            class={props.list_class.clone()}
+        >
+            { match &props.suggestions {
+                Suggestions::Loading => status(&props.loading_text),
+                Suggestions::Ready(items) if items.is_empty() => status(&props.empty_text),
+                _ => html! { for props.suggestions.items().iter().enumerate().map(|(index, suggestion)| {
+                    let active = props.active == Some(index);
+                    let on_select = props.on_select.clone();
+                    let on_highlight = props.on_highlight.clone();
+                    html! {
+                        <li
+                            key={suggestion.id}
+                            id={item_id(&props.id, index)}
+                            role="option"
+                            aria-selected={active.to_string()}
+                            style={with_active(props.item_style, props.active_style, active)}
+                            class={with_active(&props.item_class, &props.active_class, active)}
+                            // Keeps focus in the input, so the listbox stays open until the click.
+                            onmousedown={Callback::from(|e: MouseEvent| e.prevent_default())}
+                            onmouseenter={Callback::from(move |_| on_highlight.emit(index))}
+                            onclick={Callback::from(move |_| on_select.emit(index))}
+                        >
+                            { for highlight_match(&suggestion.label, &props.query).into_iter().map(|(segment, matched)| {
+                                if matched {
+                                    html! { <mark>{ segment.to_string() }</mark> }
+                                } else {
+                                    html! { segment.to_string() }
+                                }
+                            }) }
+                            if let Some(description) = &suggestion.description {
+                                <div style="font-size: 0.875em; opacity: 0.7;">{ description.to_string() }</div>
+                            }
+                        </li>
+                    }
+                }) },
+            } }
+        </ul>
     }
 }
 
-/// Properties for rendering a hamburger toggle icon (used in responsive menus).
+/// Default style of the theme toggle's group of buttons.
+const THEME_TOGGLE_STYLE: &str = "display: inline-flex; gap: 0.25rem; margin-left: 1rem;";
+
+/// Default style of each theme toggle button.
+const THEME_BUTTON_STYLE: &str = "padding: 0.25rem 0.5rem; border: 1px solid var(--navbar-border, #ccc); border-radius: 4px; background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;";
+
+/// Default style of the backdrop behind the command palette.
+const PALETTE_BACKDROP_STYLE: &str = "position: fixed; inset: 0; z-index: 2000; display: flex; justify-content: center; align-items: flex-start; padding-top: 15vh; background: var(--navbar-backdrop, rgba(0,0,0,0.4));";
+
+/// Default style of the command palette dialog.
+const PALETTE_DIALOG_STYLE: &str = "width: min(36rem, 90vw); background: var(--navbar-surface, white); border-radius: 8px; box-shadow: 0 8px 24px var(--navbar-shadow, rgba(0,0,0,0.2)); overflow: hidden;";
+
+/// Properties for the `CommandPalette` component.
 #[derive(Properties, PartialEq)]
-pub struct ToggleProps {
-    /// Callback triggered when the toggle is clicked.
-    pub onclick: Callback<MouseEvent>,
-    /// Inline style for the toggle container.
-    #[prop_or("display: flex; flex-direction: column; gap: 5px; cursor: pointer;")]
-    pub toggle_style: &'static str,
-    /// Inline style for each line/bar in the hamburger icon.
-    #[prop_or("width: 25px; height: 3px; background: black;")]
-    pub line_style: &'static str,
-    /// Optional class for the toggle container.
+pub struct CommandPaletteProps {
+    /// Menu items listed under `pages_section`, submenus included.
     #[prop_or_default]
-    pub toggle_class: String,
-    /// Optional class for each line in the icon.
+    pub menus: Vec<Menu>,
+    /// Dropdown items listed under `account_section`.
     #[prop_or_default]
-    pub line_class: String,
+    pub dropdown_items: Vec<DropdownItem>,
+    /// Mega menu items listed under `more_section`.
+    #[prop_or_default]
+    pub mega_menu_items: Vec<MegaMenuItem>,
+    /// App commands (links or actions), each listed under its own section.
+    #[prop_or_default]
+    pub commands: Vec<Command>,
+    /// Key that opens the palette together with `Ctrl` (or `Cmd` on macOS).
+    #[prop_or(AttrValue::Static("k"))]
+    pub shortcut: AttrValue,
+    /// Callback fired with the chosen command, before it navigates or runs.
+    #[prop_or_default]
+    pub on_select: Callback<Command>,
+    /// Accessible name of the dialog.
+    #[prop_or(AttrValue::Static("Command palette"))]
+    pub label: AttrValue,
+    /// Placeholder text for the filter input.
+    #[prop_or(AttrValue::Static("Search pages and actions..."))]
+    pub placeholder: AttrValue,
+    /// Text shown when nothing matches the filter.
+    #[prop_or(AttrValue::Static("No results"))]
+    pub empty_text: AttrValue,
+    /// Section heading of the menu items.
+    #[prop_or(AttrValue::Static("Pages"))]
+    pub pages_section: AttrValue,
+    /// Section heading of the dropdown items.
+    #[prop_or(AttrValue::Static("Account"))]
+    pub account_section: AttrValue,
+    /// Section heading of the mega menu items.
+    #[prop_or(AttrValue::Static("More"))]
+    pub more_section: AttrValue,
+    /// Section heading of the recently used commands, listed first while the filter is empty.
+    #[prop_or(AttrValue::Static("Recent"))]
+    pub recent_section: AttrValue,
+    /// Number of recently used commands to remember.
+    #[prop_or(5)]
+    pub recent_limit: usize,
+    /// `localStorage` key the recently used commands are saved under; empty to keep them in
+    /// memory only.
+    #[prop_or(AttrValue::Static("navbar-command-palette-recent"))]
+    pub recent_storage_key: AttrValue,
+    /// Style for the backdrop covering the page.
+    #[prop_or(PALETTE_BACKDROP_STYLE)]
+    pub backdrop_style: &'static str,
+    /// Optional class for the backdrop.
+    #[prop_or_default]
+    pub backdrop_class: String,
+    /// Style for the dialog.
+    #[prop_or(PALETTE_DIALOG_STYLE)]
+    pub dialog_style: &'static str,
+    /// Optional class for the dialog.
+    #[prop_or_default]
+    pub dialog_class: String,
+    /// Style for the filter input.
+    #[prop_or(
+        "width: 100%; box-sizing: border-box; padding: 0.75rem 1rem; font-size: 1rem; border: none; border-bottom: 1px solid var(--navbar-border, #eee); outline: none; background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
+    )]
+    pub input_style: &'static str,
+    /// Optional class for the filter input.
+    #[prop_or_default]
+    pub input_class: String,
+    /// Style for the section headings.
+    #[prop_or(
+        "padding: 0.5rem 1rem 0.25rem; font-size: 0.75rem; font-weight: 600; text-transform: uppercase; opacity: 0.6;"
+    )]
+    pub section_style: &'static str,
+    /// Optional class for the section headings.
+    #[prop_or_default]
+    pub section_class: String,
+    /// Style for each command.
+    #[prop_or("padding: 0.5rem 1rem; cursor: pointer;")]
+    pub item_style: &'static str,
+    /// Optional class for each command.
+    #[prop_or_default]
+    pub item_class: String,
+    /// Style added to the highlighted command.
+    #[prop_or("background: var(--navbar-highlight, #f0f0f0);")]
+    pub item_active_style: &'static str,
+    /// Optional class added to the highlighted command.
+    #[prop_or_default]
+    pub item_active_class: String,
 }
 
-#[function_component(NavbarToggle)]
-fn navbar_toggle(props: &ToggleProps) -> Html {
+/// A command palette over the navbar's items and app commands, opened with `Ctrl+K` / `Cmd+K`.
+///
+/// The palette is a modal dialog with a filter input and a listbox of commands grouped by section.
+/// Typing fuzzy-filters the commands (best match first), `ArrowDown`/`ArrowUp` move the
+/// highlight, `Enter` chooses the highlighted command and `Escape` or a click on the backdrop
+/// closes the palette, returning focus to where it was. Choosing a command navigates to its link
+/// (through the router, if any) or runs its action, and remembers it in the "Recent" section,
+/// saved to `localStorage`.
+///
+/// The [`Navbar`] renders one with `show_command_palette`; render it directly for full styling.
+///
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use navbar::core::Command;
+/// use navbar::yew::{CommandPalette, Menu};
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <CommandPalette
+///             menus={vec![Menu::new(1, "Docs", "/docs")]}
+///             commands={vec![
+///                 Command::action("logout", "Log out", || {}).with_section("Account"),
+///             ]}
+///         />
+///     }
+/// }
+/// ```
+#[function_component(CommandPalette)]
+pub fn command_palette(props: &CommandPaletteProps) -> Html {
+    let input_id = use_element_id("command-palette-input");
+    let listbox_id = use_element_id("command-palette-list");
+    let router = use_link_router();
+    let open = use_state_eq(|| false);
+    let query = use_state_eq(AttrValue::default);
+    let active = use_state_eq(|| 0_usize);
+    let recent = {
+        let key = props.recent_storage_key.clone();
+        let limit = props.recent_limit;
+        use_state_eq(move || RecentCommands::from_ids(load_recent_commands(&key), limit))
+    };
+    let focus_return = use_memo((), |_| FocusReturn::default());
+
+    let commands = use_memo(
+        (
+            props.menus.clone(),
+            props.dropdown_items.clone(),
+            props.mega_menu_items.clone(),
+            props.commands.clone(),
+            (props.pages_section.clone(), props.account_section.clone()),
+            props.more_section.clone(),
+        ),
+        |(menus, dropdown_items, mega_menu_items, commands, (pages, account), more)| {
+            CommandSet::new()
+                .with_menus(pages, menus)
+                .with_dropdown_items(account, dropdown_items)
+                .with_mega_menu_items(more, mega_menu_items)
+                .with_commands(commands.clone())
+        },
+    );
+    let groups = commands.filter(&query, recent.ids(), &props.recent_section);
+    let listed: Vec<Command> = groups
+        .iter()
+        .flat_map(|group| group.commands.iter().cloned())
+        .collect();
+
+    {
+        let open = open.setter();
+        use_effect_with(props.shortcut.clone(), move |key| {
+            let listener = listen_shortcut(key.to_string(), move || open.set(true));
+            move || drop(listener)
+        });
+    }
+    {
+        let input_id = input_id.clone();
+        let query = query.setter();
+        let active = active.setter();
+        use_effect_with(*open, move |open| {
+            if *open {
+                focus_return.capture();
+                focus_element(&input_id);
+            } else {
+                focus_return.restore();
+                query.set(AttrValue::default());
+                active.set(0);
+            }
+        });
+    }
+    {
+        let id = item_id(&listbox_id, *active);
+        use_effect_with(id, |id| scroll_into_view(id));
+    }
+
+    let close = {
+        let open = open.setter();
+        Callback::from(move |_: ()| open.set(false))
+    };
+
+    let choose = {
+        let open = open.setter();
+        let recent = recent.clone();
+        let key = props.recent_storage_key.clone();
+        let on_select = props.on_select.clone();
+        let listed = listed.clone();
+        Callback::from(move |index: usize| {
+            let Some(command) = listed.get(index) else {
+                return;
+            };
+            open.set(false);
+            let mut updated = (*recent).clone();
+            updated.push(&command.id);
+            save_recent_commands(&key, updated.ids());
+            recent.set(updated);
+            on_select.emit(command.clone());
+            match &command.action {
+                CommandAction::Navigate(link) => router.navigate(link),
+                CommandAction::Run(action) => action(),
+            }
+        })
+    };
+
+    let oninput = {
+        let query = query.setter();
+        let active = active.setter();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            query.set(input.value().into());
+            active.set(0);
+        })
+    };
+
+    let onkeydown = {
+        let active = active.clone();
+        let close = close.clone();
+        let choose = choose.clone();
+        let len = listed.len();
+        Callback::from(move |e: KeyboardEvent| {
+            // The input is the only focusable element, which keeps focus inside the dialog.
+            if e.key() == "Tab" {
+                e.prevent_default();
+                return;
+            }
+            match ComboboxKey::from_key(&e.key()) {
+                Some(ComboboxKey::Close) => {
+                    e.prevent_default();
+                    close.emit(());
+                }
+                Some(ComboboxKey::Select) => {
+                    e.prevent_default();
+                    choose.emit(*active);
+                }
+                Some(key @ (ComboboxKey::Next | ComboboxKey::Previous)) => {
+                    e.prevent_default();
+                    if let Some(index) = key.target(Some(*active), len) {
+                        active.set(index);
+                    }
+                }
+                None => {}
+            }
+        })
+    };
+
+    if !*open {
+        return html! {};
+    }
+
+    let mut index = 0;
     html! {
         <div
-            style={props.toggle_style}
-            class={props.toggle_class.clone()}
-            onclick={props.onclick.clone()}
-            tabindex="0"
-            role="button"
-            aria-label="Toggle Menu"
+            style={props.backdrop_style}
+            class={props.backdrop_class.clone()}
+            onclick={close.reform(|_: MouseEvent| ())}
         >
-            <div style={props.line_style} class={props.line_class.clone()} />
-            <div style={props.line_style} class={props.line_class.clone()} />
-            <div style={props.line_style} class={props.line_class.clone()} />
+            <div
+                role="dialog"
+                aria-modal="true"
+                aria-label={props.label.clone()}
+                style={props.dialog_style}
+                class={props.dialog_class.clone()}
+                onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
+            >
+                <input
+                    id={input_id}
+                    type="text"
+                    role="combobox"
+                    aria-autocomplete="list"
+                    aria-expanded="true"
+                    aria-controls={listbox_id.clone()}
+                    aria-activedescendant={(!listed.is_empty()).then(|| item_id(&listbox_id, *active))}
+                    aria-label={props.placeholder.clone()}
+                    placeholder={props.placeholder.clone()}
+                    autocomplete="off"
+                    style={props.input_style}
+                    class={props.input_class.clone()}
+                    value={(*query).clone()}
+                    {oninput}
+                    {onkeydown}
+                />
+                <div
+                    id={listbox_id.clone()}
+                    role="listbox"
+                    aria-label={props.label.clone()}
+                    style="max-height: 20rem; overflow-y: auto; padding: 0.25rem 0;"
+                >
+                    { for groups.iter().enumerate().map(|(group_index, group)| {
+                        let heading_id = format!("{listbox_id}-group-{group_index}");
+                        html! {
+                            <div key={group_index} role="group" aria-labelledby={heading_id.clone()}>
+                                <div
+                                    id={heading_id}
+                                    role="presentation"
+                                    style={props.section_style}
+                                    class={props.section_class.clone()}
+                                >
+                                    { group.section.to_string() }
+                                </div>
+                                { for group.commands.iter().map(|command| {
+                                    let current = index;
+                                    index += 1;
+                                    let selected = current == *active;
+                                    let active = active.setter();
+                                    let choose = choose.clone();
+                                    html! {
+                                        <div
+                                            id={item_id(&listbox_id, current)}
+                                            role="option"
+                                            aria-selected={selected.to_string()}
+                                            style={with_active(props.item_style, props.item_active_style, selected)}
+                                            class={with_active(&props.item_class, &props.item_active_class, selected)}
+                                            onmouseenter={Callback::from(move |_| active.set(current))}
+                                            onclick={Callback::from(move |_| choose.emit(current))}
+                                        >
+                                            { for highlight_match(&command.label, &query).into_iter().map(|(segment, matched)| {
+                                                if matched {
+                                                    html! { <mark>{ segment.to_string() }</mark> }
+                                                } else {
+                                                    html! { segment.to_string() }
+                                                }
+                                            }) }
+                                            if let Some(description) = &command.description {
+                                                <div style="font-size: 0.875em; opacity: 0.7;">{ description.to_string() }</div>
+                                            }
+                                        </div>
+                                    }
+                                }) }
+                            </div>
+                        }
+                    }) }
+                    if listed.is_empty() {
+                        <div role="status" style={props.item_style} class={props.item_class.clone()}>
+                            { props.empty_text.clone() }
+                        </div>
+                    }
+                </div>
+            </div>
         </div>
     }
 }
 
-/// Properties for rendering a horizontal navigation menu.
+/// Properties for the `ThemeToggle` component.
 #[derive(Properties, PartialEq)]
-pub struct MenuProps {
-    /// List of menu items to display.
-    pub menus: Vec<Menu>,
-    /// Inline style for each menu item anchor tag.
-    #[prop_or("padding: 0.5rem 1rem; text-decoration: none; color: black;")]
-    pub menu_item_style: &'static str,
-    /// Inline style for the entire list container.
-    #[prop_or("display: flex; gap: 1rem; list-style: none; margin: 0; padding: 0;")]
-    pub list_style: &'static str,
-    /// Optional class for each menu item.
+pub struct ThemeToggleProps {
+    /// Mode used until the user picks one (or when nothing was saved).
     #[prop_or_default]
-    pub menu_item_class: String,
-    /// Optional class for the list container.
+    pub default_mode: ThemeMode,
+    /// `localStorage` key the picked mode is saved under; empty to keep it in memory only.
+    #[prop_or(AttrValue::Static("navbar-theme"))]
+    pub storage_key: AttrValue,
+    /// Attribute of `<html>` set to the applied theme (`"light"` or `"dark"`); empty to leave
+    /// the document alone.
+    #[prop_or(AttrValue::Static("data-theme"))]
+    pub attribute: AttrValue,
+    /// Callback fired with the preference on mount and whenever the mode or, in system mode,
+    /// the system preference changes.
     #[prop_or_default]
-    pub list_class: String,
+    pub on_theme_change: Callback<ThemePreference>,
+    /// Accessible name of the group of buttons.
+    #[prop_or(AttrValue::Static("Theme"))]
+    pub label: AttrValue,
+    /// Text of the light mode button.
+    #[prop_or(AttrValue::Static("Light"))]
+    pub light_text: AttrValue,
+    /// Text of the dark mode button.
+    #[prop_or(AttrValue::Static("Dark"))]
+    pub dark_text: AttrValue,
+    /// Text of the system mode button.
+    #[prop_or(AttrValue::Static("System"))]
+    pub system_text: AttrValue,
+    /// Style for the group of buttons.
+    #[prop_or(THEME_TOGGLE_STYLE)]
+    pub style: &'static str,
+    /// Optional class for the group of buttons.
+    #[prop_or_default]
+    pub class: String,
+    /// Style for each button.
+    #[prop_or(THEME_BUTTON_STYLE)]
+    pub button_style: &'static str,
+    /// Optional class for each button.
+    #[prop_or_default]
+    pub button_class: String,
+    /// Style added to the button of the current mode.
+    #[prop_or("background: var(--navbar-highlight, #f0f0f0); font-weight: bold;")]
+    pub active_style: &'static str,
+    /// Optional class added to the button of the current mode.
+    #[prop_or_default]
+    pub active_class: String,
 }
 
-#[function_component(NavbarMenu)]
-fn navbar_menu(props: &MenuProps) -> Html {
+/// A light/dark/system switch that themes the document.
+///
+/// The picked mode is saved to `localStorage` and restored once mounted: the first render, on
+/// the server and when hydrating, shows `default_mode`, so both agree. In system mode, the theme
+/// follows `prefers-color-scheme`, live. The applied theme is set on `<html>` as
+/// `data-theme="light"` or `data-theme="dark"`, for the app's own styles, and reported through
+/// `on_theme_change`. The buttons form a group with `aria-pressed` on the current mode.
+///
+/// The [`Navbar`] renders one with `show_theme_toggle`, switching to its `dark_theme`.
+///
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use navbar::yew::ThemeToggle;
+///
+/// #[function_component]
+/// fn App() -> Html {
+///     html! {
+///         <ThemeToggle storage_key="app-theme" />
+///     }
+/// }
+///
+/// let html = futures::executor::block_on(yew::LocalServerRenderer::<App>::new().render());
+/// // Only the default mode, `System`, is pressed until the saved mode is read.
+/// let pressed = html.split("aria-pressed=\"true\"").nth(1).unwrap();
+/// assert!(pressed.split("</button>").next().unwrap().ends_with("System"));
+/// ```
+#[function_component(ThemeToggle)]
+pub fn theme_toggle(props: &ThemeToggleProps) -> Html {
+    let mode = use_state_eq(|| props.default_mode);
+    let system_dark = use_state_eq(|| false);
+    // The server can't read the saved mode, so the first render shows `default_mode` on both
+    // sides and the saved mode and the system preference are read once mounted.
+    let restored = use_state_eq(|| false);
+
+    {
+        let mode = mode.setter();
+        let system_dark = system_dark.setter();
+        let restored = restored.setter();
+        let key = props.storage_key.clone();
+        use_effect_with((), move |_| {
+            if let Some(saved) = load_theme_mode(&key) {
+                mode.set(saved);
+            }
+            system_dark.set(prefers_dark_scheme());
+            restored.set(true);
+            let listener = watch_color_scheme(move |dark| system_dark.set(dark));
+            move || drop(listener)
+        });
+    }
+
+    {
+        let attribute = props.attribute.clone();
+        let on_theme_change = props.on_theme_change.clone();
+        let preference = ThemePreference {
+            mode: *mode,
+            system_dark: *system_dark,
+        };
+        use_effect_with((preference, *restored), move |&(preference, restored)| {
+            if restored {
+                set_document_theme(&attribute, preference.theme());
+                on_theme_change.emit(preference);
+            }
+        });
+    }
+
     html! {
-        <ul style={props.list_style} class={props.list_class.clone()}>
-            { for props.menus.iter().map(|m| html! {
-                <li key={m.id}>
-                    <a href={m.link} style={props.menu_item_style} class={props.menu_item_class.clone()}>
-                        { m.icon_start.clone().unwrap_or_default() }
-                        { m.name }
-                        { m.icon_end.clone().unwrap_or_default() }
-                    </a>
-                </li>
+        <div role="group" aria-label={props.label.clone()} style={props.style} class={props.class.clone()}>
+            { for ThemeMode::ALL.into_iter().map(|option| {
+                let pressed = option == *mode;
+                let text = match option {
+                    ThemeMode::Light => props.light_text.clone(),
+                    ThemeMode::Dark => props.dark_text.clone(),
+                    ThemeMode::System => props.system_text.clone(),
+                };
+                let onclick = {
+                    let mode = mode.clone();
+                    let key = props.storage_key.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        save_theme_mode(&key, option);
+                        mode.set(option);
+                    })
+                };
+                html! {
+                    <button
+                        type="button"
+                        aria-pressed={pressed.to_string()}
+                        onclick={onclick}
+                        style={with_active(props.button_style, props.active_style, pressed)}
+                        class={with_active(&props.button_class, &props.active_class, pressed)}
+                    >
+                        { text }
+                    </button>
+                }
             }) }
-        </ul>
+        </div>
     }
 }
 
@@ -732,20 +3395,22 @@ fn navbar_menu(props: &MenuProps) -> Html {
 #[derive(Properties, PartialEq)]
 pub struct ButtonProps {
     /// The URL the button should navigate to.
-    #[prop_or("#")]
-    pub href: &'static str,
+    #[prop_or(AttrValue::Static("#"))]
+    pub href: AttrValue,
     /// Text displayed inside the button.
-    #[prop_or("Click Me")]
-    pub text: &'static str,
+    #[prop_or(AttrValue::Static("Click Me"))]
+    pub text: AttrValue,
     /// Style for the outer div wrapping the button.
-    #[prop_or("padding: 0.5rem 1rem; background-color: #007bff; border-radius: 4px;")]
+    #[prop_or(
+        "padding: 0.5rem 1rem; background-color: var(--navbar-accent, #007bff); border-radius: 4px;"
+    )]
     pub button_style: &'static str,
     /// Style applied to the anchor inside the button.
-    #[prop_or("color: white; text-decoration: none;")]
+    #[prop_or("color: var(--navbar-accent-text, white); text-decoration: none;")]
     pub link_style: &'static str,
     /// Target attribute for the anchor link (e.g. `_blank`, `_self`).
-    #[prop_or("_self")]
-    pub target: &'static str,
+    #[prop_or(AttrValue::Static("_self"))]
+    pub target: AttrValue,
     /// Optional class for the outer button div.
     #[prop_or_default]
     pub button_class: String,
@@ -756,15 +3421,17 @@ pub struct ButtonProps {
 
 #[function_component(NavbarButton)]
 fn navbar_button(props: &ButtonProps) -> Html {
+    let router = use_link_router();
     html! {
         <div style={props.button_style} class={props.button_class.clone()}>
             <a
-                href={props.href}
-                target={props.target}
+                href={router.href(&props.href)}
+                onclick={router.onclick(&props.href, &props.target)}
+                target={props.target.clone()}
                 style={props.link_style}
                 class={props.link_class.clone()}
             >
-                { props.text }
+                { props.text.clone() }
             </a>
         </div>
     }
@@ -777,7 +3444,7 @@ pub struct ProfileMenuProps {
     pub items: Vec<DropdownItem>,
     /// Style for the dropdown container.
     #[prop_or(
-        "position: absolute; top: 100%; left: 0; background: white; box-shadow: 0 4px 8px rgba(0,0,0,0.1); z-index: 1000;"
+        "position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); z-index: 1000;"
     )]
     pub dropdown_style: &'static str,
     /// Style for each item in the dropdown.
@@ -794,12 +3461,12 @@ pub struct ProfileMenuProps {
     pub avatar_img_style: &'static str,
     /// Style for fallback avatar (e.g., initials).
     #[prop_or(
-        "width: 40px; height: 40px; border-radius: 50%; background-color: #ccc; color: #fff; display: flex; align-items: center; justify-content: center; font-weight: bold;"
+        "width: 40px; height: 40px; border-radius: 50%; background-color: var(--navbar-border, #ccc); color: var(--navbar-accent-text, #fff); display: flex; align-items: center; justify-content: center; font-weight: bold;"
     )]
     pub avatar_fallback_style: &'static str,
     /// Displayed if avatar image is not available.
-    #[prop_or("Profile")]
-    pub profile_text: &'static str,
+    #[prop_or(AttrValue::Static("Profile"))]
+    pub profile_text: AttrValue,
     /// Optional URL for the avatar image.
     #[prop_or_default]
     pub profile_image_url: Option<String>,
@@ -822,15 +3489,83 @@ pub struct ProfileMenuProps {
     /// Class name for the button.
     #[prop_or_default]
     pub button_class: String,
+    /// Enter and exit transition of the dropdown list.
+    #[prop_or_default]
+    pub transition: Option<Transition>,
+    /// Whether a click outside the open dropdown closes it.
+    #[prop_or(true)]
+    pub close_on_outside_click: bool,
+    /// Callback to close the dropdown, e.g. after a click outside of it or `Escape`.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// Callback to open the dropdown from the keyboard (`ArrowDown`/`ArrowUp` on the button).
+    #[prop_or_default]
+    pub on_open: Callback<()>,
+    /// Item to focus when the dropdown is shown or this prop changes, e.g.
+    /// `Some(MenuKey::First)` after opening it from the keyboard.
+    #[prop_or_default]
+    pub initial_focus: Option<MenuKey>,
+    /// Id of the dropdown list, e.g. [`PopupProps::id`]. The button's id is `"{id}-button"`.
+    /// Generated if `None`.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    /// Handles key presses on the button in place of its built-in menu button keys, e.g.
+    /// [`TriggerProps::onkeydown`].
+    #[prop_or_default]
+    pub on_button_keydown: Option<Callback<KeyboardEvent>>,
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[prop_or_default]
+    pub current_path: Option<AttrValue>,
+    /// How item links are matched against the current path.
+    #[prop_or_default]
+    pub active_match: ActiveMatch,
+    /// Style added to the active item's link, which also gets `aria-current="page"`.
+    #[prop_or("font-weight: bold;")]
+    pub active_style: &'static str,
+    /// Optional class added to the active item's link.
+    #[prop_or_default]
+    pub active_class: String,
 }
 
+/// Renders the profile button and its dropdown following the WAI-ARIA menu button pattern.
 #[function_component(ProfileMenu)]
 fn profile_menu(props: &ProfileMenuProps) -> Html {
+    let generated_id = use_element_id("dropdown");
+    let id = props.id.clone().unwrap_or(generated_id);
+    use_click_outside(
+        id.clone(),
+        props.is_open && props.close_on_outside_click,
+        props.on_close.clone(),
+    );
+
+    let router = use_link_router();
+    let location = use_location_path();
+    let path = props.current_path.clone().unwrap_or(location);
+    let len = props.items.len();
+    let (phase, on_transition_end) = use_transition(props.is_open, props.transition.clone());
+    let transition = props.transition.clone().unwrap_or_default();
+    let menu_button = use_menu_button(MenuButtonOptions {
+        id: id.clone(),
+        len,
+        is_open: props.is_open,
+        shown: props.is_open && phase.is_mounted(),
+        initial_focus: props.initial_focus,
+        on_open: props.on_open.clone(),
+        on_close: props.on_close.clone(),
+    });
+    let on_button_keydown = props
+        .on_button_keydown
+        .clone()
+        .unwrap_or(menu_button.on_button_keydown.clone());
+
     html! {
         <div style={props.wrapper_style} class={props.wrapper_class.clone()}>
             <button
+                id={menu_button.button_id.clone()}
                 onclick={props.toggle.clone()}
-                aria-haspopup="true"
+                onkeydown={on_button_keydown}
+                aria-controls={id.clone()}
+                aria-haspopup="menu"
                 aria-expanded={props.is_open.to_string()}
                 style={props.button_style}
                 class={props.button_class.clone()}
@@ -856,16 +3591,36 @@ fn profile_menu(props: &ProfileMenuProps) -> Html {
                         }
                     } }
             </button>
-            { if props.is_open {
+            { if phase.is_mounted() {
                     html! {
-                        <ul style={props.dropdown_style} class={props.dropdown_class.clone()}>
-                            { for props.items.iter().map(|item| html! {
-                                <li key={item.id} style={props.item_style} class={props.item_class.clone()}>
-                                    <a href={item.link}>
-                                        { item.icon.clone().unwrap_or_default() }
-                                        { item.label }
-                                    </a>
-                                </li>
+                        <ul
+                            id={id.clone()}
+                            role="menu"
+                            aria-labelledby={menu_button.button_id.clone()}
+                            style={with_phase(props.dropdown_style, transition.style(phase))}
+                            class={with_phase(&props.dropdown_class, transition.class(phase))}
+                            ontransitionend={on_transition_end.reform(|_| ())}
+                        >
+                            { for props.items.iter().enumerate().map(|(index, item)| {
+                                let active = props.active_match.is_active(&item.link, &path);
+                                html! {
+                                    <li key={item.id} role="none" style={props.item_style} class={props.item_class.clone()}>
+                                        <a
+                                            id={item_id(&id, index)}
+                                            href={router.href(&item.link)}
+                                            onclick={router.onclick(&item.link, "")}
+                                            role="menuitem"
+                                            tabindex="-1"
+                                            onkeydown={menu_button.on_item_keydown(index)}
+                                            aria-current={active.then_some("page")}
+                                            style={active.then_some(props.active_style)}
+                                            class={active.then(|| props.active_class.clone())}
+                                        >
+                                            { item.icon.clone().unwrap_or_default() }
+                                            { item.label.clone() }
+                                        </a>
+                                    </li>
+                                }
                             }) }
                         </ul>
                     }
@@ -876,6 +3631,291 @@ fn profile_menu(props: &ProfileMenuProps) -> Html {
     }
 }
 
+/// Properties for rendering the notification bell and its feed.
+#[derive(Properties, PartialEq)]
+pub struct NotificationMenuProps {
+    /// Notifications listed in the feed, newest first.
+    pub items: Vec<Notification>,
+    /// Indicates if the feed is currently open.
+    pub is_open: bool,
+    /// Callback to toggle the feed.
+    pub toggle: Callback<MouseEvent>,
+    /// Callback to open the feed from the keyboard (`ArrowDown`/`ArrowUp` on the bell).
+    #[prop_or_default]
+    pub on_open: Callback<()>,
+    /// Callback to close the feed, e.g. after a click outside of it, `Escape` or a selection.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// Called with the notification the user clicked.
+    #[prop_or_default]
+    pub on_select: Callback<Notification>,
+    /// Called when the user clicks "Mark all read". Shown while a notification is unread.
+    #[prop_or_default]
+    pub on_mark_all_read: Callback<()>,
+    /// Unread count above which the badge shows `"{badge_max}+"`.
+    #[prop_or(BADGE_MAX)]
+    pub badge_max: usize,
+    /// Accessible name of the bell button.
+    #[prop_or(AttrValue::Static("Notifications"))]
+    pub label: AttrValue,
+    /// Text of the button that marks every notification as read.
+    #[prop_or(AttrValue::Static("Mark all read"))]
+    pub mark_all_read_text: AttrValue,
+    /// Text shown when the feed is empty.
+    #[prop_or(AttrValue::Static("No notifications"))]
+    pub empty_text: AttrValue,
+    /// Text read out by screen readers before an unread notification.
+    #[prop_or(AttrValue::Static("Unread:"))]
+    pub unread_text: AttrValue,
+    /// Enter and exit transition of the feed.
+    #[prop_or_default]
+    pub transition: Option<Transition>,
+    /// Whether a click outside the open feed closes it.
+    #[prop_or(true)]
+    pub close_on_outside_click: bool,
+    /// Item to focus when the feed is shown or this prop changes, e.g. `Some(MenuKey::First)`
+    /// after opening it from the keyboard.
+    #[prop_or_default]
+    pub initial_focus: Option<MenuKey>,
+    /// Id of the feed, e.g. [`PopupProps::id`]. The bell's id is `"{id}-button"`. Generated if
+    /// `None`.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    /// Handles key presses on the bell in place of its built-in menu button keys, e.g.
+    /// [`TriggerProps::onkeydown`].
+    #[prop_or_default]
+    pub on_button_keydown: Option<Callback<KeyboardEvent>>,
+    /// Style for the wrapper around the bell.
+    #[prop_or("position: relative; display: inline-block; margin: 0 0 0 1rem;")]
+    pub wrapper_style: &'static str,
+    /// Style for the bell button.
+    #[prop_or(
+        "position: relative; background: none; border: none; cursor: pointer; color: var(--navbar-fg, black); padding: 0.25rem;"
+    )]
+    pub button_style: &'static str,
+    /// Style for the unread count badge.
+    #[prop_or(
+        "position: absolute; top: -0.25rem; right: -0.25rem; min-width: 1.25rem; padding: 0 0.25rem; border-radius: 9999px; background: var(--navbar-accent, #007bff); color: var(--navbar-accent-text, white); font-size: 0.75rem; line-height: 1.25rem; text-align: center;"
+    )]
+    pub badge_style: &'static str,
+    /// Style for the feed container.
+    #[prop_or(
+        "position: absolute; top: 100%; right: 0; min-width: 18rem; margin: 0; padding: 0; list-style: none; background: var(--navbar-surface, white); box-shadow: 0 4px 8px var(--navbar-shadow, rgba(0,0,0,0.1)); z-index: 1000;"
+    )]
+    pub dropdown_style: &'static str,
+    /// Style for each notification.
+    #[prop_or(
+        "display: flex; flex-direction: column; width: 100%; padding: 0.5rem 1rem; background: none; border: none; text-align: left; cursor: pointer; color: inherit;"
+    )]
+    pub item_style: &'static str,
+    /// Style added to unread notifications.
+    #[prop_or("font-weight: bold; background: var(--navbar-highlight, #f0f0f0);")]
+    pub unread_style: &'static str,
+    /// Style for the time of each notification.
+    #[prop_or("font-size: 0.75rem; font-weight: normal; opacity: 0.7;")]
+    pub time_style: &'static str,
+    /// Style for the "Mark all read" button and the empty text.
+    #[prop_or(
+        "width: 100%; padding: 0.5rem 1rem; background: none; border: none; border-top: 1px solid var(--navbar-border, #ccc); text-align: center; cursor: pointer; color: var(--navbar-accent, #007bff);"
+    )]
+    pub footer_style: &'static str,
+    /// Class name for the wrapper element.
+    #[prop_or_default]
+    pub wrapper_class: String,
+    /// Class name for the bell button.
+    #[prop_or_default]
+    pub button_class: String,
+    /// Class name for the badge.
+    #[prop_or_default]
+    pub badge_class: String,
+    /// Class name for the feed container.
+    #[prop_or_default]
+    pub dropdown_class: String,
+    /// Class name for each notification.
+    #[prop_or_default]
+    pub item_class: String,
+    /// Class added to unread notifications.
+    #[prop_or_default]
+    pub unread_class: String,
+    /// Class name for the "Mark all read" button and the empty text.
+    #[prop_or_default]
+    pub footer_class: String,
+}
+
+/// Renders a bell with an unread badge and a feed of notifications, following the WAI-ARIA
+/// menu button pattern like [`ProfileMenu`].
+///
+/// The badge counts unread notifications, capped at `badge_max` (`"99+"` by default).
+/// Clicking a notification calls `on_select` and closes the feed; while any is unread, the feed
+/// ends with a "Mark all read" item. The open state is controlled, e.g. by [`use_navbar`] with
+/// [`Popup::Notifications`](crate::core::Popup::Notifications).
+///
+/// # Example
+/// ```rust
+/// use yew::prelude::*;
+/// use navbar::core::{Notification, Popup};
+/// use navbar::yew::{NavbarOptions, NotificationMenu, use_navbar};
+///
+/// #[function_component]
+/// fn Bell() -> Html {
+///     let navbar = use_navbar(NavbarOptions::default());
+///     let bell = navbar.trigger_props(Popup::Notifications);
+///     let feed = navbar.popup_props(Popup::Notifications);
+///     html! {
+///         <NotificationMenu
+///             items={vec![Notification::new(1, "Build passed", "2 min ago")]}
+///             id={feed.id}
+///             is_open={feed.is_open}
+///             initial_focus={feed.initial_focus}
+///             toggle={bell.onclick}
+///             on_button_keydown={bell.onkeydown}
+///             on_close={feed.on_close}
+///             on_select={Callback::from(|n: Notification| log::info!("{}", n.title))}
+///         />
+///     }
+/// }
+///
+/// let html = futures::executor::block_on(yew::LocalServerRenderer::<Bell>::new().render());
+/// assert!(html.contains(">1</span>"));
+/// assert!(html.contains(r#"id="navbar-notifications-button""#));
+/// ```
+#[function_component(NotificationMenu)]
+pub fn notification_menu(props: &NotificationMenuProps) -> Html {
+    let generated_id = use_element_id("notifications");
+    let id = props.id.clone().unwrap_or(generated_id);
+    use_click_outside(
+        id.clone(),
+        props.is_open && props.close_on_outside_click,
+        props.on_close.clone(),
+    );
+
+    let unread = unread_count(&props.items);
+    let len = props.items.len() + usize::from(unread > 0);
+    let (phase, on_transition_end) = use_transition(props.is_open, props.transition.clone());
+    let transition = props.transition.clone().unwrap_or_default();
+    let menu_button = use_menu_button(MenuButtonOptions {
+        id: id.clone(),
+        len,
+        is_open: props.is_open,
+        shown: props.is_open && phase.is_mounted(),
+        initial_focus: props.initial_focus,
+        on_open: props.on_open.clone(),
+        on_close: props.on_close.clone(),
+    });
+    let on_button_keydown = props
+        .on_button_keydown
+        .clone()
+        .unwrap_or(menu_button.on_button_keydown.clone());
+
+    let on_item_click = |item: &Notification| {
+        let item = item.clone();
+        let on_select = props.on_select.clone();
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| {
+            on_select.emit(item.clone());
+            on_close.emit(());
+        })
+    };
+
+    html! {
+        <div style={props.wrapper_style} class={props.wrapper_class.clone()}>
+            <button
+                id={menu_button.button_id.clone()}
+                type="button"
+                onclick={props.toggle.clone()}
+                onkeydown={on_button_keydown}
+                aria-controls={id.clone()}
+                aria-haspopup="menu"
+                aria-expanded={props.is_open.to_string()}
+                style={props.button_style}
+                class={props.button_class.clone()}
+            >
+                <svg
+                    width="24"
+                    height="24"
+                    viewBox="0 0 24 24"
+                    fill="none"
+                    stroke="currentColor"
+                    stroke-width="2"
+                    stroke-linecap="round"
+                    stroke-linejoin="round"
+                    aria-hidden="true"
+                >
+                    <path d={BELL_ICON_PATH} />
+                </svg>
+                <span style={VISUALLY_HIDDEN_STYLE}>{ props.label.clone() }</span>
+                { unread_badge(unread, props.badge_max, props.badge_style, &props.badge_class) }
+            </button>
+            if phase.is_mounted() {
+                <ul
+                    id={id.clone()}
+                    role="menu"
+                    aria-labelledby={menu_button.button_id.clone()}
+                    style={with_phase(props.dropdown_style, transition.style(phase))}
+                    class={with_phase(&props.dropdown_class, transition.class(phase))}
+                    ontransitionend={on_transition_end.reform(|_| ())}
+                >
+                    { for props.items.iter().enumerate().map(|(index, item)| html! {
+                        <li key={item.id} role="none">
+                            <button
+                                id={item_id(&id, index)}
+                                type="button"
+                                role="menuitem"
+                                tabindex="-1"
+                                onclick={on_item_click(item)}
+                                onkeydown={menu_button.on_item_keydown(index)}
+                                style={with_active(props.item_style, props.unread_style, !item.read)}
+                                class={with_active(&props.item_class, &props.unread_class, !item.read)}
+                            >
+                                { unread_prefix(!item.read, &props.unread_text) }
+                                <span>{ item.title.clone() }</span>
+                                <span style={props.time_style}>{ item.time.clone() }</span>
+                            </button>
+                        </li>
+                    }) }
+                    if props.items.is_empty() {
+                        <li role="none" style={props.footer_style} class={props.footer_class.clone()}>
+                            { props.empty_text.clone() }
+                        </li>
+                    }
+                    if unread > 0 {
+                        <li role="none">
+                            <button
+                                id={item_id(&id, props.items.len())}
+                                type="button"
+                                role="menuitem"
+                                tabindex="-1"
+                                onclick={props.on_mark_all_read.reform(|_| ())}
+                                onkeydown={menu_button.on_item_keydown(props.items.len())}
+                                style={props.footer_style}
+                                class={props.footer_class.clone()}
+                            >
+                                { props.mark_all_read_text.clone() }
+                            </button>
+                        </li>
+                    }
+                </ul>
+            }
+        </div>
+    }
+}
+
+/// Renders the unread count badge, shared by the bell and the mobile notification section.
+fn unread_badge(unread: usize, max: usize, style: &'static str, class: &str) -> Html {
+    match badge_text(unread, max) {
+        Some(badge) => html! { <span style={style} class={class.to_string()}>{ badge }</span> },
+        None => html! {},
+    }
+}
+
+/// Renders the text read out by screen readers before an unread notification.
+fn unread_prefix(unread: bool, text: &AttrValue) -> Html {
+    if !unread {
+        return html! {};
+    }
+    html! { <span style={VISUALLY_HIDDEN_STYLE}>{ text.clone() }</span> }
+}
+
 /// Properties for rendering a complex mega menu layout.
 #[derive(Properties, PartialEq)]
 pub struct MegaMenuProps {
@@ -886,11 +3926,11 @@ pub struct MegaMenuProps {
     pub wrapper_style: &'static str,
     /// Style for the inner card/container element.
     #[prop_or(
-        "background: white; display: flex; gap: 2rem; padding: 1rem; box-shadow: 0 2px 8px rgba(0,0,0,0.1);"
+        "background: var(--navbar-surface, white); display: flex; gap: 2rem; padding: 1rem; box-shadow: 0 2px 8px var(--navbar-shadow, rgba(0,0,0,0.1));"
     )]
     pub card_style: &'static str,
     /// Style for each link in the mega menu.
-    #[prop_or("text-decoration: none; color: black;")]
+    #[prop_or("text-decoration: none; color: var(--navbar-fg, black);")]
     pub item_link_style: &'static str,
     /// Optional class for the wrapper.
     #[prop_or_default]
@@ -901,18 +3941,98 @@ pub struct MegaMenuProps {
     /// Optional class for each menu item.
     #[prop_or_default]
     pub item_class: String,
+    /// Whether the menu is open. It stays mounted while its exit `transition` runs.
+    #[prop_or(true)]
+    pub is_open: bool,
+    /// Enter and exit transition of the menu.
+    #[prop_or_default]
+    pub transition: Option<Transition>,
+    /// Whether a click outside the mega menu closes it.
+    #[prop_or(true)]
+    pub close_on_outside_click: bool,
+    /// Callback to close the mega menu, e.g. after a click outside of it or `Escape`.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// Item to focus when the menu is shown or this prop changes, e.g. `Some(MenuKey::First)`
+    /// after opening it from the keyboard.
+    #[prop_or_default]
+    pub initial_focus: Option<MenuKey>,
+    /// Id of the menu, e.g. for the "More" button's `aria-controls`. Generated if `None`.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
 }
 
 #[function_component(MegaMenu)]
 fn mega_menu(props: &MegaMenuProps) -> Html {
+    let generated_id = use_element_id("mega-menu");
+    let id = props.id.clone().unwrap_or(generated_id);
+    let router = use_link_router();
+    use_click_outside(
+        id.clone(),
+        props.is_open && props.close_on_outside_click,
+        props.on_close.clone(),
+    );
+    let (phase, on_transition_end) = use_transition(props.is_open, props.transition.clone());
+    let transition = props.transition.clone().unwrap_or_default();
+
+    let len = props.items.len();
+    {
+        let id = id.clone();
+        let shown = props.is_open && phase.is_mounted();
+        use_effect_with((props.initial_focus, shown), move |&(focus, shown)| {
+            if let Some(key) = focus.filter(|_| shown) {
+                focus_menu_item(&id, key, None, len);
+            }
+        });
+    }
+
+    let on_item_keydown = |index: usize| {
+        let id = id.clone();
+        let on_close = props.on_close.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            match MenuKey::from_key(&e.key(), Orientation::Both) {
+                Some(MenuKey::Close) => {
+                    e.prevent_default();
+                    on_close.emit(());
+                }
+                Some(MenuKey::Leave) => on_close.emit(()),
+                Some(key) => {
+                    let moved = focus_menu_item(&id, key, Some(index), len);
+                    if moved {
+                        e.prevent_default();
+                    }
+                }
+                None => {}
+            }
+        })
+    };
+
+    if !phase.is_mounted() {
+        return html! {};
+    }
+
     html! {
-        <div style={props.wrapper_style} class={props.wrapper_class.clone()}>
-            <div style={props.card_style} class={props.card_class.clone()}>
-                { for props.items.iter().map(|item| html! {
-                    <a href={item.link} style={props.item_link_style} class={props.item_class.clone()}>
+        <div
+            id={id.clone()}
+            style={with_phase(props.wrapper_style, transition.style(phase))}
+            class={with_phase(&props.wrapper_class, transition.class(phase))}
+            ontransitionend={on_transition_end.reform(|_| ())}
+        >
+            <div role="menu" style={props.card_style} class={props.card_class.clone()}>
+                { for props.items.iter().enumerate().map(|(index, item)| html! {
+                    <a
+                        id={item_id(&id, index)}
+                        href={router.href(&item.link)}
+                        onclick={router.onclick(&item.link, "")}
+                        role="menuitem"
+                        tabindex="-1"
+                        onkeydown={on_item_keydown(index)}
+                        style={props.item_link_style}
+                        class={props.item_class.clone()}
+                    >
                         <div>
-                            <p><strong>{ item.title }</strong></p>
-                            <p>{ item.description }</p>
+                            <p><strong>{ item.title.clone() }</strong></p>
+                            <p>{ item.description.clone() }</p>
                         </div>
                     </a>
                 }) }
@@ -932,7 +4052,9 @@ pub struct MobileMenuProps {
     /// Dropdown items (usually profile-related).
     pub dropdown_items: Vec<DropdownItem>,
     /// Style for the dropdown container.
-    #[prop_or("background: #f9f9f9; padding: 1rem; border-radius: 4px;")]
+    #[prop_or(
+        "background: var(--navbar-surface-muted, #f9f9f9); padding: 1rem; border-radius: 4px;"
+    )]
     pub dropdown_style: &'static str,
     /// Style for each dropdown item.
     #[prop_or("padding: 0.5rem;")]
@@ -940,18 +4062,57 @@ pub struct MobileMenuProps {
     /// Whether to show profile menu.
     #[prop_or(false)]
     pub show_profile_menu: bool,
+    /// Whether to show the notification section.
+    #[prop_or(false)]
+    pub show_notifications: bool,
+    /// Notifications listed in the notification section.
+    #[prop_or_default]
+    pub notifications: Vec<Notification>,
+    /// Called with the notification the user clicked.
+    #[prop_or_default]
+    pub on_notification_select: Callback<Notification>,
+    /// Called when the user clicks "Mark all read".
+    #[prop_or_default]
+    pub on_mark_all_read: Callback<()>,
+    /// Heading and accessible name of the notification section.
+    #[prop_or(AttrValue::Static("Notifications"))]
+    pub notifications_label: AttrValue,
+    /// Text of the button that marks every notification as read.
+    #[prop_or(AttrValue::Static("Mark all read"))]
+    pub mark_all_read_text: AttrValue,
+    /// Unread count above which the badge next to the heading shows `"{max}+"`.
+    #[prop_or(BADGE_MAX)]
+    pub notifications_badge_max: usize,
+    /// Style for the unread count badge next to the heading.
+    #[prop_or(
+        "display: inline-block; min-width: 1.25rem; margin-left: 0.5rem; padding: 0 0.25rem; border-radius: 9999px; background: var(--navbar-accent, #007bff); color: var(--navbar-accent-text, white); font-size: 0.75rem; line-height: 1.25rem; text-align: center;"
+    )]
+    pub notifications_badge_style: &'static str,
+    /// Optional class for the unread count badge next to the heading.
+    #[prop_or_default]
+    pub notifications_badge_class: String,
+    /// Text read out by screen readers before an unread notification.
+    #[prop_or(AttrValue::Static("Unread:"))]
+    pub unread_text: AttrValue,
+    /// Style for each notification button; unread ones are also bold.
+    #[prop_or(
+        "display: flex; flex-direction: column; width: 100%; padding: 0; background: none; border: none; text-align: left; cursor: pointer; color: inherit;"
+    )]
+    pub notification_style: &'static str,
     /// Whether to show search input.
     #[prop_or(false)]
     pub show_search: bool,
     /// Style for the search input field.
-    #[prop_or("padding: 0.5rem; width: 100%; border: 1px solid #ccc;")]
+    #[prop_or(
+        "padding: 0.5rem; width: 100%; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black);"
+    )]
     pub search_input_style: &'static str,
     /// Placeholder text for the search input.
-    #[prop_or("Search...")]
-    pub search_placeholder: &'static str,
+    #[prop_or(AttrValue::Static("Search..."))]
+    pub search_placeholder: AttrValue,
     /// Style for the mobile menu container.
     #[prop_or(
-        "display: flex; flex-direction: column; gap: 1rem; padding: 1rem; position: absolute; top: 100%; left: 0; background: white; z-index: 999; width: max-content;"
+        "display: flex; flex-direction: column; gap: 1rem; padding: 1rem; position: absolute; top: 100%; left: 0; background: var(--navbar-surface, white); z-index: 999; width: max-content;"
     )]
     pub container_style: &'static str,
     /// Optional class for container.
@@ -966,39 +4127,297 @@ pub struct MobileMenuProps {
     /// Optional class for dropdown items.
     #[prop_or_default]
     pub dropdown_item_class: String,
+    /// Style for the expanded submenu (accordion) of an item with `children`.
+    #[prop_or("margin: 0; padding-left: 1rem; list-style: none;")]
+    pub submenu_style: &'static str,
+    /// Optional class for the expanded submenu of an item with `children`.
+    #[prop_or_default]
+    pub submenu_class: String,
     /// Optional class for search input.
     #[prop_or_default]
     pub search_input_class: String,
+    /// Current search text, e.g. the navbar's `search_state`. `None` leaves the input uncontrolled.
+    #[prop_or_default]
+    pub search_value: Option<AttrValue>,
+    /// Callback fired with the search text on every keystroke.
+    #[prop_or_default]
+    pub on_search_input: Callback<String>,
+    /// Callback fired with the search text on `Enter` or a click on the search button.
+    #[prop_or_default]
+    pub on_search_submit: Callback<String>,
+    /// Whether to show a submit button next to the search input.
+    #[prop_or(false)]
+    pub show_search_button: bool,
+    /// Text of the search submit button.
+    #[prop_or(AttrValue::Static("Search"))]
+    pub search_button_text: AttrValue,
+    /// Style for the search submit button.
+    #[prop_or(
+        "padding: 0.5rem 1rem; border: 1px solid var(--navbar-border, #ccc); background: var(--navbar-surface, white); color: var(--navbar-fg, black); cursor: pointer;"
+    )]
+    pub search_button_style: &'static str,
+    /// Optional class for the search submit button.
+    #[prop_or_default]
+    pub search_button_class: String,
+    /// Provider of the suggestions listed under the search input as the user types.
+    #[prop_or_default]
+    pub suggestions: Option<SuggestionProvider>,
+    /// Callback fired with the chosen suggestion, before navigating to its `link` (if any).
+    #[prop_or_default]
+    pub on_suggestion_select: Callback<Suggestion>,
+    /// Delay (ms) after the last keystroke before an asynchronous suggestion provider is called.
+    #[prop_or(300)]
+    pub search_debounce_ms: u32,
+    /// Text shown while an asynchronous suggestion provider is loading.
+    #[prop_or(AttrValue::Static("Loading..."))]
+    pub suggestions_loading_text: AttrValue,
+    /// Text shown when the suggestion provider finds nothing.
+    #[prop_or(AttrValue::Static("No results"))]
+    pub suggestions_empty_text: AttrValue,
+    /// Style for the suggestions listbox.
+    #[prop_or(SUGGESTIONS_STYLE)]
+    pub suggestions_style: &'static str,
+    /// Optional class for the suggestions listbox.
+    #[prop_or_default]
+    pub suggestions_class: String,
+    /// Style for each suggestion.
+    #[prop_or("padding: 0.5rem 1rem; cursor: pointer;")]
+    pub suggestion_style: &'static str,
+    /// Optional class for each suggestion.
+    #[prop_or_default]
+    pub suggestion_class: String,
+    /// Style added to the highlighted suggestion.
+    #[prop_or("background: var(--navbar-highlight, #f0f0f0);")]
+    pub suggestion_active_style: &'static str,
+    /// Optional class added to the highlighted suggestion.
+    #[prop_or_default]
+    pub suggestion_active_class: String,
+    /// Id of the menu container, e.g. for the toggle's `aria-controls`. Generated if `None`.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    /// Whether the menu is open. It stays mounted while its exit `transition` runs.
+    #[prop_or(true)]
+    pub is_open: bool,
+    /// Where to move focus when the menu is shown or this prop changes: its first focusable
+    /// element, or its last one for `Some(MenuKey::Last)`.
+    #[prop_or_default]
+    pub initial_focus: Option<MenuKey>,
+    /// Key presses inside the menu, e.g. [`PopupProps::onkeydown`] to close it with `Escape`.
+    #[prop_or_default]
+    pub onkeydown: Callback<KeyboardEvent>,
+    /// Keeps the menu rendered while closed, for a surrounding `<details>` to show and hide it
+    /// before the app hydrates.
+    #[prop_or(false)]
+    pub keep_mounted: bool,
+    /// Content rendered at the bottom of the menu.
+    #[prop_or_default]
+    pub extra: Html,
+    /// Enter and exit transition of the menu.
+    #[prop_or_default]
+    pub transition: Option<Transition>,
+    /// Layout of the menu. Drawers and the sheet are modal dialogs over a backdrop.
+    #[prop_or_default]
+    pub variant: MobileVariant,
+    /// Accessible name of the menu when shown as a modal dialog.
+    #[prop_or(AttrValue::Static("Menu"))]
+    pub label: AttrValue,
+    /// Style of the backdrop behind a drawer or sheet.
+    #[prop_or(MOBILE_BACKDROP_STYLE)]
+    pub backdrop_style: &'static str,
+    /// Optional class for the backdrop behind a drawer or sheet.
+    #[prop_or_default]
+    pub backdrop_class: String,
+    /// Whether a click outside the mobile menu closes it.
+    #[prop_or(true)]
+    pub close_on_outside_click: bool,
+    /// Callback to close the mobile menu, e.g. after a click outside of it.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// Path that item links are matched against. Defaults to `window.location.pathname`.
+    #[prop_or_default]
+    pub current_path: Option<AttrValue>,
+    /// How item links are matched against the current path.
+    #[prop_or_default]
+    pub active_match: ActiveMatch,
+    /// Style added to the active item's link, which also gets `aria-current="page"`.
+    #[prop_or("font-weight: bold;")]
+    pub active_style: &'static str,
+    /// Optional class added to the active item's link.
+    #[prop_or_default]
+    pub active_class: String,
 }
 
 #[function_component(MobileMenu)]
 fn mobile_menu(props: &MobileMenuProps) -> Html {
+    let generated_id = use_element_id("mobile-menu");
+    let id = props.id.clone().unwrap_or(generated_id);
+    use_click_outside(
+        id.clone(),
+        props.is_open && props.close_on_outside_click,
+        props.on_close.clone(),
+    );
+    let router = use_link_router();
+    let location = use_location_path();
+    let path = props.current_path.clone().unwrap_or(location);
+    let (phase, on_transition_end) = use_transition(props.is_open, props.transition.clone());
+    let modal = props.variant.is_modal();
+    {
+        let id = id.clone();
+        let on_close = props.on_close.clone();
+        let trapped = modal && props.is_open && phase.is_mounted();
+        use_effect_with(trapped, move |trapped| {
+            let guard = trapped.then(|| {
+                (
+                    FocusTrap::new(id.to_string(), move || on_close.emit(())),
+                    ScrollLock::new(),
+                )
+            });
+            move || drop(guard)
+        });
+    }
+    {
+        let id = id.clone();
+        let shown = props.is_open && phase.is_mounted();
+        use_effect_with((props.initial_focus, shown), move |&(focus, shown)| {
+            if let Some(key) = focus.filter(|_| shown) {
+                focus_within(&id, key);
+            }
+        });
+    }
+    if !phase.is_mounted() && !props.keep_mounted {
+        return html! {};
+    }
+    let transition = props.transition.clone().unwrap_or_default();
+    let style = with_phase(props.container_style, props.variant.panel_style());
+
     html! {
-        <div style={props.container_style} class={props.container_class.clone()}>
-            <NavbarMenu
-                menus={props.menus.clone()}
-                menu_item_style={props.menu_item_style}
-                menu_item_class={props.menu_item_class.clone()}
-                list_style=""
-                list_class=""
-            />
-            if props.show_search {
-                <input
-                    type="text"
-                    style={props.search_input_style}
-                    placeholder={props.search_placeholder}
-                    class={props.search_input_class.clone()}
+        <>
+            if modal {
+                <div
+                    style={props.backdrop_style}
+                    class={props.backdrop_class.clone()}
+                    aria-hidden="true"
+                    onclick={props.on_close.reform(|_| ())}
                 />
             }
-            if props.show_profile_menu {
-                <ul style={props.dropdown_style} class={props.dropdown_class.clone()}>
-                    { for props.dropdown_items.iter().map(|item| html! {
-                        <li key={item.id} style={props.dropdown_item_style} class={props.dropdown_item_class.clone()}>
-                            <a href={item.link}>{ item.label }</a>
-                        </li>
-                    }) }
-                </ul>
-            }
-        </div>
+            <div
+                id={id}
+                role={modal.then_some("dialog")}
+                aria-modal={modal.then_some("true")}
+                aria-label={modal.then(|| props.label.clone())}
+                style={with_phase(&style, transition.style(phase))}
+                class={with_phase(&props.container_class, transition.class(phase))}
+                onkeydown={props.onkeydown.clone()}
+                ontransitionend={on_transition_end.reform(|_| ())}
+            >
+                <NavbarMenu
+                    menus={props.menus.clone()}
+                    menu_item_style={props.menu_item_style}
+                    menu_item_class={props.menu_item_class.clone()}
+                    list_style=""
+                    list_class=""
+                    orientation={Orientation::Vertical}
+                    submenu_style={props.submenu_style}
+                    submenu_class={props.submenu_class.clone()}
+                    accordion=true
+                    current_path={path.clone()}
+                    active_match={props.active_match.clone()}
+                    active_style={props.active_style}
+                    active_class={props.active_class.clone()}
+                />
+                if props.show_search {
+                    <NavbarSearch
+                        value={props.search_value.clone()}
+                        placeholder={props.search_placeholder.clone()}
+                        input_style={props.search_input_style}
+                        input_class={props.search_input_class.clone()}
+                        on_input={props.on_search_input.clone()}
+                        on_submit={props.on_search_submit.clone()}
+                        show_button={props.show_search_button}
+                        button_text={props.search_button_text.clone()}
+                        button_style={props.search_button_style}
+                        button_class={props.search_button_class.clone()}
+                        suggestions={props.suggestions.clone()}
+                        on_suggestion_select={props.on_suggestion_select.clone()}
+                        debounce_ms={props.search_debounce_ms}
+                        suggestions_loading_text={props.suggestions_loading_text.clone()}
+                        suggestions_empty_text={props.suggestions_empty_text.clone()}
+                        suggestions_style={props.suggestions_style}
+                        suggestions_class={props.suggestions_class.clone()}
+                        suggestion_style={props.suggestion_style}
+                        suggestion_class={props.suggestion_class.clone()}
+                        suggestion_active_style={props.suggestion_active_style}
+                        suggestion_active_class={props.suggestion_active_class.clone()}
+                    />
+                }
+                if props.show_profile_menu {
+                    <ul style={props.dropdown_style} class={props.dropdown_class.clone()}>
+                        { for props.dropdown_items.iter().map(|item| {
+                            let active = props.active_match.is_active(&item.link, &path);
+                            html! {
+                                <li key={item.id} style={props.dropdown_item_style} class={props.dropdown_item_class.clone()}>
+                                    <a
+                                        href={router.href(&item.link)}
+                                        onclick={router.onclick(&item.link, "")}
+                                        aria-current={active.then_some("page")}
+                                        style={active.then_some(props.active_style)}
+                                        class={active.then(|| props.active_class.clone())}
+                                    >
+                                        { item.label.clone() }
+                                    </a>
+                                </li>
+                            }
+                        }) }
+                    </ul>
+                }
+                if props.show_notifications {
+                    <section aria-label={props.notifications_label.clone()}>
+                        <p style="margin: 0; padding: 0.5rem; font-weight: bold;">
+                            { props.notifications_label.clone() }
+                            { unread_badge(
+                                unread_count(&props.notifications),
+                                props.notifications_badge_max,
+                                props.notifications_badge_style,
+                                &props.notifications_badge_class,
+                            ) }
+                        </p>
+                        <ul style={props.dropdown_style} class={props.dropdown_class.clone()}>
+                            { for props.notifications.iter().map(|item| {
+                                let onclick = {
+                                    let item = item.clone();
+                                    let on_select = props.on_notification_select.clone();
+                                    Callback::from(move |_: MouseEvent| on_select.emit(item.clone()))
+                                };
+                                html! {
+                                    <li key={item.id} style={props.dropdown_item_style} class={props.dropdown_item_class.clone()}>
+                                        <button
+                                            type="button"
+                                            onclick={onclick}
+                                            style={with_active(props.notification_style, "font-weight: bold;", !item.read)}
+                                        >
+                                            { unread_prefix(!item.read, &props.unread_text) }
+                                            <span>{ item.title.clone() }</span>
+                                            <small>{ item.time.clone() }</small>
+                                        </button>
+                                    </li>
+                                }
+                            }) }
+                            if unread_count(&props.notifications) > 0 {
+                                <li style={props.dropdown_item_style} class={props.dropdown_item_class.clone()}>
+                                    <button
+                                        type="button"
+                                        onclick={props.on_mark_all_read.reform(|_| ())}
+                                        style={props.notification_style}
+                                    >
+                                        { props.mark_all_read_text.clone() }
+                                    </button>
+                                </li>
+                            }
+                        </ul>
+                    </section>
+                }
+                { props.extra.clone() }
+            </div>
+        </>
     }
 }
@@ -0,0 +1,145 @@
+//! Client-side navigation for item links, through `yew-router` when the feature is enabled.
+
+#[cfg(feature = "yew-router")]
+use crate::core::is_route_link;
+use crate::dom::assign_location;
+use yew::prelude::*;
+#[cfg(feature = "yew-router")]
+use yew_router::navigator::NavigatorKind;
+#[cfg(feature = "yew-router")]
+use yew_router::prelude::{Navigator, Routable, use_location, use_navigator};
+
+/// Renders item links, routing app-internal ones through the enclosing router.
+///
+/// Outside a `yew-router` router (or without the feature), links are plain `href`s.
+#[derive(Clone, PartialEq)]
+pub(super) struct LinkRouter {
+    #[cfg(feature = "yew-router")]
+    navigator: Option<Navigator>,
+}
+
+impl LinkRouter {
+    /// Returns the `href` for a link, including the router's basename.
+    pub(super) fn href(&self, link: &str) -> AttrValue {
+        #[cfg(feature = "yew-router")]
+        if let Some(navigator) = self.navigator.as_ref().filter(|_| is_route_link(link)) {
+            let base = navigator.basename().unwrap_or_default();
+            let hash = if navigator.kind() == NavigatorKind::Hash {
+                "#"
+            } else {
+                ""
+            };
+            return format!("{hash}{base}{link}").into();
+        }
+        AttrValue::from(link.to_string())
+    }
+
+    /// Returns a click handler that navigates to `link` without reloading the page, or `None`
+    /// if the browser should follow the link itself.
+    ///
+    /// Clicks with a modifier key (to open a new tab or window) are left to the browser, as are
+    /// links that open in another browsing context (`target` other than `_self`).
+    pub(super) fn onclick(&self, link: &str, target: &str) -> Option<Callback<MouseEvent>> {
+        #[cfg(feature = "yew-router")]
+        if let Some(navigator) = self.navigator.clone() {
+            if is_route_link(link) && matches!(target, "" | "_self") {
+                let route = yew_router::AnyRoute::new(link);
+                return Some(Callback::from(move |e: MouseEvent| {
+                    if e.meta_key() || e.ctrl_key() || e.shift_key() || e.alt_key() {
+                        return;
+                    }
+                    e.prevent_default();
+                    navigator.push(&route);
+                }));
+            }
+        }
+        #[cfg(not(feature = "yew-router"))]
+        let _ = (link, target);
+        None
+    }
+
+    /// Navigates to `link`, through the router for app-internal links.
+    pub(super) fn navigate(&self, link: &str) {
+        #[cfg(feature = "yew-router")]
+        if let Some(navigator) = self.navigator.as_ref().filter(|_| is_route_link(link)) {
+            navigator.push(&yew_router::AnyRoute::new(link));
+            return;
+        }
+        assign_location(&self.href(link));
+    }
+}
+
+/// Returns the link router of the enclosing `yew-router` router, if any.
+#[hook]
+pub(super) fn use_link_router() -> LinkRouter {
+    LinkRouter {
+        #[cfg(feature = "yew-router")]
+        navigator: use_navigator(),
+    }
+}
+
+/// Returns the path of the enclosing router's current location, without its basename.
+#[cfg(feature = "yew-router")]
+#[hook]
+pub(super) fn use_router_path() -> Option<AttrValue> {
+    let navigator = use_navigator();
+    let location = use_location()?;
+    let path = location.path();
+    let base = navigator.as_ref().and_then(|n| n.basename());
+    let path = match base.and_then(|base| path.strip_prefix(base)) {
+        Some("") => "/",
+        Some(rest) if rest.starts_with('/') => rest,
+        _ => path,
+    };
+    Some(AttrValue::from(path.to_string()))
+}
+
+/// Points navbar items at a typed `yew-router` route instead of a string path.
+///
+/// The item's `link` becomes the route's path, so it navigates through the enclosing router like
+/// `yew_router::Link` and is highlighted while that route is current:
+///
+/// ```rust
+/// use navbar::yew::{DropdownItem, Menu, WithRoute};
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, Routable, PartialEq)]
+/// enum Route {
+///     #[at("/users/:id")]
+///     User { id: u32 },
+/// }
+///
+/// let menu = Menu::new(1, "Ada", "").with_route(Route::User { id: 7 });
+/// assert_eq!(menu.link, "/users/7");
+/// let item = DropdownItem::new(1, "Profile", "").with_route(Route::User { id: 7 });
+/// assert_eq!(item.link, "/users/7");
+/// ```
+#[cfg(feature = "yew-router")]
+pub trait WithRoute: Sized {
+    /// Sets the item's link to the path of `route`.
+    fn with_route<R: Routable>(self, route: R) -> Self;
+}
+
+#[cfg(feature = "yew-router")]
+impl<I> WithRoute for crate::core::Menu<I> {
+    fn with_route<R: Routable>(mut self, route: R) -> Self {
+        self.link = route.to_path().into();
+        self
+    }
+}
+
+#[cfg(feature = "yew-router")]
+impl<I> WithRoute for crate::core::DropdownItem<I> {
+    fn with_route<R: Routable>(mut self, route: R) -> Self {
+        self.link = route.to_path().into();
+        self
+    }
+}
+
+#[cfg(feature = "yew-router")]
+impl WithRoute for crate::core::MegaMenuItem {
+    fn with_route<R: Routable>(mut self, route: R) -> Self {
+        self.link = route.to_path().into();
+        self
+    }
+}